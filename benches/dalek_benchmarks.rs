@@ -24,8 +24,10 @@ use ice_frost::keygen::{
     Coefficients,
     DHPrivateKey,
     EncryptedSecretShare,
+    VerifiableSecretSharingCommitment,
 };
 use ice_frost::DistributedKeyGeneration;
+use ice_frost::IndividualPublicKey;
 use ice_frost::IndividualSecretKey;
 use ice_frost::Parameters;
 use ice_frost::Participant;
@@ -38,6 +40,11 @@ use ice_frost::SignatureAggregator;
 const NUMBER_OF_PARTICIPANTS: u32 = 5;
 const THRESHOLD_OF_PARTICIPANTS: u32 = 3;
 
+const LARGE_NUMBER_OF_PARTICIPANTS: u32 = 200;
+const LARGE_THRESHOLD_OF_PARTICIPANTS: u32 = 101;
+
+const LARGE_THRESHOLD_OF_COMMITMENTS: u32 = 256;
+
 mod dkg_benches {
     use super::*;
 
@@ -167,22 +174,21 @@ mod dkg_benches {
         });
     }
 
-    fn round_two_t_out_of_n(c: &mut Criterion) {
-        let params = Parameters { n: NUMBER_OF_PARTICIPANTS, t: THRESHOLD_OF_PARTICIPANTS };
+    fn round_two(c: &mut Criterion, params: Parameters, name: &str) {
         let mut rng = OsRng;
 
-        let mut participants = Vec::<Participant>::with_capacity(NUMBER_OF_PARTICIPANTS as usize);
-        let mut coefficients = Vec::<Coefficients>::with_capacity(NUMBER_OF_PARTICIPANTS as usize);
-        let mut dh_secret_keys = Vec::<DHPrivateKey>::with_capacity(NUMBER_OF_PARTICIPANTS as usize);
+        let mut participants = Vec::<Participant>::with_capacity(params.n as usize);
+        let mut coefficients = Vec::<Coefficients>::with_capacity(params.n as usize);
+        let mut dh_secret_keys = Vec::<DHPrivateKey>::with_capacity(params.n as usize);
 
-        for i in 1..NUMBER_OF_PARTICIPANTS+1 {
+        for i in 1..params.n+1 {
             let (p, c, dh_sk) = Participant::new_dealer(&params, i, "Φ", &mut rng);
             participants.push(p);
             coefficients.push(c);
             dh_secret_keys.push(dh_sk);
         }
 
-        let mut p1_my_encrypted_secret_shares = Vec::<EncryptedSecretShare>::with_capacity(NUMBER_OF_PARTICIPANTS as usize);
+        let mut p1_my_encrypted_secret_shares = Vec::<EncryptedSecretShare>::with_capacity(params.n as usize);
 
         let (p1_state, _participant_lists) = DistributedKeyGeneration::<_>::new_initial(&params,
                                                           &dh_secret_keys[0],
@@ -194,7 +200,7 @@ mod dkg_benches {
         let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().unwrap();
         p1_my_encrypted_secret_shares.push(p1_their_encrypted_secret_shares[0].clone());
 
-        for i in 2..NUMBER_OF_PARTICIPANTS+1 {
+        for i in 2..params.n+1 {
             let (pi_state, _participant_lists) = DistributedKeyGeneration::<_>::new_initial(&params,
                                                               &dh_secret_keys[(i-1) as usize],
                                                               &participants[(i-1) as usize].index.clone(),
@@ -206,11 +212,32 @@ mod dkg_benches {
             p1_my_encrypted_secret_shares.push(pi_their_encrypted_secret_shares[0].clone());
         }
 
-        c.bench_function("Round Two", move |b| {
+        c.bench_function(name, move |b| {
             b.iter(|| p1_state.clone().to_round_two(p1_my_encrypted_secret_shares.clone(), &mut rng));
         });
     }
 
+    fn round_two_t_out_of_n(c: &mut Criterion) {
+        round_two(c, Parameters { n: NUMBER_OF_PARTICIPANTS, t: THRESHOLD_OF_PARTICIPANTS }, "Round Two");
+    }
+
+    fn round_two_large_group(c: &mut Criterion) {
+        round_two(c, Parameters { n: LARGE_NUMBER_OF_PARTICIPANTS, t: LARGE_THRESHOLD_OF_PARTICIPANTS }, "Round Two (n=200)");
+    }
+
+    fn public_share_generation_large_threshold(c: &mut Criterion) {
+        let params = Parameters { n: LARGE_THRESHOLD_OF_COMMITMENTS, t: LARGE_THRESHOLD_OF_COMMITMENTS };
+        let mut rng = OsRng;
+
+        let commitments: Vec<VerifiableSecretSharingCommitment> = (1..4)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0.commitments.unwrap())
+            .collect();
+
+        c.bench_function("Public share generation (t=256)", move |b| {
+            b.iter(|| IndividualPublicKey::generate_from_commitments(1, &commitments).unwrap());
+        });
+    }
+
     fn finish_t_out_of_n(c: &mut Criterion) {
         let params = Parameters { n: NUMBER_OF_PARTICIPANTS, t: THRESHOLD_OF_PARTICIPANTS };
         let mut rng = OsRng;
@@ -331,6 +358,8 @@ mod dkg_benches {
             round_one_t_out_of_n_initial,
             round_one_t_out_of_n_signer,
             round_two_t_out_of_n,
+            round_two_large_group,
+            public_share_generation_large_threshold,
             finish_t_out_of_n,
             reshare_t_out_of_n,
     }
@@ -415,7 +444,7 @@ mod sign_benches {
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
         for i in 1..THRESHOLD_OF_PARTICIPANTS+1 {
-            aggregator.include_signer(i, participants_public_comshares[(i-1) as usize].commitments[0], (&participants_secret_keys[(i-1) as usize]).into());
+            aggregator.include_signer(i, participants_public_comshares[(i-1) as usize].commitments[0], (&participants_secret_keys[(i-1) as usize]).into()).unwrap();
         }
 
         let signers = aggregator.get_signers();
@@ -505,7 +534,7 @@ mod sign_benches {
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
         for i in 1..THRESHOLD_OF_PARTICIPANTS+1 {
-            aggregator.include_signer(i, participants_public_comshares[(i-1) as usize].commitments[0], (&participants_secret_keys[(i-1) as usize]).into());
+            aggregator.include_signer(i, participants_public_comshares[(i-1) as usize].commitments[0], (&participants_secret_keys[(i-1) as usize]).into()).unwrap();
         }
 
         let signers = aggregator.get_signers().clone();
@@ -602,7 +631,7 @@ mod sign_benches {
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
         for i in 1..THRESHOLD_OF_PARTICIPANTS+1 {
-            aggregator.include_signer(i, participants_public_comshares[(i-1) as usize].commitments[0], (&participants_secret_keys[(i-1) as usize]).into());
+            aggregator.include_signer(i, participants_public_comshares[(i-1) as usize].commitments[0], (&participants_secret_keys[(i-1) as usize]).into()).unwrap();
         }
 
         let signers = aggregator.get_signers().clone();
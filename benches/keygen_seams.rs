@@ -0,0 +1,107 @@
+// -*- mode: rust; -*-
+//
+// This file is part of ice-frost.
+// Copyright (c) 2020 isis lovecruft
+// Copyright (c) 2021-2022 Toposware Inc.
+// See LICENSE for licensing information.
+//
+// Authors:
+// - isis agora lovecruft <isis@patternsinthevoid.net>
+// - Toposware developers <dev@toposware.com>
+
+//! Benchmarks for the hot paths of the FROST key generation, driven in
+//! isolation through the `bench` feature.
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+
+use rand::rngs::OsRng;
+
+use ice_frost::bench;
+use ice_frost::keygen::{
+    collect_shares_for,
+    DHPublicKey,
+    EncryptedSecretShare,
+    VerifiableSecretSharingCommitment,
+};
+use ice_frost::DistributedKeyGeneration;
+use ice_frost::Parameters;
+use ice_frost::Participant;
+
+const NUMBER_OF_PARTICIPANTS: u32 = 50;
+const THRESHOLD_OF_PARTICIPANTS: u32 = 26;
+
+mod seam_benches {
+    use super::*;
+
+    fn round_one_verification(c: &mut Criterion) {
+        let params = Parameters { n: NUMBER_OF_PARTICIPANTS, t: THRESHOLD_OF_PARTICIPANTS };
+        let mut rng = OsRng;
+
+        let participants: Vec<Participant> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0)
+            .collect();
+
+        c.bench_function("Round one proofs verification", move |b| {
+            b.iter(|| bench::verify_round_one_proofs(&params, &participants, "Φ"))
+        });
+    }
+
+    fn round_two_decryption(c: &mut Criterion) {
+        let params = Parameters { n: NUMBER_OF_PARTICIPANTS, t: THRESHOLD_OF_PARTICIPANTS };
+        let mut rng = OsRng;
+
+        let dealers: Vec<_> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng))
+            .collect();
+        let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+
+        let all_dealers_shares: Vec<Vec<EncryptedSecretShare>> = dealers
+            .iter()
+            .map(|(p, coefficients, dh_sk)| {
+                let (state, _) = DistributedKeyGeneration::<_>::new_initial(
+                    &params, dh_sk, &p.index, coefficients, &participants, "Φ", &mut rng).unwrap();
+                state.their_encrypted_secret_shares().unwrap().clone()
+            })
+            .collect();
+
+        let my_encrypted_shares = collect_shares_for(1, &all_dealers_shares);
+        let senders: Vec<(u32, DHPublicKey)> = participants
+            .iter()
+            .map(|p| (p.index, p.dh_public_key.clone()))
+            .collect();
+        let dh_sk = dealers[0].2.clone();
+
+        c.bench_function("Round two shares decryption", move |b| {
+            b.iter(|| bench::decrypt_shares(&dh_sk, &senders, &my_encrypted_shares))
+        });
+    }
+
+    fn group_key_interpolation(c: &mut Criterion) {
+        let params = Parameters { n: NUMBER_OF_PARTICIPANTS, t: THRESHOLD_OF_PARTICIPANTS };
+        let mut rng = OsRng;
+
+        let commitments: Vec<VerifiableSecretSharingCommitment> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0.commitments.unwrap())
+            .collect();
+
+        c.bench_function("Group key interpolation", move |b| {
+            b.iter(|| bench::calculate_group_key(&commitments))
+        });
+    }
+
+    criterion_group! {
+        name = seam_benches;
+        config = Criterion::default().sample_size(10);
+        targets =
+            round_one_verification,
+            round_two_decryption,
+            group_key_interpolation,
+    }
+}
+
+criterion_main!(
+    seam_benches::seam_benches,
+);
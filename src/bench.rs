@@ -0,0 +1,116 @@
+// -*- mode: rust; -*-
+//
+// This file is part of ice-frost.
+// Copyright (c) 2020 isis lovecruft
+// Copyright (c) 2021-2022 Toposware Inc.
+// See LICENSE for licensing information.
+//
+// Authors:
+// - isis agora lovecruft <isis@patternsinthevoid.net>
+// - Toposware developers <dev@toposware.com>
+
+//! Entry points into the hot paths of the distributed key generation, so
+//! that they can be benchmarked without running the whole protocol.
+//!
+//! This module is only available with the `bench` feature, and is not part
+//! of the stable API of this crate.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::keygen::for_each_decrypted_share;
+use crate::keygen::interpolate_group_key;
+use crate::keygen::partition_participants;
+use crate::keygen::PartitionChecks;
+use crate::keygen::DHPrivateKey;
+use crate::keygen::DHPublicKey;
+use crate::keygen::EncryptedSecretShare;
+use crate::keygen::Error;
+use crate::keygen::GroupKey;
+use crate::keygen::Participant;
+use crate::keygen::SecretShare;
+use crate::keygen::VerifiableSecretSharingCommitment;
+use crate::parameters::Parameters;
+
+use sha2::Sha512;
+
+/// Verify the broadcasts of the `participants` to an initial key generation
+/// in round one, as done by `DistributedKeyGeneration::<RoundOne>::new_initial`.
+///
+/// # Returns
+///
+/// The indices of the participants whose DH key proof, commitments or secret
+/// key proof do not verify.
+pub fn verify_round_one_proofs(parameters: &Parameters, participants: &[Participant], context_string: &str) -> Vec<u32> {
+    partition_participants::<Sha512>(parameters, participants, context_string, PartitionChecks::ALL)
+        .misbehaving_participants
+}
+
+/// Decrypt the `encrypted_shares` received in round two, as done by the owner
+/// of `dh_private_key` when moving to round two, given the DH public keys of
+/// the `senders`, sorted by index.
+///
+/// Shares from unknown senders are skipped, and the shares are not verified
+/// against any commitment.
+pub fn decrypt_shares(
+    dh_private_key: &DHPrivateKey,
+    senders: &[(u32, DHPublicKey)],
+    encrypted_shares: &[EncryptedSecretShare],
+) -> Result<Vec<SecretShare>, Error> {
+    let mut shares: Vec<SecretShare> = Vec::with_capacity(encrypted_shares.len());
+
+    for_each_decrypted_share(dh_private_key, senders, encrypted_shares, |_, _, _, share| {
+        shares.push(share?);
+        Ok(())
+    })?;
+
+    Ok(shares)
+}
+
+/// Interpolate the group key from the dealers' `commitments`, as done when
+/// finishing the key generation.
+pub fn calculate_group_key(commitments: &[VerifiableSecretSharingCommitment]) -> Result<GroupKey, Error> {
+    interpolate_group_key(commitments)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::keygen::collect_shares_for;
+    use crate::test_utils::all_encrypted_shares;
+    use crate::test_utils::run_dkg;
+    use crate::test_utils::run_round_one;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn seams_match_the_key_generation() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        let (participants, states) = run_dkg(&params, "Φ", &mut rng);
+
+        assert!(verify_round_one_proofs(&params, &participants, "Φ").is_empty());
+        assert_eq!(verify_round_one_proofs(&params, &participants, "Ψ"), vec![1, 2, 3]);
+
+        let commitments: Vec<VerifiableSecretSharingCommitment> = participants
+            .iter()
+            .map(|p| p.commitments.clone().unwrap())
+            .collect();
+        assert_eq!(calculate_group_key(&commitments).unwrap(), states[0].group_key().unwrap());
+
+        let (participants, dh_private_keys, states) = run_round_one(&params, "Φ", &mut rng);
+        let all_dealers_shares = all_encrypted_shares(&states);
+
+        let senders: Vec<(u32, DHPublicKey)> = participants
+            .iter()
+            .map(|p| (p.index, p.dh_public_key.clone()))
+            .collect();
+        let shares = decrypt_shares(&dh_private_keys[0], &senders, &collect_shares_for(1, &all_dealers_shares)).unwrap();
+        assert_eq!(shares.len(), 3);
+    }
+}
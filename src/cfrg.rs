@@ -0,0 +1,493 @@
+// -*- mode: rust; -*-
+//
+// This file is part of ice-frost.
+// Copyright (c) 2021-2022 Toposware Inc.
+// See LICENSE for licensing information.
+//
+// Authors:
+// - Toposware developers <dev@toposware.com>
+
+//! Conversions to the key packages of the CFRG FROST draft.
+//!
+//! Tooling built around the [CFRG draft] for FROST(ristretto255, SHA-512)
+//! exchanges keys as a `KeyPackage`, held by each signer, and a
+//! `PublicKeyPackage`, held by the coordinator. The draft identifies
+//! participants by non-zero scalars, which are the participant indices of this
+//! crate, and encodes scalars and group elements as this crate does, i.e. as
+//! 32-byte little-endian scalars and compressed Ristretto points, so that no
+//! coordinate adjustment is needed.
+//!
+//! [CFRG draft]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-frost/
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::convert::TryInto;
+use core::fmt;
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_TABLE;
+use curve25519_dalek::ristretto::CompressedRistretto;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar;
+
+use zeroize::Zeroize;
+
+use crate::keygen::Error;
+use crate::keygen::GroupKey;
+use crate::keygen::IndividualPublicKey;
+use crate::keygen::SecretKey;
+use crate::parameters::Parameters;
+
+fn decompress(bytes: &[u8]) -> Result<RistrettoPoint, Error> {
+    CompressedRistretto::from_slice(bytes).decompress().ok_or(Error::SerialisationError)
+}
+
+fn scalar_from_bytes(bytes: &[u8]) -> Result<Scalar, Error> {
+    Scalar::from_canonical_bytes(bytes.try_into().map_err(|_| Error::SerialisationError)?)
+        .ok_or(Error::SerialisationError)
+}
+
+/// The key material of a single signer, in the shape of the CFRG draft.
+///
+/// Its `Debug` output redacts the signing share, so that it can be logged.
+#[derive(Clone, Eq, PartialEq, Zeroize)]
+#[zeroize(drop)]
+pub struct KeyPackage {
+    /// The identifier of the signer, i.e. its participant index.
+    pub(crate) identifier: Scalar,
+    /// The signer's secret share of the group signing key.
+    pub(crate) signing_share: Scalar,
+    /// The public verification share of the signer.
+    pub(crate) verifying_share: RistrettoPoint,
+    /// The group public key.
+    pub(crate) verifying_key: RistrettoPoint,
+    /// The minimum number of signers, i.e. the threshold.
+    pub(crate) min_signers: u32,
+}
+
+impl fmt::Debug for KeyPackage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KeyPackage")
+            .field("identifier", &self.identifier)
+            .field("signing_share", &"<redacted>")
+            .field("verifying_share", &self.verifying_share.compress())
+            .field("verifying_key", &self.verifying_key.compress())
+            .field("min_signers", &self.min_signers)
+            .finish()
+    }
+}
+
+impl KeyPackage {
+    /// Build the key package of the holder of `secret_key` in a group with
+    /// the given `group_key` and `parameters`.
+    pub fn new(secret_key: &SecretKey, group_key: &GroupKey, parameters: &Parameters) -> KeyPackage {
+        KeyPackage {
+            identifier: Scalar::from(secret_key.index),
+            signing_share: secret_key.key,
+            verifying_share: &RISTRETTO_BASEPOINT_TABLE * &secret_key.key,
+            verifying_key: group_key.0,
+            min_signers: parameters.t,
+        }
+    }
+
+    /// Get the encoding of the identifier of the signer.
+    pub fn identifier(&self) -> [u8; 32] {
+        self.identifier.to_bytes()
+    }
+
+    /// Get the encoding of the signer's secret share.
+    pub fn signing_share(&self) -> [u8; 32] {
+        self.signing_share.to_bytes()
+    }
+
+    /// Get the encoding of the signer's public verification share.
+    pub fn verifying_share(&self) -> [u8; 32] {
+        self.verifying_share.compress().to_bytes()
+    }
+
+    /// Get the encoding of the group public key.
+    pub fn verifying_key(&self) -> [u8; 32] {
+        self.verifying_key.compress().to_bytes()
+    }
+
+    /// Get the minimum number of signers of the group.
+    pub fn min_signers(&self) -> u32 {
+        self.min_signers
+    }
+
+    /// Serialise this key package to an array of bytes, as the concatenation
+    /// of the encodings of the identifier, the signing share, the verifying
+    /// share and the verifying key, followed by the minimum number of signers.
+    pub fn to_bytes(&self) -> [u8; 132] {
+        let mut res = [0u8; 132];
+        res[0..32].copy_from_slice(&self.identifier());
+        res[32..64].copy_from_slice(&self.signing_share());
+        res[64..96].copy_from_slice(&self.verifying_share());
+        res[96..128].copy_from_slice(&self.verifying_key());
+        res[128..132].copy_from_slice(&self.min_signers.to_le_bytes());
+
+        res
+    }
+
+    /// Deserialise this array of bytes to a `KeyPackage`, checking that the
+    /// verifying share matches the signing share.
+    pub fn from_bytes(bytes: &[u8; 132]) -> Result<KeyPackage, Error> {
+        let identifier = scalar_from_bytes(&bytes[0..32])?;
+        let signing_share = scalar_from_bytes(&bytes[32..64])?;
+        let verifying_share = decompress(&bytes[64..96])?;
+        let verifying_key = decompress(&bytes[96..128])?;
+        let min_signers = u32::from_le_bytes(
+            bytes[128..132]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        );
+
+        if identifier == Scalar::zero() || verifying_share != &RISTRETTO_BASEPOINT_TABLE * &signing_share {
+            return Err(Error::SerialisationError);
+        }
+
+        Ok(KeyPackage { identifier, signing_share, verifying_share, verifying_key, min_signers })
+    }
+}
+
+/// The version of the key material of a group, for audit purposes.
+///
+/// Refreshing or resharing the signers' shares leaves the [`GroupKey`]
+/// unchanged, so this is the only way to tell the successive generations of
+/// verifying shares of a group apart. It is not part of the CFRG draft, and
+/// carries no cryptographic meaning.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KeyVersion(pub u64);
+
+impl KeyVersion {
+    /// Get the version following this one.
+    ///
+    /// # Returns
+    ///
+    /// The next version, or an [`Error::KeyVersionOverflow`] if this is the
+    /// last one.
+    pub fn next(&self) -> Result<KeyVersion, Error> {
+        self.0.checked_add(1).map(KeyVersion).ok_or(Error::KeyVersionOverflow)
+    }
+}
+
+/// The public key material of a group, in the shape of the CFRG draft.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicKeyPackage {
+    /// The identifiers and public verification shares of the signers, sorted
+    /// by identifier.
+    pub(crate) verifying_shares: Vec<(Scalar, RistrettoPoint)>,
+    /// The group public key.
+    pub(crate) verifying_key: RistrettoPoint,
+    /// The version of the verifying shares, if tracked.
+    pub(crate) version: Option<KeyVersion>,
+}
+
+impl PublicKeyPackage {
+    /// Build the public key package of a group from the public keys of its
+    /// signers and its `group_key`.
+    pub fn new(individual_public_keys: &[IndividualPublicKey], group_key: &GroupKey) -> PublicKeyPackage {
+        let mut public_keys: Vec<&IndividualPublicKey> = individual_public_keys.iter().collect();
+        public_keys.sort_by_key(|public_key| public_key.index);
+
+        PublicKeyPackage {
+            verifying_shares: public_keys
+                .iter()
+                .map(|public_key| (Scalar::from(public_key.index), public_key.share))
+                .collect(),
+            verifying_key: group_key.0,
+            version: None,
+        }
+    }
+
+    /// Set the [`KeyVersion`] of this public key package.
+    pub fn with_version(mut self, version: KeyVersion) -> PublicKeyPackage {
+        self.version = Some(version);
+        self
+    }
+
+    /// Get the [`KeyVersion`] of this public key package, if any.
+    pub fn version(&self) -> Option<KeyVersion> {
+        self.version
+    }
+
+    /// Build the public key package of this group after a refresh or a
+    /// resharing of its signers' shares, from their new public keys.
+    ///
+    /// The version of the new package follows the one of this package, a
+    /// package without version being considered at [`KeyVersion::default`].
+    ///
+    /// # Returns
+    ///
+    /// The new [`PublicKeyPackage`], or an [`Error::InvalidGroupKey`] if the
+    /// `group_key` changed, in which case this is a new group rather than a
+    /// refreshed one, or an [`Error::KeyVersionOverflow`] if the version of
+    /// this package is the last one.
+    pub fn refreshed(
+        &self,
+        individual_public_keys: &[IndividualPublicKey],
+        group_key: &GroupKey,
+    ) -> Result<PublicKeyPackage, Error> {
+        if group_key.0 != self.verifying_key {
+            return Err(Error::InvalidGroupKey);
+        }
+
+        let version = self.version.unwrap_or_default().next()?;
+
+        Ok(PublicKeyPackage::new(individual_public_keys, group_key).with_version(version))
+    }
+
+    /// Get the encodings of the identifiers and verifying shares of the
+    /// signers, sorted by identifier.
+    pub fn verifying_shares(&self) -> Vec<([u8; 32], [u8; 32])> {
+        self.verifying_shares
+            .iter()
+            .map(|(identifier, share)| (identifier.to_bytes(), share.compress().to_bytes()))
+            .collect()
+    }
+
+    /// Get the encoding of the group public key.
+    pub fn verifying_key(&self) -> [u8; 32] {
+        self.verifying_key.compress().to_bytes()
+    }
+
+    /// Serialise this public key package to a Vec of bytes, as the number of
+    /// signers followed by the encodings of their identifiers and verifying
+    /// shares, and of the verifying key, and then the version if any.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let len = self.verifying_shares.len();
+        let mut res = Vec::with_capacity(4 + 64 * len + 32 + 8);
+
+        res.extend_from_slice(&TryInto::<u32>::try_into(len).unwrap().to_le_bytes());
+        for (identifier, share) in self.verifying_shares() {
+            res.extend_from_slice(&identifier);
+            res.extend_from_slice(&share);
+        }
+        res.extend_from_slice(&self.verifying_key());
+        if let Some(version) = self.version {
+            res.extend_from_slice(&version.0.to_le_bytes());
+        }
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `PublicKeyPackage`
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicKeyPackage, Error> {
+        let len = u32::from_le_bytes(
+            bytes
+                .get(0..4)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ) as usize;
+
+        let unversioned_len = 4 + 64 * len + 32;
+        if bytes.len() != unversioned_len && bytes.len() != unversioned_len + 8 {
+            return Err(Error::SerialisationError);
+        }
+
+        let mut verifying_shares = Vec::with_capacity(len);
+        let mut index_slice = 4;
+
+        for _ in 0..len {
+            let identifier = scalar_from_bytes(&bytes[index_slice..index_slice + 32])?;
+            let share = decompress(&bytes[index_slice + 32..index_slice + 64])?;
+            verifying_shares.push((identifier, share));
+            index_slice += 64;
+        }
+
+        let verifying_key = decompress(&bytes[index_slice..index_slice + 32])?;
+        index_slice += 32;
+
+        let version = match bytes.get(index_slice..index_slice + 8) {
+            Some(version) => Some(KeyVersion(u64::from_le_bytes(
+                version.try_into().map_err(|_| Error::SerialisationError)?
+            ))),
+            None => None,
+        };
+
+        Ok(PublicKeyPackage { verifying_shares, verifying_key, version })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils::trusted_dealer_keys;
+
+    /// The group public key of the FROST(ristretto255, SHA-512) test vectors
+    /// of RFC 9591.
+    const GROUP_PUBLIC_KEY: &str = "e2a62f39eede11269e3bd5a7d97554f5ca384f9f6d3dd9c3c0d05083c7254f57";
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn key_packages_match_draft_test_vectors() {
+        // The FROST(ristretto255, SHA-512) test vectors of the CFRG draft,
+        // for a 2-out-of-3 group.
+        let group_secret_key = from_hex("1b25a55e463cfd15cf14a5d3acc3d15053f08da49c8afcf3ab265f2ebc4f970b");
+        let group_public_key = from_hex(GROUP_PUBLIC_KEY);
+        let participant_shares = [
+            from_hex("5c3430d391552f6e60ecdc093ff9f6f4488756aa6cebdbad75a768010b8f830e"),
+            from_hex("b06fc5eac20b4f6e1b271d9df2343d843e1e1fb03c4cbb673f2872d459ce6f01"),
+            from_hex("f17e505f0e2581c6acfe54d3846a622834b5e7b50cad9a2109a97ba7a80d5c04"),
+        ];
+
+        let params = Parameters { n: 3, t: 2 };
+        let group_key = GroupKey::new(
+            &RISTRETTO_BASEPOINT_TABLE * &Scalar::from_canonical_bytes(group_secret_key).unwrap());
+        assert_eq!(group_key.to_bytes(), group_public_key);
+
+        let secret_keys: Vec<SecretKey> = participant_shares
+            .iter()
+            .zip(1..=params.n)
+            .map(|(share, index)| SecretKey { index, key: Scalar::from_canonical_bytes(*share).unwrap() })
+            .collect();
+
+        for (secret_key, share) in secret_keys.iter().zip(participant_shares.iter()) {
+            let key_package = KeyPackage::new(secret_key, &group_key, &params);
+            let bytes = key_package.to_bytes();
+
+            let mut identifier = [0u8; 32];
+            identifier[0] = secret_key.index as u8;
+            let verifying_share = secret_key.to_public().share.compress().to_bytes();
+
+            assert_eq!(bytes[0..32], identifier);
+            assert_eq!(bytes[32..64], share[..]);
+            assert_eq!(bytes[64..96], verifying_share);
+            assert_eq!(bytes[96..128], group_public_key);
+            assert_eq!(key_package.min_signers(), 2);
+
+            assert_eq!(KeyPackage::from_bytes(&bytes).unwrap(), key_package);
+        }
+
+        let public_keys: Vec<IndividualPublicKey> = secret_keys.iter().rev().map(|sk| sk.to_public()).collect();
+        let public_key_package = PublicKeyPackage::new(&public_keys, &group_key);
+
+        assert_eq!(public_key_package.verifying_key(), group_public_key);
+        for ((identifier, share), secret_key) in public_key_package.verifying_shares().iter().zip(secret_keys.iter()) {
+            assert_eq!(*identifier, Scalar::from(secret_key.index).to_bytes());
+            assert_eq!(*share, secret_key.to_public().share.compress().to_bytes());
+        }
+
+        let bytes = public_key_package.to_bytes();
+        assert_eq!(PublicKeyPackage::from_bytes(&bytes).unwrap(), public_key_package);
+        assert!(PublicKeyPackage::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        // A key package whose verifying share does not match its signing share is rejected.
+        let mut bytes = KeyPackage::new(&secret_keys[0], &group_key, &params).to_bytes();
+        bytes[64..96].copy_from_slice(&group_public_key);
+        assert!(KeyPackage::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn key_package_debug_redacts_signing_share() {
+        let params = Parameters { n: 3, t: 2 };
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, rand::rngs::OsRng);
+
+        let package = KeyPackage::new(&secret_keys[0], &group_key, &params);
+        let debug = std::format!("{:?}", package);
+
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains(&std::format!("{:?}", package.signing_share)));
+        assert!(debug.contains(&std::format!("{:?}", package.verifying_key.compress())));
+    }
+
+    #[test]
+    fn refreshes_bump_the_key_version() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng = rand::rngs::OsRng;
+
+        let (group_key, mut secret_keys) = trusted_dealer_keys(&params, &mut rng);
+
+        let public_keys: Vec<IndividualPublicKey> = secret_keys.iter().map(|sk| sk.to_public()).collect();
+        let package = PublicKeyPackage::new(&public_keys, &group_key);
+        assert_eq!(package.version(), None);
+
+        let mut packages = vec![package];
+        for _ in 0..2 {
+            // Refresh the shares with a polynomial g(x) = b1.x, which preserves the group key.
+            let b1 = Scalar::random(&mut rng);
+            for secret_key in secret_keys.iter_mut() {
+                secret_key.key += b1 * Scalar::from(secret_key.index);
+            }
+
+            let public_keys: Vec<IndividualPublicKey> = secret_keys.iter().map(|sk| sk.to_public()).collect();
+            let refreshed = packages.last().unwrap().refreshed(&public_keys, &group_key).unwrap();
+            packages.push(refreshed);
+        }
+
+        assert_eq!(packages[1].version(), Some(KeyVersion(1)));
+        assert_eq!(packages[2].version(), Some(KeyVersion(2)));
+        for package in packages.iter() {
+            assert_eq!(package.verifying_key(), group_key.to_bytes());
+        }
+        assert_ne!(packages[1].verifying_shares(), packages[2].verifying_shares());
+
+        // The version survives serialisation, and unversioned packages still parse.
+        for package in packages.iter() {
+            assert_eq!(&PublicKeyPackage::from_bytes(&package.to_bytes()).unwrap(), package);
+        }
+
+        // A different group key is not a refresh.
+        let other_group_key = GroupKey::new(&RISTRETTO_BASEPOINT_TABLE * &Scalar::random(&mut rng));
+        assert_eq!(packages[2].refreshed(&public_keys, &other_group_key), Err(Error::InvalidGroupKey));
+
+        // The version does not wrap around.
+        assert_eq!(KeyVersion(u64::MAX - 1).next(), Ok(KeyVersion(u64::MAX)));
+        assert_eq!(KeyVersion(u64::MAX).next(), Err(Error::KeyVersionOverflow));
+        let last = packages[2].clone().with_version(KeyVersion(u64::MAX));
+        assert_eq!(last.refreshed(&public_keys, &group_key), Err(Error::KeyVersionOverflow));
+    }
+
+    #[test]
+    fn binding_factors_match_draft_test_vectors() {
+        use crate::signature::compute_binding_factors;
+        use crate::signature::compute_group_commitment;
+
+        // The round one outputs of the FROST(ristretto255, SHA-512) test
+        // vectors of RFC 9591, for the signers 1 and 3 and the message "test".
+        let group_key = GroupKey::from_bytes(&from_hex(GROUP_PUBLIC_KEY)).unwrap();
+        let message = b"test";
+        let point = |hex: &str| decompress(&from_hex(hex)).unwrap();
+        let commitments = [
+            (
+                1,
+                point("965def4d0958398391fc06d8c2d72932608b1e6255226de4fb8d972dac15fd57"),
+                point("ec5170920660820007ae9e1d363936659ef622f99879898db86e5bf1d5bf2a14"),
+            ),
+            (
+                3,
+                point("480e06e3de182bf83489c45d7441879932fd7b434a26af41455756264fbd5d6e"),
+                point("3064746dfd3c1862ef58fc68c706da287dd925066865ceacc816b3a28c7b363b"),
+            ),
+        ];
+
+        let binding_factors = compute_binding_factors(message, &commitments, &group_key);
+        assert_eq!(binding_factors.len(), 2);
+        assert_eq!(
+            binding_factors[&1].to_bytes(),
+            from_hex("8967fd70fa06a58e5912603317fa94c77626395a695a0e4e4efc4476662eba0c")
+        );
+        assert_eq!(
+            binding_factors[&3].to_bytes(),
+            from_hex("f2c1bb7c33a10511158c2f1766a4a5fadf9f86f2a92692ed333128277cc31006")
+        );
+
+        // The group commitment is the first half of the final signature.
+        let group_commitment = compute_group_commitment(&commitments, &binding_factors).unwrap();
+        assert_eq!(
+            group_commitment.compress().to_bytes(),
+            from_hex("fc45655fbc66bbffad654ea4ce5fdae253a49a64ace25d9adb62010dd9fb2555")
+        );
+    }
+}
@@ -497,11 +497,15 @@
 #[cfg(feature = "std")]
 use std::boxed::Box;
 #[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(feature = "std")]
 use std::vec::Vec;
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
 #[cfg(feature = "alloc")]
@@ -509,16 +513,29 @@ use alloc::string::{String, ToString};
 #[cfg(feature = "std")]
 use std::string::{String, ToString};
 
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::btree_map::Entry;
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::collections::btree_map::Entry;
+
 use core::convert::TryInto;
 use core::fmt;
 use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::mem::size_of;
 use core::ops::Deref;
+use core::sync::atomic::{self, AtomicBool};
 
 use curve25519_dalek::constants::RISTRETTO_BASEPOINT_TABLE;
 use curve25519_dalek::ristretto::CompressedRistretto;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
 use curve25519_dalek::traits::Identity;
+use curve25519_dalek::traits::VartimeMultiscalarMul;
 
 use rand::CryptoRng;
 use rand::RngCore;
@@ -528,8 +545,13 @@ use sha2::Sha512;
 
 use hkdf::Hkdf;
 
+use hmac::{Hmac, Mac, NewMac};
+
+use subtle::{Choice, ConstantTimeEq};
+
 use zeroize::Zeroize;
 
+use crate::nizk::DkgHash;
 use crate::nizk::NizkOfSecretKey;
 use crate::parameters::Parameters;
 use crate::signature::calculate_lagrange_coefficients;
@@ -542,18 +564,28 @@ use aes::cipher::{
 };
 
 /// Errors that may happen during Key Generation
+///
+/// This enum is `#[non_exhaustive]`: new variants may be added in minor
+/// releases, so matches outside this crate must include a wildcard arm.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     /// Serialisation error
     SerialisationError,
     /// Encrypted secret share decryption failure
     DecryptionError,
+    /// The key derivation for encrypting or decrypting a secret share failed
+    KdfError,
+    /// The authentication tag of an encrypted secret share is not correct
+    IntegrityError,
     /// Secret share verification failure
     ShareVerificationError,
     /// Complaint verification failure
     ComplaintVerificationError,
     /// GroupKey generation failure
     InvalidGroupKey,
+    /// A commitment does not match the parameters, or commits to an identity public key
+    InvalidCommitment,
     /// Invalid NiZK proof of knowledge
     InvalidProofOfKnowledge,
     /// The participant is missing some others' secret shares
@@ -562,10 +594,30 @@ pub enum Error {
     NoEncryptedShares,
     /// At least one complaint has been issued during to_round_two() execution
     Complaint(Vec::<Complaint>),
+    /// The participant with this index sent an invalid share, during to_round_two_strict() execution
+    BadShare(u32),
     /// Not all participants have been included
     InvalidNumberOfParticipants(usize, u32),
     /// Too many invalid participants, with their indices
     TooManyInvalidParticipants(Vec::<u32>),
+    /// A participant index is zero or above the parameters' `max_index`
+    InvalidParticipantIndex(u32),
+    /// The threshold is zero or above the number of participants
+    InvalidParameters,
+    /// The commitment of the participant with this index does not carry a proof of knowledge for this key generation session
+    MismatchedSession(u32),
+    /// The receipt of the participant with this index does not acknowledge the given share
+    InvalidShareReceipt(u32),
+    /// The random number generator failed to provide randomness
+    RandomnessError,
+    /// The key version cannot be incremented any further
+    KeyVersionOverflow,
+    /// This round one state, or a clone of it, has already been advanced to round two
+    StateAlreadyAdvanced,
+    /// The encrypted secret shares of this participant have already been computed
+    SharesAlreadyComputed,
+    /// The participant with this index is not among the validated participants of this key generation
+    UnapprovedParticipant(u32),
     /// Custom error
     Custom(String),
 }
@@ -579,6 +631,12 @@ impl fmt::Display for Error {
             Error::DecryptionError => {
                 write!(f, "Could not decrypt encrypted share.")
             },
+            Error::KdfError => {
+                write!(f, "Could not derive the key to encrypt or decrypt a secret share.")
+            },
+            Error::IntegrityError => {
+                write!(f, "The authentication tag of the encrypted share is not correct.")
+            },
             Error::ShareVerificationError => {
                 write!(f, "The secret share is not correct.")
             },
@@ -588,6 +646,9 @@ impl fmt::Display for Error {
             Error::InvalidGroupKey => {
                 write!(f, "Could not generate a valid group key with the given commitments.")
             },
+            Error::InvalidCommitment => {
+                write!(f, "The commitment is not consistent with the parameters.")
+            },
             Error::InvalidProofOfKnowledge => {
                 write!(f, "The NiZK proof of knowledge of the secret key is not correct.")
             }
@@ -600,12 +661,42 @@ impl fmt::Display for Error {
             Error::Complaint(complaints) => {
                 write!(f, "{:?}", complaints)
             },
+            Error::BadShare(index) => {
+                write!(f, "The participant {} sent an invalid share.", index)
+            },
             Error::InvalidNumberOfParticipants(nb, n_params) => {
                 write!(f, "The number of participants {} does not match DKG instance parameters {}.", nb, n_params)
             },
             Error::TooManyInvalidParticipants(indices) => {
                 write!(f, "Too many invalid participants to continue the DKG: {:?}", indices)
             },
+            Error::InvalidParticipantIndex(index) => {
+                write!(f, "The participant index {} is out of range.", index)
+            },
+            Error::InvalidParameters => {
+                write!(f, "The threshold must lie between 1 and the number of participants.")
+            },
+            Error::MismatchedSession(index) => {
+                write!(f, "The commitment of participant {} belongs to another key generation session.", index)
+            },
+            Error::InvalidShareReceipt(index) => {
+                write!(f, "The receipt of participant {} does not acknowledge this share.", index)
+            },
+            Error::RandomnessError => {
+                write!(f, "The random number generator failed.")
+            },
+            Error::KeyVersionOverflow => {
+                write!(f, "The key version cannot be incremented any further.")
+            },
+            Error::StateAlreadyAdvanced => {
+                write!(f, "This round one state has already been advanced to round two.")
+            },
+            Error::SharesAlreadyComputed => {
+                write!(f, "The encrypted shares have already been computed.")
+            },
+            Error::UnapprovedParticipant(index) => {
+                write!(f, "The participant {} is not among the validated participants.", index)
+            },
             Error::Custom(string) => {
                 write!(f, "{:?}", string)
             },
@@ -632,33 +723,64 @@ impl Coefficients {
         for elem in tmp.iter_mut() {
             res.extend_from_slice(elem);
         }
+        tmp.zeroize();
 
         res
     }
 
     /// Deserialise this slice of bytes to a `Coefficients`
+    ///
+    /// The coefficients read so far are zeroized if the bytes turn out to be
+    /// invalid.
     pub fn from_bytes(bytes: &[u8]) -> Result<Coefficients, Error> {
         let len = u32::from_le_bytes(
-            bytes[0..4]
+            bytes
+                .get(0..4)
+                .ok_or(Error::SerialisationError)?
                 .try_into()
                 .map_err(|_| Error::SerialisationError)?,
-        );
-        let mut points: Vec<Scalar> =
-            Vec::with_capacity(len as usize);
+        ) as usize;
+
+        let expected_len = len.checked_mul(32).and_then(|l| l.checked_add(4)).ok_or(Error::SerialisationError)?;
+        if bytes.len() < expected_len {
+            return Err(Error::SerialisationError);
+        }
+
+        // Dropping the coefficients on the error path zeroizes them.
+        let mut coefficients = Coefficients(Vec::with_capacity(len));
         let mut index_slice = 4usize;
         let mut array = [0u8; 32];
 
         for _ in 0..len {
             array.copy_from_slice(&bytes[index_slice..index_slice + 32]);
-            points.push(
-                Scalar::from_canonical_bytes(array)
-                    .ok_or(Error::SerialisationError)?,
-            );
+            let point = Scalar::from_canonical_bytes(array);
+            array.zeroize();
+
+            coefficients.0.push(point.ok_or(Error::SerialisationError)?);
             index_slice += 32;
         }
 
-        Ok(Coefficients(points))
+        Ok(coefficients)
+    }
+}
+
+/// Check that every higher-degree coefficient of the polynomial `a` differs
+/// from the one of same degree in `b`, regardless of their constant terms.
+///
+/// When a dealer reshares the same secret twice, both polynomials share their
+/// constant term, and this should always hold so that shares from a previous
+/// resharing are invalidated. The comparison is made in constant time.
+pub fn coefficients_differ(a: &Coefficients, b: &Coefficients) -> bool {
+    if a.0.len() != b.0.len() {
+        return true;
+    }
+
+    let mut any_equal = Choice::from(0u8);
+    for (a_j, b_j) in a.0.iter().zip(b.0.iter()).skip(1) {
+        any_equal |= a_j.ct_eq(b_j);
     }
+
+    !bool::from(any_equal)
 }
 
 /// A commitment to a participant's secret polynomial coefficients for Feldman's
@@ -671,6 +793,72 @@ pub struct VerifiableSecretSharingCommitment {
     pub points: Vec<RistrettoPoint>,
 }
 
+/// Compute the `len` first powers of `term`, starting at `term^0`.
+fn powers_of(term: &Scalar, len: usize) -> Vec<Scalar> {
+    let mut powers: Vec<Scalar> = Vec::with_capacity(len);
+    let mut power = Scalar::one();
+
+    for _ in 0..len {
+        powers.push(power);
+        power *= term;
+    }
+
+    powers
+}
+
+/// Compute \\(\\sum_j \\lambda_j \\cdot g^{P_j(i)}\\) for the polynomials P_j attested
+/// to by the `commitments`, where \\(\\lambda_j\\) are the Lagrange coefficients of
+/// the commitment indices, with a single multiscalar multiplication.
+fn interpolate_commitments(
+    participant_index: u32,
+    commitments: &[VerifiableSecretSharingCommitment],
+) -> Result<RistrettoPoint, Error>
+{
+    let max_len = commitments.iter().map(|c| c.points.len()).max().unwrap_or(0);
+
+    interpolate_commitments_of_len(participant_index, commitments, max_len)
+}
+
+/// Compute the same interpolation as [`interpolate_commitments`], for
+/// `commitments` holding at most `max_len` points each, which sizes all the
+/// buffers upfront.
+fn interpolate_commitments_of_len(
+    participant_index: u32,
+    commitments: &[VerifiableSecretSharingCommitment],
+    max_len: usize,
+) -> Result<RistrettoPoint, Error>
+{
+    debug_assert!(commitments.iter().all(|c| c.points.len() <= max_len));
+
+    let mut index_vector: Vec<u32> = Vec::with_capacity(commitments.len());
+    index_vector.extend(commitments.iter().map(|c| c.index));
+
+    let powers = powers_of(&participant_index.into(), max_len);
+
+    let mut scalars: Vec<Scalar> = Vec::with_capacity(max_len * commitments.len());
+    let mut points: Vec<RistrettoPoint> = Vec::with_capacity(max_len * commitments.len());
+
+    for (position, commitment) in commitments.iter().enumerate() {
+        // The Lagrange coefficients skip the indices equal to their own, so
+        // that duplicate or zero indices would silently yield a wrong point.
+        // Checking the previous indices costs as much as computing the
+        // coefficient itself.
+        if commitment.index == 0 || index_vector[..position].contains(&commitment.index) {
+            return Err(Error::InvalidParticipantIndex(commitment.index));
+        }
+
+        let coeff = calculate_lagrange_coefficients(&commitment.index, &index_vector)
+            .map_err(|_| Error::InvalidParticipantIndex(commitment.index))?;
+
+        for (power, point) in powers.iter().zip(commitment.points.iter()) {
+            scalars.push(coeff * power);
+            points.push(*point);
+        }
+    }
+
+    Ok(RistrettoPoint::vartime_multiscalar_mul(scalars, points))
+}
+
 impl VerifiableSecretSharingCommitment {
     /// Retrieve \\( \alpha_{i0} * B \\), where \\( B \\) is the Ristretto basepoint.
     pub fn public_key(&self) -> Option<&RistrettoPoint> {
@@ -680,6 +868,47 @@ impl VerifiableSecretSharingCommitment {
         None
     }
 
+    /// Check that this commitment is internally consistent with the given
+    /// `parameters`, before processing any share against it.
+    ///
+    /// The commitment must hold exactly \\(t\\) points, and its public key
+    /// must not be the identity. The points need no further torsion check,
+    /// since the Ristretto group has prime order.
+    pub fn validate(&self, parameters: &Parameters) -> Result<(), Error> {
+        if self.points.len() != parameters.t as usize {
+            return Err(Error::InvalidCommitment);
+        }
+
+        self.validate_public_key()
+    }
+
+    /// Check that this commitment has a public key, which is not the identity.
+    fn validate_public_key(&self) -> Result<(), Error> {
+        match self.public_key() {
+            Some(public_key) if *public_key != RistrettoPoint::identity() => Ok(()),
+            _ => Err(Error::InvalidCommitment),
+        }
+    }
+
+    /// Check whether this commitment and `other` commit to the same polynomial,
+    /// regardless of the participant index they are attributed to.
+    ///
+    /// # Usage
+    ///
+    /// A dealer must sample a fresh polynomial for every key generation session,
+    /// and a coordinator can detect a dealer reusing its polynomial by keeping
+    /// the commitments received during previous sessions, and checking each new
+    /// commitment against them with this method, e.g. before calling
+    /// `DistributedKeyGeneration::<RoundOne>::new_initial()`.
+    ///
+    /// Note that in a resharing, a dealer's commitment always starts with its
+    /// public verification share, hence only the whole commitments should be
+    /// compared, and not their public keys alone.
+    pub fn commitments_equal(&self, other: &VerifiableSecretSharingCommitment) -> bool {
+        self.points.len() == other.points.len() &&
+            self.points.iter().zip(other.points.iter()).all(|(a, b)| a == b)
+    }
+
     /// Evaluate g^P(i) without knowing the secret coefficients of the polynomial
     pub fn evaluate_hiding(&self, term: &Scalar) -> RistrettoPoint {
         let mut sum = RistrettoPoint::identity();
@@ -698,6 +927,16 @@ impl VerifiableSecretSharingCommitment {
         
     }
 
+    /// Evaluate g^P(i) without knowing the secret coefficients of the polynomial,
+    /// with a single multiscalar multiplication over the precomputed powers of `term`.
+    ///
+    /// This returns the same result as `evaluate_hiding`, and is much faster for
+    /// high-degree polynomials. It runs in variable time, which is fine as both
+    /// the commitments and the evaluation point are public.
+    pub fn evaluate_hiding_vartime(&self, term: &Scalar) -> RistrettoPoint {
+        RistrettoPoint::vartime_multiscalar_mul(powers_of(term, self.points.len()), self.points.iter())
+    }
+
     /// Serialise this commitment to the secret polynomial coefficients as a Vec of bytes
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut res: Vec<u8> = Vec::with_capacity(self.points.len() * 32 + 8);
@@ -717,6 +956,10 @@ impl VerifiableSecretSharingCommitment {
 
     /// Deserialise this slice of bytes to a `VerifiableSecretSharingCommitment`
     pub fn from_bytes(bytes: &[u8]) -> Result<VerifiableSecretSharingCommitment, Error> {
+        if bytes.len() < 8 {
+            return Err(Error::SerialisationError);
+        }
+
         let index = u32::from_le_bytes(
             bytes[0..4]
                 .try_into()
@@ -727,6 +970,16 @@ impl VerifiableSecretSharingCommitment {
                 .try_into()
                 .map_err(|_| Error::SerialisationError)?,
         );
+
+        // Check the announced length against the buffer before allocating anything.
+        let expected_len = (len as usize)
+            .checked_mul(32)
+            .and_then(|l| l.checked_add(8))
+            .ok_or(Error::SerialisationError)?;
+        if expected_len > bytes.len() {
+            return Err(Error::SerialisationError);
+        }
+
         let mut points: Vec<RistrettoPoint> =
             Vec::with_capacity(len as usize);
         let mut index_slice = 8usize;
@@ -785,6 +1038,12 @@ impl DHPublicKey {
     }
 
     /// Deserialise this slice of bytes to a `DHPublicKey`
+    ///
+    /// # Note
+    ///
+    /// Points are encoded with Ristretto, so any successfully decoded key
+    /// already lies in the prime-order group: there is no cofactor to clear,
+    /// and encodings which do not represent a valid Ristretto point are rejected.
     pub fn from_bytes(bytes: &[u8; 32]) -> Result<DHPublicKey, Error> {
         let key = CompressedRistretto(*bytes)
             .decompress()
@@ -820,6 +1079,10 @@ pub struct Participant {
     /// The zero-knowledge proof of knowledge of the DH private key.
     /// It is computed similarly to the proof_of_secret_key.
     pub proof_of_dh_private_key: NizkOfSecretKey,
+    /// Optional application-specific data attached to this participant, such
+    /// as an operator name or a network address. It is not covered by any of
+    /// the proofs above, and is only carried along for the caller's use.
+    pub metadata: Option<Vec<u8>>,
 }
 
 impl Participant {
@@ -850,6 +1113,19 @@ impl Participant {
     /// Diffie-Hellman private key for secret shares encryption which
     /// must be kept private.
     pub fn new_dealer(
+        parameters: &Parameters,
+        index: u32,
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> (Self, Coefficients, DHPrivateKey)
+    {
+        Self::new_dealer_with_hash::<Sha512>(parameters, index, context_string, rng)
+    }
+
+    /// Same as [`Participant::new_dealer`], except that the proofs of
+    /// knowledge are computed with the hash function `H`, which must match the
+    /// one of the [`DistributedKeyGeneration`] instance.
+    pub fn new_dealer_with_hash<H: DkgHash>(
         parameters: &Parameters,
         index: u32,
         context_string: &str,
@@ -857,7 +1133,7 @@ impl Participant {
     ) -> (Self, Coefficients, DHPrivateKey)
     {
         let (dealer, coeff_option, dh_private_key) =
-            Self::new_internal(parameters, false, index, None, context_string, &mut rng);
+            Self::new_internal::<H>(parameters, false, index, None, context_string, &mut rng);
         (dealer, coeff_option.unwrap(), dh_private_key)
     }
 
@@ -884,6 +1160,19 @@ impl Participant {
     /// signers's Diffie-Hellman private key for secret shares encryption
     /// which must be kept private, 
     pub fn new_signer(
+        parameters: &Parameters,
+        index: u32,
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> (Self, DHPrivateKey)
+    {
+        Self::new_signer_with_hash::<Sha512>(parameters, index, context_string, rng)
+    }
+
+    /// Same as [`Participant::new_signer`], except that the proof of
+    /// knowledge is computed with the hash function `H`, which must match the
+    /// one of the [`DistributedKeyGeneration`] instance.
+    pub fn new_signer_with_hash<H: DkgHash>(
         parameters: &Parameters,
         index: u32,
         context_string: &str,
@@ -891,11 +1180,11 @@ impl Participant {
     ) -> (Self, DHPrivateKey)
     {
         let (signer, _coeff_option, dh_private_key) =
-            Self::new_internal(parameters, true, index, None, context_string, &mut rng);
+            Self::new_internal::<H>(parameters, true, index, None, context_string, &mut rng);
         (signer, dh_private_key)
     }
 
-    fn new_internal(
+    fn new_internal<H: DkgHash>(
         parameters: &Parameters,
         is_signer: bool,
         index: u32,
@@ -919,7 +1208,7 @@ impl Participant {
 
         // Compute a proof of knowledge of dh_secret_key
         let proof_of_dh_private_key: NizkOfSecretKey =
-            NizkOfSecretKey::prove(&index, &dh_private_key, &dh_public_key, context_string, &mut rng);
+            NizkOfSecretKey::prove_with_hash::<H>(&index, &dh_private_key, &dh_public_key, context_string, &mut rng);
 
         if is_signer {
             // Signers don't need coefficients, commitments or proofs of secret key.
@@ -929,7 +1218,8 @@ impl Participant {
                     dh_public_key,
                     commitments: None,
                     proof_of_secret_key: None,
-                    proof_of_dh_private_key
+                    proof_of_dh_private_key,
+                    metadata: None,
                 },
                 None,
                 dh_private_key,
@@ -963,7 +1253,7 @@ impl Participant {
             //         the FROST paper: \alpha_i = (\mu_i, c_i), but we stick with Schnorr's
             //         original notation here.)
             let proof_of_secret_key: NizkOfSecretKey =
-                NizkOfSecretKey::prove(&index, &coefficients.0[0], commitments.public_key().unwrap(), context_string, rng);
+                NizkOfSecretKey::prove_with_hash::<H>(&index, &coefficients.0[0], commitments.public_key().unwrap(), context_string, rng);
 
             (
                 Participant {
@@ -971,7 +1261,8 @@ impl Participant {
                     dh_public_key,
                     commitments: Some(commitments),
                     proof_of_secret_key: Some(proof_of_secret_key),
-                    proof_of_dh_private_key
+                    proof_of_dh_private_key,
+                    metadata: None,
                 },
                 Some(coefficients),
                 dh_private_key
@@ -979,6 +1270,46 @@ impl Participant {
         }
     }
 
+    /// Rotate this participant's Diffie-Hellman keypair, leaving its
+    /// `commitments` and `proof_of_secret_key` untouched.
+    ///
+    /// # Usage
+    ///
+    /// The returned `DHPublicKey` and its proof of knowledge, which are also
+    /// stored in this participant, should be broadcast to every other
+    /// participant, so that subsequent secret shares are encrypted to the
+    /// new key. The returned `DHPrivateKey` replaces the previous one, which
+    /// can no longer decrypt shares sent to this participant.
+    pub fn rotate_dh_keypair(
+        &mut self,
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> (DHPublicKey, NizkOfSecretKey, DHPrivateKey)
+    {
+        self.rotate_dh_keypair_with_hash::<Sha512>(context_string, rng)
+    }
+
+    /// Same as [`Participant::rotate_dh_keypair`], except that the proof of
+    /// knowledge is computed with the hash function `H`, which must match the
+    /// one of the [`DistributedKeyGeneration`] instance.
+    pub fn rotate_dh_keypair_with_hash<H: DkgHash>(
+        &mut self,
+        context_string: &str,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> (DHPublicKey, NizkOfSecretKey, DHPrivateKey)
+    {
+        let dh_private_key = DHPrivateKey(Scalar::random(&mut rng));
+        let dh_public_key = DHPublicKey(&RISTRETTO_BASEPOINT_TABLE * &dh_private_key);
+
+        let proof_of_dh_private_key: NizkOfSecretKey =
+            NizkOfSecretKey::prove_with_hash::<H>(&self.index, &dh_private_key, &dh_public_key, context_string, &mut rng);
+
+        self.dh_public_key = dh_public_key.clone();
+        self.proof_of_dh_private_key = proof_of_dh_private_key.clone();
+
+        (dh_public_key, proof_of_dh_private_key, dh_private_key)
+    }
+
     /// Reshare this dealer's secret key to a new set of participants.
     /// 
     /// # Inputs
@@ -1002,7 +1333,29 @@ impl Participant {
     /// of the new set accordingly.
     /// It also returns a list of the valid / misbehaving participants
     /// of the new set for handling outside of this crate.
+    ///
+    /// # Randomness
+    ///
+    /// All the randomness of the resharing, i.e. the new DH private key, the
+    /// new polynomial coefficients, the proofs of knowledge and the share
+    /// encryption nonces, is drawn from `rng` and from nowhere else. Hence a
+    /// hardware RNG, or a precomputed stream of bytes from one, can be passed
+    /// as is to make the whole resharing rely on it.
     pub fn reshare(
+        parameters: &Parameters,
+        secret_key: SecretKey,
+        signers: &[Participant],
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<(Self, Vec<EncryptedSecretShare>, DKGParticipantList), Error>
+    {
+        Self::reshare_with_hash::<Sha512>(parameters, secret_key, signers, context_string, rng)
+    }
+
+    /// Same as [`Participant::reshare`], except that the proofs of knowledge
+    /// are computed and verified with the hash function `H`, which must match
+    /// the one of the [`DistributedKeyGeneration`] instance of the new set.
+    pub fn reshare_with_hash<H: DkgHash>(
         parameters: &Parameters,
         secret_key: SecretKey,
         signers: &[Participant],
@@ -1011,29 +1364,114 @@ impl Participant {
     ) -> Result<(Self, Vec<EncryptedSecretShare>, DKGParticipantList), Error>
     {
         let (dealer, coeff_option, dh_private_key) =
-            Self::new_internal(parameters, false, secret_key.index, Some(secret_key.key), context_string, &mut rng);
+            Self::new_internal::<H>(parameters, false, secret_key.index, Some(secret_key.key), context_string, &mut rng);
 
         // Unwrapping cannot panic here
         let coefficients = coeff_option.unwrap();
 
-        let (participant_state, participant_lists) = DistributedKeyGeneration::new_state_internal(
+        let (mut participant_state, participant_lists) = DistributedKeyGeneration::<RoundOne, H>::new_state_internal(
             parameters,
             &dh_private_key,
             &secret_key.index,
-            Some(&coefficients),
             signers,
             context_string,
             true,
             false,
-            &mut rng,
+            true,
         )?;
 
-        // Unwrapping cannot panic here
-        let encrypted_shares = participant_state.their_encrypted_secret_shares().unwrap().clone();
+        let encrypted_shares = participant_state.encrypt_shares_once(&coefficients, signers, &mut rng)?.clone();
 
         Ok((dealer, encrypted_shares, participant_lists))
     }
 
+    /// Reshare this dealer's secret key to a new set of participants, as
+    /// [`Participant::reshare`] does, additionally returning the dealer's new
+    /// [`VerifiableSecretSharingCommitment`].
+    ///
+    /// # Returns
+    ///
+    /// A distributed key generation protocol [`Participant`], its commitment
+    /// to its new secret polynomial, a `Vec<EncryptedSecretShare>` to be sent
+    /// to each participant of the new set accordingly, and a list of the valid
+    /// / misbehaving participants of the new set.
+    pub fn reshare_with_commitment(
+        parameters: &Parameters,
+        secret_key: SecretKey,
+        signers: &[Participant],
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<(Self, VerifiableSecretSharingCommitment, Vec<EncryptedSecretShare>, DKGParticipantList), Error>
+    {
+        let (dealer, encrypted_shares, participant_lists) =
+            Self::reshare(parameters, secret_key, signers, context_string, rng)?;
+
+        // Unwrapping cannot panic here, since a resharing participant is always a dealer
+        let commitment = dealer.commitments.clone().unwrap();
+
+        Ok((dealer, commitment, encrypted_shares, participant_lists))
+    }
+
+    /// Reshare this participant's secret key to the same set of participants
+    /// with a different threshold, keeping the group key unchanged.
+    ///
+    /// # Inputs
+    ///
+    /// * The current protocol instance [`Parameters`],
+    /// * The `new_threshold` of the group,
+    /// * This participant's `secret_key`,
+    /// * A reference to the list of signers, which must hold exactly one
+    ///   signer for each of the current participant indices,
+    /// * A context string to prevent replay attacks.
+    ///
+    /// # Usage
+    ///
+    /// This is a [`Participant::reshare`] with the new parameters
+    /// \\((n, t')\\). The signers run the key generation with the current
+    /// `parameters`, since these are the parameters of the dealers, and at
+    /// least \\(t\\) of the current participants must act as dealers for the
+    /// group key to be preserved.
+    ///
+    /// # Returns
+    ///
+    /// The same values as [`Participant::reshare`], or an
+    /// [`Error::InvalidParameters`] if the new threshold is not in
+    /// \\([1, n]\\), an [`Error::InvalidNumberOfParticipants`] or an
+    /// [`Error::InvalidParticipantIndex`] if the signers do not match the
+    /// current participant indices.
+    pub fn rekey(
+        parameters: &Parameters,
+        new_threshold: u32,
+        secret_key: SecretKey,
+        signers: &[Participant],
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<(Self, Vec<EncryptedSecretShare>, DKGParticipantList), Error>
+    {
+        let new_parameters = Parameters { n: parameters.n, t: new_threshold };
+        new_parameters.validate()?;
+
+        if signers.len() != parameters.n as usize {
+            return Err(Error::InvalidNumberOfParticipants(signers.len(), parameters.n));
+        }
+
+        let mut seen_indices: Vec<u32> = Vec::with_capacity(signers.len());
+        for signer in signers.iter() {
+            if signer.index == 0 || signer.index > parameters.n || seen_indices.contains(&signer.index) {
+                return Err(Error::InvalidParticipantIndex(signer.index));
+            }
+            seen_indices.push(signer.index);
+        }
+
+        Self::reshare(&new_parameters, secret_key, signers, context_string, rng)
+    }
+
+    /// Retrieve the points of this participant's commitment to its secret
+    /// polynomial coefficients, or `None` if this participant is a signer.
+    pub fn commitment_points(&self) -> Option<&[RistrettoPoint]> {
+        self.commitments.as_ref().map(|commitments| &commitments.points[..])
+    }
+
     /// Retrieve \\( \alpha_{i0} * B \\), where \\( B \\) is the Ristretto basepoint.
     ///
     /// This is used to pass into the final call to `DistributedKeyGeneration::<RoundTwo>.finish()`.
@@ -1045,6 +1483,39 @@ impl Participant {
         None
     }
 
+    /// Retrieve the [`PublicParticipant`] view of this participant, which is
+    /// the only part of it that should be broadcast to other participants.
+    pub fn to_public(&self) -> PublicParticipant {
+        PublicParticipant {
+            index: self.index,
+            dh_public_key: self.dh_public_key.clone(),
+            commitments: self.commitments.clone(),
+            proof_of_secret_key: self.proof_of_secret_key.clone(),
+            proof_of_dh_private_key: self.proof_of_dh_private_key.clone(),
+            metadata: self.metadata.clone(),
+        }
+    }
+
+    /// Check that this dealer, obtained from `Participant::reshare`, is
+    /// resharing its genuine secret share of the previous group key.
+    ///
+    /// # Inputs
+    ///
+    /// * The `old_commitments` of the dealers of the previous key generation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with either an empty `Ok` or `Err` value, depending on
+    /// whether or not the verification was successful.
+    pub fn verify_reshare(
+        &self,
+        old_commitments: &[VerifiableSecretSharingCommitment],
+    ) -> Result<(), Error> {
+        let new_commitment = self.commitments.as_ref().ok_or(Error::ShareVerificationError)?;
+
+        verify_reshare(old_commitments, new_commitment, self.index)
+    }
+
     /// Serialise this participant to a Vec of bytes
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut res: Vec<u8> = Vec::new();
@@ -1069,47 +1540,72 @@ impl Participant {
 
         res.extend_from_slice(&self.proof_of_dh_private_key.to_bytes());
 
+        match &self.metadata {
+            Some(m) => {
+                res.push(1u8);
+                res.extend_from_slice(&TryInto::<u32>::try_into(m.len()).unwrap().to_le_bytes());
+                res.extend_from_slice(m);
+            },
+            None => res.push(0u8),
+        }
+
         res
     }
 
     /// Deserialise this slice of bytes to a `Participant`
+    ///
+    /// The trailing metadata is optional, so that participants serialised
+    /// before it was introduced can still be read.
     pub fn from_bytes(bytes: &[u8]) -> Result<Participant, Error> {
         let index = u32::from_le_bytes(
-            bytes[0..4]
+            bytes
+                .get(0..4)
+                .ok_or(Error::SerialisationError)?
                 .try_into()
                 .map_err(|_| Error::SerialisationError)?,
         );
 
-        let mut array = [0u8; 32];
-        array.copy_from_slice(&bytes[4..36]);
-
-        let dh_public_key = DHPublicKey::from_bytes(&array)?;
+        let dh_public_key = DHPublicKey::from_bytes(
+            &bytes
+                .get(4..36)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        )?;
 
         let mut index_slice = 36;
-        let commitments = match bytes[index_slice] {
-            1u8 => {
+        let commitments = match bytes.get(index_slice) {
+            Some(1u8) => {
                 index_slice += 1;
                 let com = VerifiableSecretSharingCommitment::from_bytes(&bytes[index_slice..])?;
+                // The commitment must belong to this participant.
+                if com.index != index {
+                    return Err(Error::SerialisationError);
+                }
                 index_slice += 4 + 4 + com.points.len() * 32;
                 Some(com)
             },
-            0u8 => {
+            Some(0u8) => {
                 index_slice += 1;
                 None
             }
             _ => return Err(Error::SerialisationError),
         };
 
-        let proof_of_secret_key = match bytes[index_slice] {
-            1u8 => {
+        let proof_of_secret_key = match bytes.get(index_slice) {
+            Some(1u8) => {
                 index_slice += 1;
-                Some(NizkOfSecretKey::from_bytes(
-                    &bytes[index_slice..index_slice+64]
+                let proof = NizkOfSecretKey::from_bytes(
+                    &bytes
+                        .get(index_slice..index_slice + 64)
+                        .ok_or(Error::SerialisationError)?
                         .try_into()
                         .map_err(|_| Error::SerialisationError)?
-                )?)
+                )?;
+                index_slice += 64;
+                Some(proof)
             },
-            0u8 => {
+            Some(0u8) => {
                 index_slice += 1;
                 None
             },
@@ -1118,10 +1614,31 @@ impl Participant {
 
         let proof_of_dh_private_key =
             NizkOfSecretKey::from_bytes(
-                &bytes[index_slice..index_slice+64]
+                &bytes
+                    .get(index_slice..index_slice + 64)
+                    .ok_or(Error::SerialisationError)?
                     .try_into()
                     .map_err(|_| Error::SerialisationError)?
             )?;
+        index_slice += 64;
+
+        let metadata = match bytes.get(index_slice) {
+            Some(1u8) => {
+                index_slice += 1;
+                let len = u32::from_le_bytes(
+                    bytes
+                        .get(index_slice..index_slice + 4)
+                        .ok_or(Error::SerialisationError)?
+                        .try_into()
+                        .map_err(|_| Error::SerialisationError)?,
+                ) as usize;
+                index_slice += 4;
+                let end = index_slice.checked_add(len).ok_or(Error::SerialisationError)?;
+                Some(bytes.get(index_slice..end).ok_or(Error::SerialisationError)?.to_vec())
+            },
+            Some(0u8) | None => None,
+            _ => return Err(Error::SerialisationError),
+        };
 
         Ok(Participant {
             index,
@@ -1129,17 +1646,23 @@ impl Participant {
             commitments,
             proof_of_secret_key,
             proof_of_dh_private_key,
+            metadata,
         })
     }
 }
 
+impl Ord for Participant {
+    /// Participants are ordered by their indices. Two participants with the
+    /// same index compare as `Equal`, since duplicate indices are rejected
+    /// elsewhere.
+    fn cmp(&self, other: &Participant) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
 impl PartialOrd for Participant {
     fn partial_cmp(&self, other: &Participant) -> Option<Ordering> {
-        match self.index.cmp(&other.index) {
-            Ordering::Less => Some(Ordering::Less),
-            Ordering::Equal => None, // Participants cannot have the same index.
-            Ordering::Greater => Some(Ordering::Greater),
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -1149,2438 +1672,8109 @@ impl PartialEq for Participant {
     }
 }
 
-/// Module to implement trait sealing so that `DkgState` cannot be
-/// implemented for externally declared types.
-mod private {
-    pub trait Sealed {}
+impl Eq for Participant {}
 
-    impl Sealed for super::RoundOne {}
-    impl Sealed for super::RoundTwo {}
+/// The public view of a [`Participant`], i.e. the material which is meant
+/// to be broadcast to every other participant of the protocol.
+///
+/// It can only be built from a `Participant`, and does not hold any secret
+/// values such as the polynomial `Coefficients` or the `DHPrivateKey`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicParticipant {
+    /// The index of this participant.
+    pub index: u32,
+    /// The public key used to derive symmetric keys for encrypting and
+    /// decrypting shares.
+    pub dh_public_key: DHPublicKey,
+    /// The commitments to the participant's secret coefficients, if any.
+    pub commitments: Option<VerifiableSecretSharingCommitment>,
+    /// The zero-knowledge proof of knowledge of the secret key, if any.
+    pub proof_of_secret_key: Option<NizkOfSecretKey>,
+    /// The zero-knowledge proof of knowledge of the DH private key.
+    pub proof_of_dh_private_key: NizkOfSecretKey,
+    /// Optional application-specific data attached to this participant.
+    pub metadata: Option<Vec<u8>>,
 }
 
-/// State machine structures for holding intermediate values during a
-/// distributed key generation protocol run, to prevent misuse.
-#[derive(Clone, Debug)]
-pub struct DistributedKeyGeneration<S: DkgState> {
-    state: Box<ActualState>,
-    data: S,
+impl PublicParticipant {
+    /// Serialise this public participant to a Vec of bytes.
+    ///
+    /// The encoding is the same as the one of [`Participant::to_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Participant::from(self.clone()).to_bytes()
+    }
+
+    /// Deserialise this slice of bytes to a `PublicParticipant`
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicParticipant, Error> {
+        Participant::from_bytes(bytes).map(|p| p.to_public())
+    }
 }
 
-/// Shared state which occurs across all rounds of a threshold signing protocol run.
-#[derive(Clone, Debug, PartialEq, Eq)]
-struct ActualState {
-    /// The parameters for this instantiation of a threshold signature.
-    parameters: Parameters,
-    /// The index of the participant.
-    index: u32,
-    /// The DH private key for deriving a symmetric key to encrypt and decrypt
-    /// secret shares.
-    dh_private_key: DHPrivateKey,
-    /// The DH public key for deriving a symmetric key to encrypt and decrypt
-    /// secret shares.
-    dh_public_key: DHPublicKey,
-    /// A vector of tuples containing the index of each participant and that
-    /// respective participant's commitments to their private polynomial
-    /// coefficients.
-    their_commitments: Option<Vec<VerifiableSecretSharingCommitment>>,
-    /// A vector of ECPoints containing the index of each participant and that
-    /// respective participant's DH public key.
-    their_dh_public_keys: Vec<(u32, DHPublicKey)>,
-    /// The encrypted secret shares this participant has calculated for all the other participants.
-    their_encrypted_secret_shares: Option<Vec<EncryptedSecretShare>>,
-    /// The secret shares this participant has received from all the other participants.
-    my_secret_shares: Option<Vec<SecretShare>>,
-}
-
-impl ActualState {
-    /// Serialise this state to a Vec of bytes
-    pub fn to_bytes(&self) -> Vec<u8> {
-        let mut res: Vec<u8> = Vec::new();
-        res.extend_from_slice(&self.parameters.to_bytes());
-        res.extend_from_slice(&self.index.to_le_bytes());
-        res.extend_from_slice(&self.dh_private_key.to_bytes());
-        res.extend_from_slice(&self.dh_public_key.to_bytes());
-        
-        match &self.their_commitments {
-            Some(v) => {
-                res.push(1u8);
-                let mut tmp = v
-                    .iter()
-                    .map(|e| e.to_bytes())
-                    .collect::<Vec<Vec<u8>>>();
-                res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
-                for commitment in tmp.iter_mut() {
-                    res.extend_from_slice(commitment);
-                }
-            },
-            None => res.push(0u8),
+impl From<PublicParticipant> for Participant {
+    fn from(public: PublicParticipant) -> Participant {
+        Participant {
+            index: public.index,
+            dh_public_key: public.dh_public_key,
+            commitments: public.commitments,
+            proof_of_secret_key: public.proof_of_secret_key,
+            proof_of_dh_private_key: public.proof_of_dh_private_key,
+            metadata: public.metadata,
         }
+    }
+}
 
-        let mut tmp = self
-            .their_dh_public_keys
-            .iter()
-            .map(|e| (e.0.to_le_bytes(), e.1.to_bytes()))
-            .collect::<Vec<([u8; 4], [u8; 32])>>();
-        res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
-        for (index, keys) in tmp.iter_mut() {
-            res.extend_from_slice(index);
-            res.extend_from_slice(keys);
-        }
+/// Verify that a dealer's `new_commitment`, produced during a resharing, commits
+/// to the dealer's secret share of the previous group key.
+///
+/// The constant term of the new commitment must equal the public verification
+/// share of the dealer with index `dealer_index`, as recomputed from the
+/// `old_commitments` of the previous key generation.
+///
+/// # Returns
+///
+/// A `Result` with either an empty `Ok` or `Err` value, depending on
+/// whether or not the verification was successful.
+pub fn verify_reshare(
+    old_commitments: &[VerifiableSecretSharingCommitment],
+    new_commitment: &VerifiableSecretSharingCommitment,
+    dealer_index: u32,
+) -> Result<(), Error> {
+    if new_commitment.index != dealer_index {
+        return Err(Error::ShareVerificationError);
+    }
 
-        match &self.their_encrypted_secret_shares {
-            Some(v) => {
-                res.push(1u8);
-                let mut tmp = v.iter()
-                    .map(|e| e.to_bytes())
-                    .collect::<Vec<[u8; 56]>>();
-                res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
-                for elem in tmp.iter_mut() {
-                    res.extend_from_slice(elem);
-                }
-            },
-            None => res.push(0u8),
-        };
+    let new_public_key = new_commitment.public_key().ok_or(Error::ShareVerificationError)?;
+    let old_public_key = IndividualPublicKey::generate_from_commitments(dealer_index, old_commitments)?;
 
-        match &self.my_secret_shares {
-            Some(v) => {
-                res.push(1u8);
-                let mut tmp = v.iter()
-                    .map(|e| e.to_bytes())
-                    .collect::<Vec<[u8; 40]>>();
-                res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
-                for elem in tmp.iter_mut() {
-                    res.extend_from_slice(elem);
-                }
-            },
-            None => res.push(0u8),
-        };
-    
-        res
+    match old_public_key.share.compress() == new_public_key.compress() {
+        true => Ok(()),
+        false => Err(Error::ShareVerificationError),
     }
-    
-    /// Deserialise this slice of bytes to an `ActualState`
-    pub fn from_bytes(bytes: &[u8]) -> Result<ActualState, Error> {
-        let mut array = [0u8; 8];
-        array.copy_from_slice(&bytes[..8]);
-        let parameters = Parameters::from_bytes(&array)?;
-
-        let index = u32::from_le_bytes(
-            bytes[8..12]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
+}
 
-        let mut array = [0u8; 32];
-        array.copy_from_slice(&bytes[12..44]);
-        let dh_private_key = DHPrivateKey::from_bytes(&array)?;
+/// Verify that the commitments of the dealers of a resharing will reconstruct
+/// the `old_group_key`, before the new signers finish the key generation.
+///
+/// The constant terms of the `new_dealers_commitments` are the dealers' public
+/// verification shares, and their interpolation is the group key the new
+/// signers will obtain.
+///
+/// # Returns
+///
+/// A `Result` with either an empty `Ok`, or an [`Error::InvalidGroupKey`] if
+/// the resharing does not preserve the group key.
+pub fn verify_group_key_continuity(
+    old_group_key: &GroupKey,
+    new_dealers_commitments: &[VerifiableSecretSharingCommitment],
+) -> Result<(), Error> {
+    let new_group_key = interpolate_group_key(new_dealers_commitments)?;
+
+    match new_group_key == *old_group_key {
+        true => Ok(()),
+        false => Err(Error::InvalidGroupKey),
+    }
+}
 
-        array.copy_from_slice(&bytes[44..76]);
-        let dh_public_key = DHPublicKey::from_bytes(&array)?;
-        
-        let mut index_slice = 76usize;
+/// Hash the points of a commitment beyond its public key.
+fn higher_terms_digest(commitment: &VerifiableSecretSharingCommitment) -> [u8; 32] {
+    let mut h = Sha512::new();
+    h.update(b"ICE-FROST-commitment-terms");
+    for point in commitment.points.iter().skip(1) {
+        h.update(point.compress().as_bytes());
+    }
 
-        let their_commitments = match bytes[index_slice] {
-            1u8 => {
-                index_slice += 1;
-                let commit_len = u32::from_le_bytes(
-                    bytes[index_slice..index_slice + 4]
-                    .try_into()
-                    .map_err(|_| Error::SerialisationError)?,
-                );
-                let mut coms: Vec<VerifiableSecretSharingCommitment> = 
-                    Vec::with_capacity(commit_len as usize);
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&h.finalize()[..32]);
+    output
+}
 
-                index_slice += 4;
+/// The leaf of a dealer's commitment, made of its index, its public key and
+/// the digest of the other points of its commitment.
+type CommitmentLeaf = (u32, RistrettoPoint, [u8; 32]);
 
-                for _ in 0..commit_len {
-                    let com = VerifiableSecretSharingCommitment::from_bytes(&bytes[index_slice..])?;
-                    index_slice += 4 + 4 + com.points.len() * 32;
-                    coms.push(com);
-                }
+/// Compute the leaves of the dealers' `commitments`, sorted by index.
+///
+/// # Returns
+///
+/// The leaves, an [`Error::InvalidCommitment`] if a commitment has no public
+/// key, or commits to the identity, or an [`Error::InvalidParticipantIndex`]
+/// if an index is zero or appears twice.
+fn commitment_leaves(commitments: &[VerifiableSecretSharingCommitment]) -> Result<Vec<CommitmentLeaf>, Error> {
+    let mut leaves = Vec::with_capacity(commitments.len());
+
+    for commitment in commitments.iter() {
+        if commitment.index == 0 {
+            return Err(Error::InvalidParticipantIndex(0));
+        }
+        commitment.validate_public_key()?;
+        // This unwrap() cannot fail, since the public key was validated above.
+        leaves.push((commitment.index, *commitment.public_key().unwrap(), higher_terms_digest(commitment)));
+    }
+    leaves.sort_by_key(|leaf| leaf.0);
 
-                Some(coms)
-            },
-            0u8 => {
-                index_slice += 1;
-                None
-            },
-            _ => return Err(Error::SerialisationError),
-        };
+    if let Some(pair) = leaves.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        return Err(Error::InvalidParticipantIndex(pair[0].0));
+    }
 
-        let dh_key_len = u32::from_le_bytes(
-            bytes[index_slice..index_slice+4]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
-        let mut their_dh_public_keys: Vec<(u32, DHPublicKey)> = 
-            Vec::with_capacity(dh_key_len as usize);
+    Ok(leaves)
+}
 
-        index_slice += 4;
-        for _ in 0..dh_key_len {
-            let index = u32::from_le_bytes(
-                bytes[index_slice..index_slice+4]
-                    .try_into()
-                    .map_err(|_| Error::SerialisationError)?,
-            );
-            let key = DHPublicKey::from_bytes(
-                &bytes[index_slice+4..index_slice+36]
-                    .try_into()
-                    .map_err(|_| Error::SerialisationError)?
-            )?;
-            their_dh_public_keys.push((index, key));
-            index_slice += 36;
-        }
+/// Hash a `leaf` of the Merkle tree of the dealers' commitments.
+fn leaf_hash(leaf: &CommitmentLeaf) -> [u8; 32] {
+    let mut h = Sha512::new();
+    h.update(b"ICE-FROST-commitment-leaf");
+    h.update(leaf.0.to_le_bytes());
+    h.update(leaf.1.compress().as_bytes());
+    h.update(leaf.2);
+
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&h.finalize()[..32]);
+    output
+}
 
-        let their_encrypted_secret_shares = match bytes[index_slice] {
-            1u8 => {
-                index_slice += 1;
-                let shares_len = u32::from_le_bytes(
-                    bytes[index_slice..index_slice+4]
-                        .try_into()
-                        .map_err(|_| Error::SerialisationError)?,
-                );
-                let mut encrypted_shares: Vec<EncryptedSecretShare> = 
-                    Vec::with_capacity(shares_len as usize);
-        
-                index_slice += 4;
-                for _ in 0..shares_len {
-                    let share = EncryptedSecretShare::from_bytes(
-                        &bytes[index_slice..index_slice+56]
-                            .try_into()
-                            .map_err(|_| Error::SerialisationError)?
-                    )?;
-                    encrypted_shares.push(share);
-                    index_slice += 56;
-                }
+/// Hash the `left` and `right` children of a node of the Merkle tree of the
+/// dealers' commitments.
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut h = Sha512::new();
+    h.update(b"ICE-FROST-commitment-node");
+    h.update(left);
+    h.update(right);
+
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&h.finalize()[..32]);
+    output
+}
 
-                Some(encrypted_shares)
-            },
-            0u8 => {
-                index_slice += 1;
-                None
-            },
-            _ => return Err(Error::SerialisationError),
-        };
+/// Compute the level above `level` in the Merkle tree of the dealers'
+/// commitments. A last node without a sibling is moved up unchanged.
+fn merkle_parent_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!(),
+        })
+        .collect()
+}
 
-        let my_secret_shares = match bytes[index_slice] {
-            1u8 => {
-                index_slice += 1;
-                let shares_len = u32::from_le_bytes(
-                    bytes[index_slice..index_slice+4]
-                        .try_into()
-                        .map_err(|_| Error::SerialisationError)?,
-                );
-                let mut shares: Vec<SecretShare> = 
-                    Vec::with_capacity(shares_len as usize);
-        
-                index_slice += 4;
-                for _ in 0..shares_len {
-                    let share = SecretShare::from_bytes(
-                        &bytes[index_slice..index_slice+40]
-                            .try_into()
-                            .map_err(|_| Error::SerialisationError)?
-                    )?;
-                    shares.push(share);
-                    index_slice += 40;
-                }
+/// Compute the Merkle root of the `leaves` of the dealers' commitments, in the
+/// given order.
+fn merkle_root(leaves: &[CommitmentLeaf]) -> Result<[u8; 32], Error> {
+    let mut level: Vec<[u8; 32]> = leaves.iter().map(leaf_hash).collect();
 
-                Some(shares)
-            },
-            0u8 => {
-                None
-            },
-            _ => return Err(Error::SerialisationError),
-        };
+    if level.is_empty() {
+        return Err(Error::InvalidCommitment);
+    }
 
-        Ok(ActualState {
-            parameters,
-            index,
-            dh_private_key,
-            dh_public_key,
-            their_commitments,
-            their_dh_public_keys,
-            their_encrypted_secret_shares,
-            my_secret_shares,
-        })
+    while level.len() > 1 {
+        level = merkle_parent_level(&level);
     }
+
+    Ok(level[0])
 }
 
-/// Marker trait to designate valid rounds in the distributed key generation
-/// protocol's state machine.  It is implemented using the [sealed trait design
-/// pattern][sealed] pattern to prevent external types from implementing further
-/// valid states.
+/// Compute the Merkle root of the dealers' `commitments`, sorted by index,
+/// which can be published in place of the commitments themselves.
 ///
-/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
-pub trait DkgState: private::Sealed {}
-
-impl DkgState for RoundOne {}
-impl DkgState for RoundTwo {}
-
-/// Marker trait to designate valid variants of [`RoundOne`] in the distributed
-/// key generation protocol's state machine.  It is implemented using the
-/// [sealed trait design pattern][sealed] pattern to prevent external types from
-/// implementing further valid states.
+/// Each leaf commits to the index and the whole commitment of a dealer, but
+/// hashes its public key apart from the other points of its commitment, so
+/// that a [`GroupKeyProof`] only needs to carry the public keys of the
+/// dealers, and a [`CommitmentInclusionProof`] shows that a single
+/// commitment is under the root.
 ///
-/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
-pub trait Round1: private::Sealed {}
-
-/// Marker trait to designate valid variants of [`RoundTwo`] in the distributed
-/// key generation protocol's state machine.  It is implemented using the
-/// [sealed trait design pattern][sealed] pattern to prevent external types from
-/// implementing further valid states.
+/// # Returns
 ///
-/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
-pub trait Round2: private::Sealed {}
+/// The root, an [`Error::InvalidCommitment`] if there are no commitments, or
+/// if a commitment has no public key or commits to the identity, or an
+/// [`Error::InvalidParticipantIndex`] if an index is zero or appears twice.
+pub fn commitments_digest(commitments: &[VerifiableSecretSharingCommitment]) -> Result<[u8; 32], Error> {
+    merkle_root(&commitment_leaves(commitments)?)
+}
 
-impl Round1 for RoundOne {}
-impl Round2 for RoundTwo {}
+/// A proof, for light clients which do not store the dealers' commitments,
+/// that a [`GroupKey`] is the interpolation of the public keys of the
+/// commitments under a published Merkle root, as computed by
+/// [`commitments_digest`].
+///
+/// The proof carries the leaves of the tree, i.e. for each dealer its index,
+/// its public key and the digest of the rest of its commitment, from which
+/// the verifier recomputes the root. Its size is thus linear in the number
+/// of dealers, all of whose public keys are needed to interpolate the group
+/// key anyway. A single commitment can then be checked against the root with
+/// a [`CommitmentInclusionProof`], of logarithmic size.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupKeyProof {
+    pub(crate) leaves: Vec<CommitmentLeaf>,
+}
 
-fn encrypt_share(
-    share: &SecretShare,
-    aes_key: &[u8; 32],
-    mut rng: impl RngCore + CryptoRng
-) -> EncryptedSecretShare {
-    let hkdf = Hkdf::<Sha512>::new(None, &aes_key[..]);
-    let mut final_aes_key = [0u8; 32];
-    hkdf.expand(&[], &mut final_aes_key)
-        .expect("KDF expansion failed unexpectedly");
+impl GroupKeyProof {
+    /// Build the proof for the dealers' `commitments`.
+    ///
+    /// # Returns
+    ///
+    /// The proof, or the same errors as [`commitments_digest`].
+    pub fn new(commitments: &[VerifiableSecretSharingCommitment]) -> Result<GroupKeyProof, Error> {
+        let leaves = commitment_leaves(commitments)?;
+        if leaves.is_empty() {
+            return Err(Error::InvalidCommitment);
+        }
 
-    let mut nonce_array = [0u8; 16];
-    rng.fill_bytes(&mut nonce_array);
+        Ok(GroupKeyProof { leaves })
+    }
 
-    let final_aes_key = GenericArray::from_slice(&final_aes_key);
-    let mut share_bytes = share.polynomial_evaluation.to_bytes();
+    /// Verify that the `group_key` is the interpolation of the public keys of
+    /// the commitments under the Merkle `root`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Ok`, an [`Error::InvalidParticipantIndex`] if the leaves are
+    /// not indexed by distinct non-zero indices in increasing order, an
+    /// [`Error::InvalidCommitment`] if the proof does not match the `root`,
+    /// or an [`Error::InvalidGroupKey`] if the public keys do not
+    /// interpolate to the `group_key`.
+    pub fn verify(&self, root: &[u8; 32], group_key: &GroupKey) -> Result<(), Error> {
+        if let Some(leaf) = self.leaves.iter().find(|leaf| leaf.0 == 0) {
+            return Err(Error::InvalidParticipantIndex(leaf.0));
+        }
 
-    let nonce = GenericArray::from_slice(&nonce_array);
-    let cipher = Aes256::new(final_aes_key);
-    let mut cipher = Aes256Ctr::from_block_cipher(cipher, nonce);
+        if let Some(pair) = self.leaves.windows(2).find(|pair| pair[0].0 >= pair[1].0) {
+            return Err(Error::InvalidParticipantIndex(pair[1].0));
+        }
 
-    cipher.apply_keystream(&mut share_bytes);
+        if self.leaves.iter().any(|leaf| leaf.1 == RistrettoPoint::identity()) {
+            return Err(Error::InvalidCommitment);
+        }
 
-    EncryptedSecretShare {
-        sender_index: share.sender_index,
-        receiver_index: share.receiver_index,
-        nonce: nonce_array,
-        encrypted_polynomial_evaluation: share_bytes,
+        if !bool::from(merkle_root(&self.leaves)?.ct_eq(root)) {
+            return Err(Error::InvalidCommitment);
+        }
+
+        let indices: Vec<u32> = self.leaves.iter().map(|leaf| leaf.0).collect();
+        let mut interpolated = RistrettoPoint::identity();
+
+        for (index, public_key, _) in self.leaves.iter() {
+            let coeff = calculate_lagrange_coefficients(index, &indices)
+                .map_err(|_| Error::InvalidParticipantIndex(*index))?;
+            interpolated += coeff * public_key;
+        }
+
+        match GroupKey::new(interpolated) == *group_key {
+            true => Ok(()),
+            false => Err(Error::InvalidGroupKey),
+        }
     }
-}
 
-fn decrypt_share(encrypted_share: &EncryptedSecretShare, aes_key: &[u8; 32]) -> Result<SecretShare, Error> {
-    let hkdf = Hkdf::<Sha512>::new(None, &aes_key[..]);
-    let mut final_aes_key = [0u8; 32];
-    hkdf.expand(&[], &mut final_aes_key)
-        .expect("KDF expansion failed unexpectedly");
+    /// Build the proof that the commitment of the dealer with the given
+    /// `index` is under the Merkle root of the commitments of this proof.
+    ///
+    /// # Returns
+    ///
+    /// The inclusion proof, or an [`Error::InvalidParticipantIndex`] if no
+    /// leaf of this proof has this `index`.
+    pub fn inclusion_proof(&self, index: u32) -> Result<CommitmentInclusionProof, Error> {
+        let leaf_position = self.leaves
+            .binary_search_by_key(&index, |leaf| leaf.0)
+            .map_err(|_| Error::InvalidParticipantIndex(index))?;
+        let mut position = leaf_position;
+
+        let mut level: Vec<[u8; 32]> = self.leaves.iter().map(leaf_hash).collect();
+        let mut siblings: Vec<[u8; 32]> = Vec::new();
+
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(position ^ 1) {
+                siblings.push(*sibling);
+            }
+            level = merkle_parent_level(&level);
+            position /= 2;
+        }
 
-    let final_aes_key = GenericArray::from_slice(&final_aes_key);
+        Ok(CommitmentInclusionProof {
+            // These unwrap() cannot fail, since the leaves have distinct
+            // u32 indices, and are thus fewer than 2^32.
+            position: TryInto::<u32>::try_into(leaf_position).unwrap(),
+            leaf_count: TryInto::<u32>::try_into(self.leaves.len()).unwrap(),
+            siblings,
+        })
+    }
 
-    let nonce = GenericArray::from_slice(&encrypted_share.nonce);
-    let cipher = Aes256::new(final_aes_key);
-    let mut cipher = Aes256Ctr::from_block_cipher(cipher, nonce);
+    /// Serialise this proof to a Vec of bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let len = self.leaves.len();
+        let mut res = Vec::with_capacity(4 + 68 * len);
+
+        // This unwrap() cannot fail, since the leaves have distinct u32
+        // indices, and are thus fewer than 2^32.
+        res.extend_from_slice(&TryInto::<u32>::try_into(len).unwrap().to_le_bytes());
+        for (index, public_key, digest) in self.leaves.iter() {
+            res.extend_from_slice(&index.to_le_bytes());
+            res.extend_from_slice(public_key.compress().as_bytes());
+            res.extend_from_slice(digest);
+        }
 
-    let mut bytes: [u8; 32] = encrypted_share.encrypted_polynomial_evaluation;
-    cipher.apply_keystream(&mut bytes);
+        res
+    }
 
-    let evaluation = Scalar::from_canonical_bytes(bytes);
-    if evaluation.is_none() {return Err(Error::DecryptionError)}
+    /// Deserialise this slice of bytes to a `GroupKeyProof`
+    pub fn from_bytes(bytes: &[u8]) -> Result<GroupKeyProof, Error> {
+        let len = u32::from_le_bytes(
+            bytes
+                .get(0..4)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ) as usize;
 
-    Ok(SecretShare { sender_index: encrypted_share.sender_index,
-                     receiver_index: encrypted_share.receiver_index, 
-                     polynomial_evaluation: evaluation.unwrap() })
-}
+        let expected_len = len
+            .checked_mul(68)
+            .and_then(|leaves_len| leaves_len.checked_add(4))
+            .ok_or(Error::SerialisationError)?;
+        if bytes.len() != expected_len {
+            return Err(Error::SerialisationError);
+        }
 
-/// Every participant in the distributed key generation has sent a vector of
-/// commitments and a zero-knowledge proof of a secret key to every other
-/// participant in the protocol.  During round one, each participant checks the
-/// zero-knowledge proofs of secret keys of all other participants.
-#[derive(Clone, Debug)]
-pub struct RoundOne {}
+        let mut leaves = Vec::with_capacity(len);
+        for chunk in bytes[4..].chunks(68) {
+            let index = u32::from_le_bytes(chunk[0..4].try_into().map_err(|_| Error::SerialisationError)?);
+            let public_key = CompressedRistretto::from_slice(&chunk[4..36])
+                .decompress()
+                .ok_or(Error::SerialisationError)?;
+            let digest: [u8; 32] = chunk[36..68].try_into().map_err(|_| Error::SerialisationError)?;
+            leaves.push((index, public_key, digest));
+        }
 
-/// Output of the first round of the Distributed Key Generation.
-#[derive(Clone, Debug)]
-pub struct DKGParticipantList {
-    /// List of the valid participants to be used in RoundTwo
-    pub valid_participants: Vec<Participant>,
-    /// List of the invalid participants that have been removed
-    pub misbehaving_participants: Option<Vec<u32>>,
+        Ok(GroupKeyProof { leaves })
+    }
 }
 
-impl DistributedKeyGeneration<RoundOne> {
-    /// Check the zero-knowledge proofs of knowledge of secret keys of all the
-    /// other participants. When no group key has been computed by a group of
-    /// participants yet, this method should be called rather than 
-    /// `DistributedKeyGeneration<RoundOne>::new()`.
-    ///
-    /// # Note
-    ///
-    /// The `participants` will be sorted by their indices.
+/// A Merkle path showing that the commitment of a single dealer is under the
+/// root of the dealers' commitments, as computed by [`commitments_digest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentInclusionProof {
+    /// The position of the dealer's leaf, among the leaves sorted by index.
+    pub(crate) position: u32,
+    /// The number of leaves of the tree.
+    pub(crate) leaf_count: u32,
+    /// The siblings of the nodes on the path from the leaf to the root.
+    pub(crate) siblings: Vec<[u8; 32]>,
+}
+
+impl CommitmentInclusionProof {
+    /// Verify that the dealer's `commitment` is under the Merkle `root`.
     ///
     /// # Returns
     ///
-    /// An updated state machine for the distributed key generation protocol if
-    /// all of the zero-knowledge proofs verified successfully, otherwise a
-    /// vector of participants whose zero-knowledge proofs were incorrect.
-    pub fn new_initial(
-        parameters: &Parameters,
-        dh_private_key: &DHPrivateKey,
-        my_index: &u32,
-        my_coefficients: &Coefficients,
-        participants: &[Participant],
-        context_string: &str,
-        mut rng: impl RngCore + CryptoRng,
-    ) -> Result<(Self, DKGParticipantList), Error>
-    {
-        Self::new_state_internal(
-            parameters,
-            dh_private_key,
-            my_index,
-            Some(my_coefficients),
-            participants,
-            context_string,
-            true,
-            true,
-            &mut rng,
-        )
+    /// An empty `Ok`, or an [`Error::InvalidCommitment`] if the commitment
+    /// is invalid, or is not under the `root` at this proof's position.
+    pub fn verify(&self, root: &[u8; 32], commitment: &VerifiableSecretSharingCommitment) -> Result<(), Error> {
+        let leaf = match commitment_leaves(core::slice::from_ref(commitment)) {
+            Ok(leaves) => leaves[0],
+            Err(_) => return Err(Error::InvalidCommitment),
+        };
+
+        if self.position >= self.leaf_count {
+            return Err(Error::InvalidCommitment);
+        }
+
+        let mut node = leaf_hash(&leaf);
+        let mut position = self.position as usize;
+        let mut level_len = self.leaf_count as usize;
+        let mut siblings = self.siblings.iter();
+
+        while level_len > 1 {
+            // A last node without a sibling is moved up unchanged.
+            if position ^ 1 < level_len {
+                let sibling = siblings.next().ok_or(Error::InvalidCommitment)?;
+                node = match position % 2 {
+                    0 => node_hash(&node, sibling),
+                    _ => node_hash(sibling, &node),
+                };
+            }
+            position /= 2;
+            level_len = level_len / 2 + level_len % 2;
+        }
+
+        if siblings.next().is_some() || !bool::from(node.ct_eq(root)) {
+            return Err(Error::InvalidCommitment);
+        }
+
+        Ok(())
+    }
+
+    /// Serialise this inclusion proof to a Vec of bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(12 + 32 * self.siblings.len());
+
+        res.extend_from_slice(&self.position.to_le_bytes());
+        res.extend_from_slice(&self.leaf_count.to_le_bytes());
+        // This unwrap() cannot fail, since a path in a tree of fewer than
+        // 2^32 leaves has fewer than 32 siblings.
+        res.extend_from_slice(&TryInto::<u32>::try_into(self.siblings.len()).unwrap().to_le_bytes());
+        for sibling in self.siblings.iter() {
+            res.extend_from_slice(sibling);
+        }
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `CommitmentInclusionProof`
+    pub fn from_bytes(bytes: &[u8]) -> Result<CommitmentInclusionProof, Error> {
+        let read_u32 = |index_slice: usize| -> Result<u32, Error> {
+            Ok(u32::from_le_bytes(
+                bytes.get(index_slice..index_slice + 4)
+                    .ok_or(Error::SerialisationError)?
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?,
+            ))
+        };
+
+        let position = read_u32(0)?;
+        let leaf_count = read_u32(4)?;
+        let len = read_u32(8)? as usize;
+
+        let expected_len = len
+            .checked_mul(32)
+            .and_then(|siblings_len| siblings_len.checked_add(12))
+            .ok_or(Error::SerialisationError)?;
+        if bytes.len() != expected_len {
+            return Err(Error::SerialisationError);
+        }
+
+        let mut siblings = Vec::with_capacity(len);
+        for chunk in bytes[12..].chunks(32) {
+            siblings.push(chunk.try_into().map_err(|_| Error::SerialisationError)?);
+        }
+
+        Ok(CommitmentInclusionProof { position, leaf_count, siblings })
+    }
+}
+
+/// Gather the encrypted shares addressed to the participant with index
+/// `receiver_index`, from the encrypted shares computed by each dealer.
+///
+/// # Inputs
+///
+/// * The `receiver_index` of the participant receiving the shares,
+/// * The shares computed by each dealer, as returned by
+///   `DistributedKeyGeneration::<RoundOne>::their_encrypted_secret_shares()`
+///   or [`Participant::reshare`].
+///
+/// # Returns
+///
+/// The shares sent to the receiver, in the order of `all_dealers_shares`,
+/// which can be passed to `DistributedKeyGeneration::<RoundOne>::to_round_two()`.
+pub fn collect_shares_for(
+    receiver_index: u32,
+    all_dealers_shares: &[Vec<EncryptedSecretShare>],
+) -> Vec<EncryptedSecretShare> {
+    all_dealers_shares
+        .iter()
+        .flat_map(|dealer_shares| dealer_shares.iter())
+        .filter(|share| share.receiver_index == receiver_index)
+        .cloned()
+        .collect()
+}
+
+/// The participants of a key generation, split between those whose broadcast
+/// verified and those which misbehaved.
+pub(crate) struct ParticipantPartition {
+    valid_participants: Vec<Participant>,
+    their_commitments: Vec<VerifiableSecretSharingCommitment>,
+    /// The proofs of knowledge of the secret keys of `their_commitments`, in
+    /// the same order.
+    their_commitment_proofs: Vec<NizkOfSecretKey>,
+    their_dh_public_keys: Vec<(u32, DHPublicKey)>,
+    pub(crate) misbehaving_participants: Vec<u32>,
+}
+
+/// The checks performed by [`partition_participants`], following the flags
+/// of a key generation state.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PartitionChecks {
+    /// Whether the commitments follow the given parameters, rather than the
+    /// ones of a new set of participants for resharing.
+    pub(crate) from_dealer: bool,
+    /// Whether the public keys and commitments of the participants are
+    /// checked, in addition to their DH keys.
+    pub(crate) from_signer: bool,
+    /// Whether the proofs of knowledge are verified, unless this has been
+    /// done beforehand.
+    pub(crate) verify_proofs: bool,
+    /// Whether the checks stop as soon as more than \\(n - t\\) participants
+    /// misbehaved, since \\(t\\) valid participants can no longer be reached.
+    /// The remaining participants are then in neither list.
+    pub(crate) abort_early: bool,
+}
+
+impl PartitionChecks {
+    /// All the checks of an initial key generation, on every participant.
+    pub(crate) const ALL: PartitionChecks = PartitionChecks {
+        from_dealer: true,
+        from_signer: true,
+        verify_proofs: true,
+        abort_early: false,
+    };
+}
+
+/// Check the DH keys of the `participants` and, for signers, their public keys
+/// and commitments, splitting them between valid and misbehaving ones.
+pub(crate) fn partition_participants<H: DkgHash>(
+    parameters: &Parameters,
+    participants: &[Participant],
+    context_string: &str,
+    checks: PartitionChecks,
+) -> ParticipantPartition {
+    let PartitionChecks { from_dealer, from_signer, verify_proofs, abort_early } = checks;
+
+    let mut their_commitments: Vec<VerifiableSecretSharingCommitment> = Vec::with_capacity(parameters.t as usize);
+    let mut their_commitment_proofs: Vec<NizkOfSecretKey> = Vec::with_capacity(parameters.t as usize);
+    let mut their_dh_public_keys: Vec<(u32, DHPublicKey)> = Vec::with_capacity(parameters.t as usize);
+    let mut valid_participants: Vec<Participant> = Vec::with_capacity(parameters.n as usize);
+    let mut misbehaving_participants: Vec<u32> = Vec::new();
+    let max_misbehaving = participants.len().saturating_sub(parameters.t as usize);
+
+    for p in participants.iter() {
+        if abort_early && misbehaving_participants.len() > max_misbehaving {
+            break;
+        }
+
+        // Always check the DH keys of the participants, unless they have been checked beforehand
+        let dh_proof = if verify_proofs {
+            p.proof_of_dh_private_key.verify_with_hash::<H>(&p.index, &p.dh_public_key, context_string)
+        } else {
+            Ok(())
+        };
+        match dh_proof {
+            Ok(_)  => {
+                // Signers additionally check the public keys of the signers
+                if from_signer {
+                    let public_key = match p.public_key() {
+                        Some(key) => key,
+                        None      => {
+                            misbehaving_participants.push(p.index);
+                            continue;
+                        }
+                    };
+                    // The commitments must be indexed as their participant,
+                    // and be consistent with the parameters. The commitments
+                    // of resharing dealers follow the parameters of the new
+                    // set, which may differ from the given ones.
+                    let commitments = p.commitments.as_ref().unwrap();
+                    let consistent = if from_dealer {
+                        commitments.validate(parameters)
+                    } else {
+                        commitments.validate_public_key()
+                    };
+                    if commitments.index != p.index || consistent.is_err() {
+                        misbehaving_participants.push(p.index);
+                        continue;
+                    }
+                    let proof = if verify_proofs {
+                        p.proof_of_secret_key.as_ref().unwrap().verify_with_hash::<H>(&p.index, public_key, context_string)
+                    } else {
+                        Ok(())
+                    };
+                    match proof {
+                        Ok(_)  => {
+                            valid_participants.push(p.clone());
+                            their_commitments.push(p.commitments.as_ref().unwrap().clone());
+                            their_commitment_proofs.push(p.proof_of_secret_key.as_ref().unwrap().clone());
+                            their_dh_public_keys.push((p.index, p.dh_public_key.clone()));
+                        },
+                        Err(_) => misbehaving_participants.push(p.index),
+                    }
+                } else {
+                    valid_participants.push(p.clone());
+                    their_dh_public_keys.push((p.index, p.dh_public_key.clone()));
+                }
+            },
+            Err(_) => misbehaving_participants.push(p.index),
+        }
+    }
+
+    // The DH public keys are kept sorted by index, to look them up by binary
+    // search and for the serialised state to be deterministic.
+    their_dh_public_keys.sort_by_key(|(index, _)| *index);
+
+    ParticipantPartition {
+        valid_participants,
+        their_commitments,
+        their_commitment_proofs,
+        their_dh_public_keys,
+        misbehaving_participants,
+    }
+}
+
+/// Verify the public broadcasts of all the `participants` to a key generation,
+/// as each of them does when starting round one, but without requiring any
+/// secret material.
+///
+/// This lets a coordinator check the whole participant set before the key
+/// generation starts. Participants whose index lies outside of the index space
+/// of the `parameters` are reported as misbehaving.
+///
+/// # Returns
+///
+/// The same partition of the participants into valid and misbehaving ones as
+/// [`DistributedKeyGeneration::<RoundOne>::new_initial`].
+pub fn verify_participant_set(
+    participants: &[Participant],
+    context_string: &str,
+    parameters: &Parameters,
+) -> DKGParticipantList {
+    verify_participant_set_with_hash::<Sha512>(participants, context_string, parameters)
+}
+
+/// Same as [`verify_participant_set`], for participants using the hash function `H`.
+pub fn verify_participant_set_with_hash<H: DkgHash>(
+    participants: &[Participant],
+    context_string: &str,
+    parameters: &Parameters,
+) -> DKGParticipantList {
+    let (in_range, out_of_range): (Vec<Participant>, Vec<Participant>) = participants
+        .iter()
+        .cloned()
+        .partition(|p| parameters.is_valid_index(p.index));
+
+    let partition = partition_participants::<H>(parameters, &in_range, context_string, PartitionChecks::ALL);
+
+    let mut misbehaving_participants = partition.misbehaving_participants;
+    misbehaving_participants.extend(out_of_range.iter().map(|p| p.index));
+
+    DKGParticipantList {
+        valid_participants: partition.valid_participants,
+        misbehaving_participants:
+            if misbehaving_participants.is_empty() {
+                None
+            } else {
+                Some(misbehaving_participants)
+            },
+    }
+}
+
+/// Interpolate the group key at 0 from the constant terms of the dealers'
+/// `commitments`.
+pub(crate) fn interpolate_group_key(commitments: &[VerifiableSecretSharingCommitment]) -> Result<GroupKey, Error> {
+    let mut index_vector: Vec<u32> = Vec::with_capacity(commitments.len());
+    let mut public_keys: Vec<RistrettoPoint> = Vec::with_capacity(commitments.len());
+
+    for commitment in commitments.iter() {
+        // Duplicate or zero indices would silently yield a wrong group key.
+        if commitment.index == 0 || index_vector.contains(&commitment.index) {
+            return Err(Error::InvalidParticipantIndex(commitment.index));
+        }
+
+        index_vector.push(commitment.index);
+        public_keys.push(*commitment.public_key().ok_or(Error::InvalidCommitment)?);
     }
 
-    /// Check the zero-knowledge proofs of knowledge of secret keys of all the
-    /// other participants. When a group key already exists and dealers have
-    /// distributed secret shares to a new set, participants of this new set
-    /// should call this method.
-    ///
-    /// # Note
-    ///
-    /// The `participants` will be sorted by their indices.
-    ///
-    /// # Returns
-    ///
-    /// An updated state machine for the distributed key generation protocol if
-    /// all of the zero-knowledge proofs verified successfully, otherwise a
-    /// vector of participants whose zero-knowledge proofs were incorrect.
-    pub fn new(
-        parameters: &Parameters,
-        dh_private_key: &DHPrivateKey,
-        my_index: &u32,
-        dealers: &[Participant],
-        context_string: &str,
-        mut rng: impl RngCore + CryptoRng,
-    ) -> Result<(Self, DKGParticipantList), Error>
-    {
-        Self::new_state_internal(
-            parameters,
-            dh_private_key,
-            my_index,
-            None,
-            dealers,
-            context_string,
-            false,
-            true,
-            &mut rng
-        )
-    }
+    // The group key is the interpolation at 0 of all index 0 of the dealers' commitments.
+    let mut coefficients: Vec<Scalar> = Vec::with_capacity(commitments.len());
+    for index in index_vector.iter() {
+        coefficients.push(
+            calculate_lagrange_coefficients(index, &index_vector)
+                .map_err(|_| Error::InvalidParticipantIndex(*index))?
+        );
+    }
+
+    Ok(GroupKey::new(RistrettoPoint::vartime_multiscalar_mul(coefficients, public_keys)))
+}
+
+/// Compute the group key of a key generation from the final set of the
+/// dealers' `commitments`, as each participant does when finishing it, e.g.
+/// for an aggregator which collected the commitments over time.
+///
+/// Each commitment is validated against the dealers' `parameters`, and the
+/// group key \\(Y = \sum\_j \lambda\_j \phi\_{j0}\\) is computed with a single
+/// multiscalar multiplication.
+///
+/// # Note
+///
+/// The group key cannot be accumulated as commitments arrive: the Lagrange
+/// coefficient \\(\lambda\_j = \prod\_{k \neq j} \frac{k}{k - j}\\) of each
+/// dealer depends on the indices of all the other dealers, so that every
+/// new commitment changes the weights of all the previous ones. Commitments
+/// should rather be validated on arrival, and the group key computed once
+/// the set of dealers is final.
+///
+/// # Returns
+///
+/// The [`GroupKey`], an [`Error::InvalidCommitment`] if a commitment does not
+/// match the `parameters`, an [`Error::InvalidParticipantIndex`] for an
+/// out-of-range or repeated dealer index, or an
+/// [`Error::InvalidNumberOfParticipants`] if there are fewer than \\(t\\)
+/// commitments.
+pub fn calculate_group_key_from(
+    commitments: &[VerifiableSecretSharingCommitment],
+    parameters: &Parameters,
+) -> Result<GroupKey, Error> {
+    if commitments.len() < parameters.t as usize {
+        return Err(Error::InvalidNumberOfParticipants(commitments.len(), parameters.t));
+    }
+
+    for commitment in commitments.iter() {
+        if !parameters.is_valid_index(commitment.index) {
+            return Err(Error::InvalidParticipantIndex(commitment.index));
+        }
+        commitment.validate(parameters)?;
+    }
+
+    interpolate_group_key(commitments)
+}
+
+/// Compute a fingerprint of a set of `participants`, with their DH public
+/// keys, commitments and proofs, so that coordinators can detect when two
+/// parties disagree on the membership of a key generation.
+///
+/// The participants are sorted by index, so that the fingerprint does not
+/// depend on their order, and their serialisations are hashed with Sha512,
+/// truncated to 32 bytes.
+pub fn fingerprint(participants: &[Participant]) -> [u8; 32] {
+    let mut sorted: Vec<&Participant> = participants.iter().collect();
+    sorted.sort_by_key(|participant| participant.index);
+
+    let mut h = Sha512::new();
+    h.update(b"ICE-FROST-participant-set");
+    h.update(TryInto::<u32>::try_into(sorted.len()).unwrap().to_le_bytes());
+
+    for participant in sorted.iter() {
+        let bytes = participant.to_bytes();
+        h.update(TryInto::<u32>::try_into(bytes.len()).unwrap().to_le_bytes());
+        h.update(&bytes);
+    }
+
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&h.finalize()[..32]);
+    output
+}
+
+/// Estimate the memory, in bytes, held by a participant's state at the end of
+/// round one of a key generation with the given `parameters`.
+///
+/// This accounts for the commitments and DH public keys of the \\(n\\)
+/// participants, and for the \\(n\\) pre-encrypted secret shares, and allows a
+/// coordinator running many key generations at once to bound their memory.
+/// It does not account for allocator overhead, nor for the inputs kept by the
+/// caller, such as the list of participants.
+pub fn estimated_round_one_bytes(parameters: &Parameters) -> usize {
+    let n = parameters.n as usize;
+    let t = parameters.t as usize;
+
+    let commitments = n * (size_of::<VerifiableSecretSharingCommitment>() + t * size_of::<RistrettoPoint>());
+    let dh_public_keys = n * size_of::<(u32, DHPublicKey)>();
+    let encrypted_shares = n * size_of::<EncryptedSecretShare>();
+
+    size_of::<ActualState>() + commitments + dh_public_keys + encrypted_shares
+}
+
+/// Verify that a set of `individual_keys` is consistent with the `commitments`
+/// of a key generation and with the resulting `group_key`.
+///
+/// Each individual public key must verify against the `commitments`, and the
+/// whole set must Lagrange-interpolate to the `group_key`. At least
+/// \\(t\\) distinct individual keys must be provided.
+///
+/// # Returns
+///
+/// A `Result` with either an empty `Ok` or `Err` value, depending on
+/// whether or not the verification was successful.
+pub fn verify_key_set(
+    individual_keys: &[IndividualPublicKey],
+    commitments: &[VerifiableSecretSharingCommitment],
+    group_key: &GroupKey,
+    parameters: &Parameters,
+) -> Result<(), Error> {
+    if individual_keys.len() < parameters.t as usize {
+        return Err(Error::InvalidNumberOfParticipants(individual_keys.len(), parameters.t));
+    }
+
+    for key in individual_keys.iter() {
+        key.verify(commitments)?;
+    }
+
+    let indices: Vec<u32> = individual_keys.iter().map(|key| key.index).collect();
+    let mut interpolated = RistrettoPoint::identity();
+
+    for key in individual_keys.iter() {
+        let coeff = calculate_lagrange_coefficients(&key.index, &indices)
+            .map_err(|error| Error::Custom(error.to_string()))?;
+        interpolated += key.share * coeff;
+    }
+
+    match interpolated.compress() == group_key.0.compress() {
+        true => Ok(()),
+        false => Err(Error::InvalidGroupKey),
+    }
+}
+
+/// Module to implement trait sealing so that `DkgState` cannot be
+/// implemented for externally declared types.
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::RoundOne {}
+    impl Sealed for super::RoundTwo {}
+}
+
+/// State machine structures for holding intermediate values during a
+/// distributed key generation protocol run, to prevent misuse.
+///
+/// The hash function `H`, used for the proofs of knowledge and the
+/// complaints, defaults to [`Sha512`]. All participants must use the same
+/// one, along with the matching `*_with_hash` constructors of [`Participant`].
+#[derive(Clone, Debug)]
+pub struct DistributedKeyGeneration<S: DkgState, H: DkgHash = Sha512> {
+    state: Box<ActualState>,
+    data: S,
+    // The hash function is only used as a type, and never held, so that it
+    // has no bearing on whether the state is `Send` or `Sync`.
+    hash: PhantomData<fn() -> H>,
+}
+
+/// The states of a distributed key generation, and the values exchanged
+/// during it, are moved across threads between rounds by asynchronous
+/// coordinators, and must remain `Send` and `Sync`.
+fn _assert_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<DistributedKeyGeneration<RoundOne>>();
+    assert_send_sync::<DistributedKeyGeneration<RoundTwo>>();
+    assert_send_sync::<DKGParticipantList>();
+    assert_send_sync::<Participant>();
+    assert_send_sync::<EncryptedSecretShare>();
+    assert_send_sync::<Complaint>();
+    assert_send_sync::<GroupKey>();
+    assert_send_sync::<IndividualPublicKey>();
+    assert_send_sync::<SecretKey>();
+}
+
+/// Shared state which occurs across all rounds of a threshold signing protocol run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ActualState {
+    /// The parameters for this instantiation of a threshold signature.
+    parameters: Parameters,
+    /// The index of the participant.
+    index: u32,
+    /// The DH private key for deriving a symmetric key to encrypt and decrypt
+    /// secret shares.
+    dh_private_key: DHPrivateKey,
+    /// The DH public key for deriving a symmetric key to encrypt and decrypt
+    /// secret shares.
+    dh_public_key: DHPublicKey,
+    /// A vector of tuples containing the index of each participant and that
+    /// respective participant's commitments to their private polynomial
+    /// coefficients.
+    their_commitments: Option<Vec<VerifiableSecretSharingCommitment>>,
+    /// A vector of ECPoints containing the index of each participant and that
+    /// respective participant's DH public key, sorted by index.
+    their_dh_public_keys: Vec<(u32, DHPublicKey)>,
+    /// The encrypted secret shares this participant has calculated for all the other participants.
+    their_encrypted_secret_shares: Option<Vec<EncryptedSecretShare>>,
+    /// The secret shares this participant has received from all the other participants.
+    my_secret_shares: Option<Vec<SecretShare>>,
+    /// The encrypted secret shares received so far during round one, to keep
+    /// track of the participants which have yet to send their share.
+    my_received_encrypted_shares: Vec<EncryptedSecretShare>,
+    /// The context string of this key generation session, which the proofs
+    /// of knowledge of the participants are bound to, or `None` for states
+    /// serialised before it was recorded.
+    context_string: Option<String>,
+    /// The proofs of knowledge of the secret keys of `their_commitments`, in
+    /// the same order, which bind each commitment to this session. It is
+    /// empty when `context_string` is `None`.
+    their_commitment_proofs: Vec<NizkOfSecretKey>,
+}
+
+impl ActualState {
+    /// Look up the DH public key of the participant with the given `index`.
+    fn their_dh_public_key(&self, index: u32) -> Option<&DHPublicKey> {
+        self.their_dh_public_keys
+            .binary_search_by_key(&index, |(i, _)| *i)
+            .ok()
+            .map(|position| &self.their_dh_public_keys[position].1)
+    }
+
+    /// Serialise this state to a Vec of bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res: Vec<u8> = Vec::new();
+        res.extend_from_slice(&self.parameters.to_bytes());
+        res.extend_from_slice(&self.index.to_le_bytes());
+        res.extend_from_slice(&self.dh_private_key.to_bytes());
+        res.extend_from_slice(&self.dh_public_key.to_bytes());
+        
+        match &self.their_commitments {
+            Some(v) => {
+                res.push(1u8);
+                let mut tmp = v
+                    .iter()
+                    .map(|e| e.to_bytes())
+                    .collect::<Vec<Vec<u8>>>();
+                res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
+                for commitment in tmp.iter_mut() {
+                    res.extend_from_slice(commitment);
+                }
+            },
+            None => res.push(0u8),
+        }
+
+        let mut tmp = self
+            .their_dh_public_keys
+            .iter()
+            .map(|e| (e.0.to_le_bytes(), e.1.to_bytes()))
+            .collect::<Vec<([u8; 4], [u8; 32])>>();
+        res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
+        for (index, keys) in tmp.iter_mut() {
+            res.extend_from_slice(index);
+            res.extend_from_slice(keys);
+        }
+
+        match &self.their_encrypted_secret_shares {
+            Some(v) => {
+                res.push(1u8);
+                let mut tmp = v.iter()
+                    .map(|e| e.to_bytes())
+                    .collect::<Vec<[u8; 56]>>();
+                res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
+                for elem in tmp.iter_mut() {
+                    res.extend_from_slice(elem);
+                }
+            },
+            None => res.push(0u8),
+        };
+
+        match &self.my_secret_shares {
+            Some(v) => {
+                res.push(1u8);
+                let mut tmp = v.iter()
+                    .map(|e| e.to_bytes())
+                    .collect::<Vec<[u8; 40]>>();
+                res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
+                for elem in tmp.iter_mut() {
+                    res.extend_from_slice(elem);
+                }
+            },
+            None => res.push(0u8),
+        };
+
+        res.extend_from_slice(&TryInto::<u32>::try_into(self.my_received_encrypted_shares.len()).unwrap().to_le_bytes());
+        for share in self.my_received_encrypted_shares.iter() {
+            res.extend_from_slice(&share.to_bytes());
+        }
+
+        // States restored from the legacy encoding keep it.
+        if let Some(context_string) = &self.context_string {
+            res.extend_from_slice(&TryInto::<u32>::try_into(context_string.len()).unwrap().to_le_bytes());
+            res.extend_from_slice(context_string.as_bytes());
+
+            res.extend_from_slice(&TryInto::<u32>::try_into(self.their_commitment_proofs.len()).unwrap().to_le_bytes());
+            for proof in self.their_commitment_proofs.iter() {
+                res.extend_from_slice(&proof.to_bytes());
+            }
+        }
+    
+        res
+    }
+    
+    /// Deserialise this slice of bytes to an `ActualState`
+    ///
+    /// States serialised before the context string was recorded, which end
+    /// right after the received encrypted shares, are restored without it.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ActualState, Error> {
+        let mut array = [0u8; 8];
+        array.copy_from_slice(&bytes[..8]);
+        let parameters = Parameters::from_bytes(&array)?;
+
+        let index = u32::from_le_bytes(
+            bytes[8..12]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+
+        let mut array = [0u8; 32];
+        array.copy_from_slice(&bytes[12..44]);
+        let dh_private_key = DHPrivateKey::from_bytes(&array)?;
+
+        array.copy_from_slice(&bytes[44..76]);
+        let dh_public_key = DHPublicKey::from_bytes(&array)?;
+        
+        let mut index_slice = 76usize;
+
+        let their_commitments = match bytes[index_slice] {
+            1u8 => {
+                index_slice += 1;
+                let commit_len = u32::from_le_bytes(
+                    bytes[index_slice..index_slice + 4]
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?,
+                );
+                let mut coms: Vec<VerifiableSecretSharingCommitment> = 
+                    Vec::with_capacity(commit_len as usize);
+
+                index_slice += 4;
+
+                for _ in 0..commit_len {
+                    let com = VerifiableSecretSharingCommitment::from_bytes(&bytes[index_slice..])?;
+                    index_slice += 4 + 4 + com.points.len() * 32;
+                    coms.push(com);
+                }
+
+                Some(coms)
+            },
+            0u8 => {
+                index_slice += 1;
+                None
+            },
+            _ => return Err(Error::SerialisationError),
+        };
+
+        let dh_key_len = u32::from_le_bytes(
+            bytes[index_slice..index_slice+4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let mut their_dh_public_keys: Vec<(u32, DHPublicKey)> = 
+            Vec::with_capacity(dh_key_len as usize);
+
+        index_slice += 4;
+        for _ in 0..dh_key_len {
+            let index = u32::from_le_bytes(
+                bytes[index_slice..index_slice+4]
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?,
+            );
+            let key = DHPublicKey::from_bytes(
+                &bytes[index_slice+4..index_slice+36]
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?
+            )?;
+            their_dh_public_keys.push((index, key));
+            index_slice += 36;
+        }
+        // States serialised before the keys were kept sorted may be unordered.
+        their_dh_public_keys.sort_by_key(|(index, _)| *index);
+
+        let their_encrypted_secret_shares = match bytes[index_slice] {
+            1u8 => {
+                index_slice += 1;
+                let shares_len = u32::from_le_bytes(
+                    bytes[index_slice..index_slice+4]
+                        .try_into()
+                        .map_err(|_| Error::SerialisationError)?,
+                );
+                let mut encrypted_shares: Vec<EncryptedSecretShare> = 
+                    Vec::with_capacity(shares_len as usize);
+        
+                index_slice += 4;
+                for _ in 0..shares_len {
+                    let share = EncryptedSecretShare::from_bytes(
+                        &bytes[index_slice..index_slice+56]
+                            .try_into()
+                            .map_err(|_| Error::SerialisationError)?
+                    )?;
+                    encrypted_shares.push(share);
+                    index_slice += 56;
+                }
+
+                Some(encrypted_shares)
+            },
+            0u8 => {
+                index_slice += 1;
+                None
+            },
+            _ => return Err(Error::SerialisationError),
+        };
+
+        let my_secret_shares = match bytes[index_slice] {
+            1u8 => {
+                index_slice += 1;
+                let shares_len = u32::from_le_bytes(
+                    bytes[index_slice..index_slice+4]
+                        .try_into()
+                        .map_err(|_| Error::SerialisationError)?,
+                );
+                let mut shares: Vec<SecretShare> = 
+                    Vec::with_capacity(shares_len as usize);
+        
+                index_slice += 4;
+                for _ in 0..shares_len {
+                    let share = SecretShare::from_bytes(
+                        &bytes[index_slice..index_slice+40]
+                            .try_into()
+                            .map_err(|_| Error::SerialisationError)?
+                    )?;
+                    shares.push(share);
+                    index_slice += 40;
+                }
+
+                Some(shares)
+            },
+            0u8 => {
+                index_slice += 1;
+                None
+            },
+            _ => return Err(Error::SerialisationError),
+        };
+
+        let received_len = u32::from_le_bytes(
+            bytes.get(index_slice..index_slice+4)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let mut my_received_encrypted_shares: Vec<EncryptedSecretShare> = Vec::new();
+
+        index_slice += 4;
+        for _ in 0..received_len {
+            let share = EncryptedSecretShare::from_bytes(
+                &bytes.get(index_slice..index_slice+56)
+                    .ok_or(Error::SerialisationError)?
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?
+            )?;
+            my_received_encrypted_shares.push(share);
+            index_slice += 56;
+        }
+
+        // Legacy states end here, but for the round marker of the
+        // `DistributedKeyGeneration` which follows.
+        let (context_string, their_commitment_proofs) = if bytes.len() == index_slice + 1 {
+            (None, Vec::new())
+        } else {
+            let context_len = u32::from_le_bytes(
+                bytes.get(index_slice..index_slice + 4)
+                    .ok_or(Error::SerialisationError)?
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?,
+            ) as usize;
+            index_slice += 4;
+            let context_string = bytes.get(index_slice..index_slice.checked_add(context_len).ok_or(Error::SerialisationError)?)
+                .and_then(|bytes| core::str::from_utf8(bytes).ok())
+                .ok_or(Error::SerialisationError)?
+                .to_string();
+            index_slice += context_len;
+
+            let proofs_len = u32::from_le_bytes(
+                bytes.get(index_slice..index_slice + 4)
+                    .ok_or(Error::SerialisationError)?
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?,
+            );
+            index_slice += 4;
+            let mut their_commitment_proofs: Vec<NizkOfSecretKey> = Vec::new();
+            for _ in 0..proofs_len {
+                their_commitment_proofs.push(NizkOfSecretKey::from_bytes(
+                    bytes.get(index_slice..index_slice + 64)
+                        .ok_or(Error::SerialisationError)?
+                        .try_into()
+                        .map_err(|_| Error::SerialisationError)?
+                )?);
+                index_slice += 64;
+            }
+
+            // Only the round marker of the `DistributedKeyGeneration` follows.
+            if bytes.len() != index_slice + 1 {
+                return Err(Error::SerialisationError);
+            }
+
+            (Some(context_string), their_commitment_proofs)
+        };
+
+        Ok(ActualState {
+            parameters,
+            index,
+            dh_private_key,
+            dh_public_key,
+            their_commitments,
+            their_dh_public_keys,
+            their_encrypted_secret_shares,
+            my_secret_shares,
+            my_received_encrypted_shares,
+            context_string,
+            their_commitment_proofs,
+        })
+    }
+}
+
+/// Marker trait to designate valid rounds in the distributed key generation
+/// protocol's state machine.  It is implemented using the [sealed trait design
+/// pattern][sealed] pattern to prevent external types from implementing further
+/// valid states.
+///
+/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
+pub trait DkgState: private::Sealed {}
+
+impl DkgState for RoundOne {}
+impl DkgState for RoundTwo {}
+
+/// Marker trait to designate valid variants of [`RoundOne`] in the distributed
+/// key generation protocol's state machine.  It is implemented using the
+/// [sealed trait design pattern][sealed] pattern to prevent external types from
+/// implementing further valid states.
+///
+/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
+pub trait Round1: private::Sealed {}
+
+/// Marker trait to designate valid variants of [`RoundTwo`] in the distributed
+/// key generation protocol's state machine.  It is implemented using the
+/// [sealed trait design pattern][sealed] pattern to prevent external types from
+/// implementing further valid states.
+///
+/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
+pub trait Round2: private::Sealed {}
+
+impl Round1 for RoundOne {}
+impl Round2 for RoundTwo {}
+
+/// Evaluate the secret polynomial of `my_coefficients` for each of the
+/// `participants`, and encrypt each share to its receiver.
+///
+/// RICE-FROST: We also encrypt the secret shares of Round 2 Step 1, in which
+/// each P_i securely sends to each other participant P_l a secret share
+/// (l, f_i(l)) and keeps (i, f_i(i)) for themselves.
+fn encrypt_shares_for(
+    my_index: &u32,
+    my_coefficients: &Coefficients,
+    dh_private_key: &DHPrivateKey,
+    participants: &[Participant],
+    mut rng: impl RngCore + CryptoRng,
+) -> Result<Vec<EncryptedSecretShare>, Error>
+{
+    let mut their_encrypted_secret_shares: Vec<EncryptedSecretShare> = Vec::with_capacity(participants.len());
+
+    let mut dh_keys = compute_dh_keys(dh_private_key, participants.iter().map(|p| &p.dh_public_key));
+
+    // XXX need a way to index their_encrypted_secret_shares
+    for (p, dh_key) in participants.iter().zip(dh_keys.iter()) {
+        let share = SecretShare::evaluate_polynomial(my_index, &p.index, my_coefficients);
+
+        match encrypt_share(&share, dh_key, &mut rng) {
+            Ok(encrypted_share) => their_encrypted_secret_shares.push(encrypted_share),
+            Err(error) => {
+                dh_keys.zeroize();
+                return Err(error);
+            }
+        }
+    }
+
+    dh_keys.zeroize();
+
+    Ok(their_encrypted_secret_shares)
+}
+
+/// Draw a uniformly random scalar from `rng`, as `Scalar::random` does, but
+/// reporting a failure of `rng` with an [`Error::RandomnessError`] instead of
+/// panicking.
+fn try_random_scalar(mut rng: impl RngCore + CryptoRng) -> Result<Scalar, Error> {
+    let mut bytes = [0u8; 64];
+    rng.try_fill_bytes(&mut bytes).map_err(|_| Error::RandomnessError)?;
+    let scalar = Scalar::from_bytes_mod_order_wide(&bytes);
+    bytes.zeroize();
+
+    Ok(scalar)
+}
+
+/// Expand a Diffie-Hellman `aes_key` into `okm` with HKDF-SHA512.
+pub(crate) fn expand_aes_key(aes_key: &[u8; 32], okm: &mut [u8]) -> Result<(), Error> {
+    let hkdf = Hkdf::<Sha512>::new(None, &aes_key[..]);
+    hkdf.expand(&[], okm).map_err(|_| Error::KdfError)
+}
+
+/// Compute the Diffie-Hellman keys shared between `dh_private_key` and each
+/// of the `recipients`, in order.
+///
+/// The products are computed with half the private scalar and then doubled
+/// and compressed as a batch, so that all recipients share a single field
+/// inversion instead of paying for one compression each.
+pub(crate) fn compute_dh_keys<'a>(
+    dh_private_key: &DHPrivateKey,
+    recipients: impl IntoIterator<Item = &'a DHPublicKey>,
+) -> Vec<[u8; 32]> {
+    let mut half_key = dh_private_key.0 * Scalar::from(2u64).invert();
+    let mut half_products: Vec<RistrettoPoint> = recipients
+        .into_iter()
+        .map(|pk| pk.0 * half_key)
+        .collect();
+
+    let dh_keys = RistrettoPoint::double_and_compress_batch(&half_products)
+        .iter()
+        .map(|dh_key| dh_key.to_bytes())
+        .collect();
+
+    half_key.zeroize();
+    half_products.zeroize();
+
+    dh_keys
+}
+
+fn encrypt_share(
+    share: &SecretShare,
+    aes_key: &[u8; 32],
+    mut rng: impl RngCore + CryptoRng
+) -> Result<EncryptedSecretShare, Error> {
+    let mut final_aes_key = [0u8; 32];
+    expand_aes_key(aes_key, &mut final_aes_key)?;
+
+    let mut nonce_array = [0u8; 16];
+    rng.try_fill_bytes(&mut nonce_array).map_err(|_| Error::RandomnessError)?;
+
+    let final_aes_key = GenericArray::from_slice(&final_aes_key);
+    let mut share_bytes = share.polynomial_evaluation.to_bytes();
+
+    let nonce = GenericArray::from_slice(&nonce_array);
+    let cipher = Aes256::new(final_aes_key);
+    let mut cipher = Aes256Ctr::from_block_cipher(cipher, nonce);
+
+    cipher.apply_keystream(&mut share_bytes);
+
+    Ok(EncryptedSecretShare {
+        sender_index: share.sender_index,
+        receiver_index: share.receiver_index,
+        nonce: nonce_array,
+        encrypted_polynomial_evaluation: share_bytes,
+    })
+}
+
+fn decrypt_share(encrypted_share: &EncryptedSecretShare, aes_key: &[u8; 32]) -> Result<SecretShare, Error> {
+    let mut final_aes_key = [0u8; 32];
+    expand_aes_key(aes_key, &mut final_aes_key)?;
+
+    let share = decrypt_share_with_expanded_key(encrypted_share, &final_aes_key);
+    final_aes_key.zeroize();
+
+    share
+}
+
+/// Decrypt a share with an AES key already expanded from the DH key.
+pub(crate) fn decrypt_share_with_expanded_key(
+    encrypted_share: &EncryptedSecretShare,
+    final_aes_key: &[u8; 32],
+) -> Result<SecretShare, Error> {
+    let final_aes_key = GenericArray::from_slice(final_aes_key);
+
+    let nonce = GenericArray::from_slice(&encrypted_share.nonce);
+    let cipher = Aes256::new(final_aes_key);
+    let mut cipher = Aes256Ctr::from_block_cipher(cipher, nonce);
+
+    let mut bytes: [u8; 32] = encrypted_share.encrypted_polynomial_evaluation;
+    cipher.apply_keystream(&mut bytes);
+
+    let evaluation = Scalar::from_canonical_bytes(bytes);
+    bytes.zeroize();
+    if evaluation.is_none() {return Err(Error::DecryptionError)}
+
+    Ok(SecretShare { sender_index: encrypted_share.sender_index,
+                     receiver_index: encrypted_share.receiver_index, 
+                     polynomial_evaluation: evaluation.unwrap() })
+}
+
+/// Decrypt the `encrypted_shares` sent by the owners of `their_dh_public_keys`
+/// to the owner of `dh_private_key`, passing each of them to `process` along
+/// with its sender's DH public key and the DH key shared with its sender.
+///
+/// The DH key shared with each sender is computed only once, and the AES key
+/// expanded from it is cached for senders which send several shares. Shares
+/// from unknown senders are skipped. The scratch keys are wiped whether or not
+/// all shares could be processed.
+pub(crate) fn for_each_decrypted_share(
+    dh_private_key: &DHPrivateKey,
+    their_dh_public_keys: &[(u32, DHPublicKey)],
+    encrypted_shares: &[EncryptedSecretShare],
+    mut process: impl FnMut(&EncryptedSecretShare, &DHPublicKey, &[u8; 32], Result<SecretShare, Error>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut dh_keys: BTreeMap<u32, (&DHPublicKey, [u8; 32])> = their_dh_public_keys
+        .iter()
+        .zip(compute_dh_keys(dh_private_key, their_dh_public_keys.iter().map(|(_, pk)| pk)))
+        .map(|((index, pk), dh_key)| (*index, (pk, dh_key)))
+        .collect();
+    let mut aes_keys: BTreeMap<u32, [u8; 32]> = BTreeMap::new();
+
+    let mut process_shares = || -> Result<(), Error> {
+        for encrypted_share in encrypted_shares.iter() {
+            if let Some((pk, dh_key)) = dh_keys.get(&encrypted_share.sender_index) {
+                let aes_key = match aes_keys.entry(encrypted_share.sender_index) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        let mut aes_key = [0u8; 32];
+                        expand_aes_key(dh_key, &mut aes_key)?;
+                        entry.insert(aes_key)
+                    },
+                };
+
+                process(encrypted_share, pk, dh_key, decrypt_share_with_expanded_key(encrypted_share, aes_key))?;
+            }
+        }
+
+        Ok(())
+    };
+    let result = process_shares();
+
+    for aes_key in aes_keys.values_mut() {
+        aes_key.zeroize();
+    }
+    for (_, dh_key) in dh_keys.values_mut() {
+        dh_key.zeroize();
+    }
+
+    result
+}
+
+/// Every participant in the distributed key generation has sent a vector of
+/// commitments and a zero-knowledge proof of a secret key to every other
+/// participant in the protocol.  During round one, each participant checks the
+/// zero-knowledge proofs of secret keys of all other participants.
+#[derive(Clone, Debug)]
+pub struct RoundOne {
+    /// Whether this state has been advanced to round two. The flag is shared
+    /// with the clones of this state, so that only one of them is advanced.
+    advanced: Arc<AtomicBool>,
+}
+
+impl RoundOne {
+    fn new() -> Self {
+        RoundOne { advanced: Arc::new(AtomicBool::new(false)) }
+    }
+}
+
+/// Output of the first round of the Distributed Key Generation.
+#[derive(Clone, Debug)]
+pub struct DKGParticipantList {
+    /// List of the valid participants to be used in RoundTwo
+    pub valid_participants: Vec<Participant>,
+    /// List of the invalid participants that have been removed
+    pub misbehaving_participants: Option<Vec<u32>>,
+}
+
+impl DKGParticipantList {
+    /// Serialise this participant list to a Vec of bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res: Vec<u8> = Vec::new();
+
+        res.extend_from_slice(&TryInto::<u32>::try_into(self.valid_participants.len()).unwrap().to_le_bytes());
+        for p in self.valid_participants.iter() {
+            let tmp = p.to_bytes();
+            res.extend_from_slice(&TryInto::<u32>::try_into(tmp.len()).unwrap().to_le_bytes());
+            res.extend_from_slice(&tmp);
+        }
+
+        match &self.misbehaving_participants {
+            Some(indices) => {
+                res.push(1u8);
+                res.extend_from_slice(&TryInto::<u32>::try_into(indices.len()).unwrap().to_le_bytes());
+                for index in indices.iter() {
+                    res.extend_from_slice(&index.to_le_bytes());
+                }
+            },
+            None => res.push(0u8),
+        }
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `DKGParticipantList`
+    pub fn from_bytes(bytes: &[u8]) -> Result<DKGParticipantList, Error> {
+        let read_u32 = |index_slice: usize| -> Result<u32, Error> {
+            Ok(u32::from_le_bytes(
+                bytes.get(index_slice..index_slice + 4)
+                    .ok_or(Error::SerialisationError)?
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?,
+            ))
+        };
+
+        let len = read_u32(0)? as usize;
+        let mut index_slice = 4usize;
+        let mut valid_participants: Vec<Participant> = Vec::new();
+
+        for _ in 0..len {
+            let participant_len = read_u32(index_slice)? as usize;
+            index_slice += 4;
+            let participant_bytes = bytes
+                .get(index_slice..index_slice.saturating_add(participant_len))
+                .ok_or(Error::SerialisationError)?;
+            valid_participants.push(Participant::from_bytes(participant_bytes)?);
+            index_slice += participant_len;
+        }
+
+        let misbehaving_participants = match bytes.get(index_slice) {
+            Some(1u8) => {
+                index_slice += 1;
+                let len = read_u32(index_slice)? as usize;
+                index_slice += 4;
+                let mut indices: Vec<u32> = Vec::new();
+                for _ in 0..len {
+                    indices.push(read_u32(index_slice)?);
+                    index_slice += 4;
+                }
+                Some(indices)
+            },
+            Some(0u8) => {
+                index_slice += 1;
+                None
+            },
+            _ => return Err(Error::SerialisationError),
+        };
+
+        if index_slice != bytes.len() {
+            return Err(Error::SerialisationError);
+        }
+
+        Ok(DKGParticipantList { valid_participants, misbehaving_participants })
+    }
+}
+
+impl<H: DkgHash> DistributedKeyGeneration<RoundOne, H> {
+    /// Check the zero-knowledge proofs of knowledge of secret keys of all the
+    /// other participants. When no group key has been computed by a group of
+    /// participants yet, this method should be called rather than 
+    /// `DistributedKeyGeneration<RoundOne>::new()`.
+    ///
+    /// # Note
+    ///
+    /// The `participants` will be sorted by their indices.
+    ///
+    /// The context string identifies this key generation session: the proofs
+    /// of knowledge of the participants' commitments are kept in the state,
+    /// and checked again against it when the group key is computed, so that
+    /// commitments from another session are rejected. Every session should
+    /// therefore use its own context string.
+    ///
+    /// # Returns
+    ///
+    /// An updated state machine for the distributed key generation protocol if
+    /// all of the zero-knowledge proofs verified successfully, otherwise a
+    /// vector of participants whose zero-knowledge proofs were incorrect.
+    pub fn new_initial(
+        parameters: &Parameters,
+        dh_private_key: &DHPrivateKey,
+        my_index: &u32,
+        my_coefficients: &Coefficients,
+        participants: &[Participant],
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<(Self, DKGParticipantList), Error>
+    {
+        let (mut state, participant_lists) = Self::new_state_internal(
+            parameters,
+            dh_private_key,
+            my_index,
+            participants,
+            context_string,
+            true,
+            true,
+            true,
+        )?;
+        state.encrypt_shares_once(my_coefficients, participants, rng)?;
+
+        Ok((state, participant_lists))
+    }
+
+    /// Same as [`DistributedKeyGeneration::<RoundOne>::new_initial`], except
+    /// that the secret shares for the other participants are not computed
+    /// yet, so that the resulting [`DKGParticipantList`] can be reviewed and
+    /// approved before any share leaves this participant.
+    ///
+    /// # Usage
+    ///
+    /// Once the participant set is approved, the encrypted shares are
+    /// computed with `DistributedKeyGeneration::<RoundOne>.compute_encrypted_shares()`,
+    /// after which the state is the same as the one returned by `new_initial`.
+    pub fn new_initial_deferred(
+        parameters: &Parameters,
+        dh_private_key: &DHPrivateKey,
+        my_index: &u32,
+        participants: &[Participant],
+        context_string: &str,
+    ) -> Result<(Self, DKGParticipantList), Error>
+    {
+        Self::new_state_internal(
+            parameters,
+            dh_private_key,
+            my_index,
+            participants,
+            context_string,
+            true,
+            true,
+            true,
+        )
+    }
+
+    /// Compute the encrypted secret shares of this participant's
+    /// `my_coefficients` for each of the `participants`, for a state created
+    /// with [`DistributedKeyGeneration::<RoundOne>::new_initial_deferred`].
+    ///
+    /// The `participants` must be among the `valid_participants` of the
+    /// [`DKGParticipantList`] returned by `new_initial_deferred`, with the
+    /// same DH public keys, so that no share is computed for a participant
+    /// which was not validated. Passing all of the approved participants, in
+    /// the same order as to `new_initial_deferred`, yields the same shares as
+    /// `new_initial` when no participant misbehaved.
+    ///
+    /// # Returns
+    ///
+    /// The encrypted shares, to be retrieved later on with
+    /// `DistributedKeyGeneration::<RoundOne>.their_encrypted_secret_shares()`
+    /// as well, an [`Error::UnapprovedParticipant`] if a participant was not
+    /// validated, an [`Error::InvalidParticipantIndex`] if a participant is
+    /// given twice, or an [`Error::SharesAlreadyComputed`].
+    pub fn compute_encrypted_shares(
+        &mut self,
+        my_coefficients: &Coefficients,
+        participants: &[Participant],
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<&Vec<EncryptedSecretShare>, Error>
+    {
+        let mut indices: Vec<u32> = Vec::with_capacity(participants.len());
+
+        for p in participants.iter() {
+            if self.state.their_dh_public_key(p.index) != Some(&p.dh_public_key) {
+                return Err(Error::UnapprovedParticipant(p.index));
+            }
+            indices.push(p.index);
+        }
+
+        indices.sort_unstable();
+        if let Some(pair) = indices.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(Error::InvalidParticipantIndex(pair[0]));
+        }
+
+        self.encrypt_shares_once(my_coefficients, participants, rng)
+    }
+
+    /// Compute the encrypted secret shares of this participant's
+    /// `my_coefficients` for each of the `participants`, whether or not they
+    /// were validated, unless they have already been computed.
+    fn encrypt_shares_once(
+        &mut self,
+        my_coefficients: &Coefficients,
+        participants: &[Participant],
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<&Vec<EncryptedSecretShare>, Error>
+    {
+        if self.state.their_encrypted_secret_shares.is_some() {
+            return Err(Error::SharesAlreadyComputed);
+        }
+
+        let their_encrypted_secret_shares = encrypt_shares_for(
+            &self.state.index, my_coefficients, &self.state.dh_private_key, participants, rng)?;
+
+        Ok(self.state.their_encrypted_secret_shares.get_or_insert(their_encrypted_secret_shares))
+    }
+
+    /// Same as [`DistributedKeyGeneration::<RoundOne>::new_initial`], except
+    /// that the zero-knowledge proofs of knowledge of the secret keys and of
+    /// the DH private keys of the `participants` are *not* verified.
+    ///
+    /// # Security
+    ///
+    /// Skipping these proofs allows rogue-key attacks on the group key, and
+    /// this must only be used when every proof of the given `participants`
+    /// has already been verified by a trusted party, with the same
+    /// `context_string`. When in doubt, use `new_initial` instead.
+    pub fn new_trusting(
+        parameters: &Parameters,
+        dh_private_key: &DHPrivateKey,
+        my_index: &u32,
+        my_coefficients: &Coefficients,
+        participants: &[Participant],
+        context_string: &str,
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<(Self, DKGParticipantList), Error>
+    {
+        let (mut state, participant_lists) = Self::new_state_internal(
+            parameters,
+            dh_private_key,
+            my_index,
+            participants,
+            context_string,
+            true,
+            true,
+            false,
+        )?;
+        state.encrypt_shares_once(my_coefficients, participants, rng)?;
+
+        Ok((state, participant_lists))
+    }
+
+    /// Check the zero-knowledge proofs of knowledge of secret keys of all the
+    /// other participants. When a group key already exists and dealers have
+    /// distributed secret shares to a new set, participants of this new set
+    /// should call this method.
+    ///
+    /// # Note
+    ///
+    /// The `participants` will be sorted by their indices.
+    ///
+    /// # Returns
+    ///
+    /// An updated state machine for the distributed key generation protocol if
+    /// all of the zero-knowledge proofs verified successfully, otherwise a
+    /// vector of participants whose zero-knowledge proofs were incorrect.
+    pub fn new(
+        parameters: &Parameters,
+        dh_private_key: &DHPrivateKey,
+        my_index: &u32,
+        dealers: &[Participant],
+        context_string: &str,
+        _rng: impl RngCore + CryptoRng,
+    ) -> Result<(Self, DKGParticipantList), Error>
+    {
+        Self::new_state_internal(
+            parameters,
+            dh_private_key,
+            my_index,
+            dealers,
+            context_string,
+            false,
+            true,
+            true,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new_state_internal(
+        parameters: &Parameters,
+        dh_private_key: &DHPrivateKey,
+        my_index: &u32,
+        participants: &[Participant],
+        context_string: &str,
+        from_dealer: bool,
+        from_signer: bool,
+        verify_proofs: bool,
+    ) -> Result<(Self, DKGParticipantList), Error>
+    {
+        let dh_public_key = DHPublicKey(&RISTRETTO_BASEPOINT_TABLE * dh_private_key);
+
+        // Bail if we didn't get enough participants.
+        if participants.len() != parameters.n as usize {
+            return Err(Error::InvalidNumberOfParticipants(participants.len(), parameters.n));
+        }
+
+        // Bail if any index lies outside of the index space of these parameters.
+        // Our own index is only bound by them when we are part of the initial set,
+        // since dealers and signers of a resharing may come from groups of different sizes.
+        if from_dealer && from_signer && !parameters.is_valid_index(*my_index) {
+            return Err(Error::InvalidParticipantIndex(*my_index));
+        }
+        if let Some(p) = participants.iter().find(|p| !parameters.is_valid_index(p.index)) {
+            return Err(Error::InvalidParticipantIndex(p.index));
+        }
+
+        // Bail if any index appears twice, as it would be counted twice when
+        // interpolating the group key. A party which is both a dealer and a
+        // signer of a resharing holds a distinct participant, with its own DH
+        // key, in each set, but must appear only once within each of them.
+        let mut indices: Vec<u32> = participants.iter().map(|p| p.index).collect();
+        indices.sort_unstable();
+        if let Some(pair) = indices.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(Error::InvalidParticipantIndex(pair[0]));
+        }
+
+        // Check the public keys and the DH keys of the participants.
+        let ParticipantPartition {
+            valid_participants,
+            their_commitments,
+            their_commitment_proofs,
+            their_dh_public_keys,
+            misbehaving_participants,
+        } = partition_participants::<H>(
+            parameters,
+            participants,
+            context_string,
+            PartitionChecks { from_dealer, from_signer, verify_proofs, abort_early: true },
+        );
+
+        // [DIFFERENT_TO_PAPER] If too many participants were misbehaving, return an error along their indices.
+        // The checks stop early in that case, so that only the first n - t + 1 of them are reported.
+        if valid_participants.len() < parameters.t as usize {
+            return Err(Error::TooManyInvalidParticipants(misbehaving_participants));
+        }
+
+        // [DIFFERENT_TO_PAPER] The secret shares from Round 2 Step 1 are
+        // pre-calculated by the callers right after, with
+        // `compute_encrypted_shares()`, since it doesn't require additional
+        // online activity, unless they are deferred until the participants
+        // are approved.
+        let state = ActualState {
+            parameters: *parameters,
+            index: *my_index,
+            dh_private_key: dh_private_key.clone(),
+            dh_public_key,
+            their_commitments: if !from_signer { None } else { Some(their_commitments) },
+            their_dh_public_keys,
+            their_encrypted_secret_shares: None,
+            my_secret_shares: None,
+            my_received_encrypted_shares: Vec::new(),
+            context_string: Some(context_string.to_string()),
+            their_commitment_proofs,
+        };
+
+        Ok(
+            (
+                DistributedKeyGeneration::<RoundOne, H> {
+                    state: Box::new(state),
+                    data: RoundOne::new(),
+                    hash: PhantomData,
+                },
+                DKGParticipantList {
+                    valid_participants,
+                    misbehaving_participants:
+                        if misbehaving_participants.is_empty() {
+                            None
+                        } else {
+                            Some(misbehaving_participants)
+                        },
+                }
+            )
+        )
+    }
+
+    /// Retrieve an encrypted secret share for each other participant, to be given to them
+    /// at the end of `DistributedKeyGeneration::<RoundOne>`.
+    pub fn their_encrypted_secret_shares(&self) -> Result<&Vec<EncryptedSecretShare>, Error> {
+        self.state.their_encrypted_secret_shares.as_ref().ok_or(Error::NoEncryptedShares)
+    }
+
+    /// Record an encrypted secret share received from another participant,
+    /// so that the participants yet to send their share can be tracked with
+    /// `DistributedKeyGeneration::<RoundOne>.pending_senders()`.
+    ///
+    /// A share received twice from the same sender replaces the previous one.
+    pub fn receive_encrypted_share(&mut self, encrypted_share: EncryptedSecretShare) -> Result<(), Error> {
+        if encrypted_share.receiver_index != self.state.index {
+            return Err(Error::Custom("The share is intended for another participant".to_string()));
+        }
+
+        if self.state.their_dh_public_key(encrypted_share.sender_index).is_none() {
+            return Err(Error::Custom("The share was sent by an unexpected participant".to_string()));
+        }
+
+        self.state.my_received_encrypted_shares.retain(|share| share.sender_index != encrypted_share.sender_index);
+        self.state.my_received_encrypted_shares.push(encrypted_share);
+
+        Ok(())
+    }
+
+    /// Retrieve the encrypted secret shares recorded so far with
+    /// `DistributedKeyGeneration::<RoundOne>.receive_encrypted_share()`.
+    pub fn received_encrypted_shares(&self) -> &[EncryptedSecretShare] {
+        &self.state.my_received_encrypted_shares
+    }
+
+    /// Get the indices of the valid participants from which no encrypted
+    /// secret share has been received yet.
+    ///
+    /// This can be used by a coordinator to decide whether to keep waiting
+    /// for some participants, or to proceed without them.
+    pub fn pending_senders(&self) -> Vec<u32> {
+        self.state.their_dh_public_keys
+            .iter()
+            .map(|(index, _)| *index)
+            .filter(|index| {
+                !self.state.my_received_encrypted_shares
+                    .iter()
+                    .any(|share| share.sender_index == *index)
+            })
+            .collect()
+    }
+
+    /// Progress to round two of the DKG protocol once we have sent each encrypted share
+    /// from `DistributedKeyGeneration::<RoundOne>.their_encrypted_secret_shares()` to its
+    /// respective other participant, and collected our shares from the other
+    /// participants in turn.
+    ///
+    /// # Advancing a state only once
+    ///
+    /// This consumes the round one state, and a state shares with its clones
+    /// a flag recording that one of them was advanced, whether or not this
+    /// succeeded. Advancing any other clone afterwards fails with
+    /// [`Error::StateAlreadyAdvanced`], since other participants expect a
+    /// single set of complaints from this one. A state deserialised with
+    /// `DistributedKeyGeneration::<RoundOne>::from_bytes` does not share
+    /// this flag, and the caller must not keep the serialised state around
+    /// once it was advanced.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_round_two(
+        self,
+        my_encrypted_secret_shares: Vec<EncryptedSecretShare>,
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<DistributedKeyGeneration<RoundTwo, H>, Error>
+    {
+        self.to_round_two_with_report(my_encrypted_secret_shares, rng).0
+    }
+
+    /// Same as [`DistributedKeyGeneration::<RoundOne>::to_round_two`], also
+    /// returning a [`RoundTwoReport`] counting the shares received, decrypted
+    /// and verified, and the complaints issued.
+    ///
+    /// The report is returned whether or not progressing to round two
+    /// succeeded.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_round_two_with_report(
+        self,
+        my_encrypted_secret_shares: Vec<EncryptedSecretShare>,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> (Result<DistributedKeyGeneration<RoundTwo, H>, Error>, RoundTwoReport)
+    {
+        let mut report = RoundTwoReport::default();
+
+        let result = self.to_round_two_internal(my_encrypted_secret_shares, &mut report, |state, encrypted_share, pk, dh_key| {
+            Ok(Complaint {
+                maker_index: encrypted_share.receiver_index,
+                accused_index: encrypted_share.sender_index,
+                dh_key,
+                proof: ComplaintProof::prove::<H>(
+                    &state.dh_private_key,
+                    &state.dh_public_key,
+                    pk,
+                    &dh_key,
+                    &mut rng,
+                )?,
+            })
+        });
+
+        (result, report)
+    }
+
+    /// Same as [`DistributedKeyGeneration::<RoundOne>::to_round_two`], except
+    /// that the first share failing to decrypt or to verify aborts the key
+    /// generation, instead of issuing complaints.
+    ///
+    /// This spares the extra broadcast round of the complaint and blame flow,
+    /// at the cost of trusting the receiver's word about the offender.
+    ///
+    /// # Returns
+    ///
+    /// The state for round two, or an [`Error::BadShare`] with the index of
+    /// the first participant which sent an invalid share.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_round_two_strict(
+        self,
+        my_encrypted_secret_shares: Vec<EncryptedSecretShare>,
+    ) -> Result<DistributedKeyGeneration<RoundTwo, H>, Error>
+    {
+        self.to_round_two_internal(my_encrypted_secret_shares, &mut RoundTwoReport::default(), |_, encrypted_share, _, _| {
+            Err(Error::BadShare(encrypted_share.sender_index))
+        })
+    }
+
+    /// Decrypt and verify the shares sent to this participant, calling
+    /// `on_invalid_share` for each share which fails to decrypt or verify,
+    /// either to build a complaint or to abort with an error. The processed
+    /// shares are counted in `report`.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_round_two_internal(
+        mut self,
+        my_encrypted_secret_shares: Vec<EncryptedSecretShare>,
+        report: &mut RoundTwoReport,
+        mut on_invalid_share: impl FnMut(&ActualState, &EncryptedSecretShare, &DHPublicKey, [u8; 32]) -> Result<Complaint, Error>,
+    ) -> Result<DistributedKeyGeneration<RoundTwo, H>, Error>
+    {
+        if self.data.advanced.swap(true, atomic::Ordering::SeqCst) {
+            return Err(Error::StateAlreadyAdvanced);
+        }
+
+        // Zero out the other participants encrypted secret shares from memory.
+        if self.state.their_encrypted_secret_shares.is_some() {
+            self.state.their_encrypted_secret_shares.unwrap().zeroize();
+            // XXX Does setting this to None always call drop()?
+            self.state.their_encrypted_secret_shares = None;
+        }
+
+        // RICE-FROST
+
+        let mut complaints: Vec<Complaint> = Vec::new();
+
+        report.received = my_encrypted_secret_shares.len();
+
+        if my_encrypted_secret_shares.len() != self.state.parameters.n as usize {
+            return Err(Error::MissingShares);
+        }
+
+        let mut my_secret_shares: Vec<SecretShare> = Vec::new();
+
+        // Step 2.1: Each P_i decrypts their shares with
+        //           key k_il = pk_l^sk_i
+        let state = &self.state;
+        let result = for_each_decrypted_share(
+            &state.dh_private_key,
+            &state.their_dh_public_keys,
+            &my_encrypted_secret_shares,
+            |encrypted_share, pk, dh_key, mut decrypted_share| {
+                // Step 2.2: Each share is verified by calculating:
+                //           g^{f_l(i)} ?= \Prod_{k=0}^{t-1} \phi_{lk}^{i^{k} mod q},
+                //           creating a complaint if the check fails.
+                let mut rejected = false;
+
+                if decrypted_share.is_ok() {
+                    report.decrypted += 1;
+                }
+
+                for commitment in state.their_commitments.as_ref().unwrap().iter() {
+                    if commitment.index == encrypted_share.sender_index {
+                        // If the decrypted share is incorrect, P_i builds
+                        // a complaint. A rejected share is wiped right away
+                        // rather than kept around until it is dropped.
+                        rejected = match decrypted_share.as_mut() {
+                            Ok(share) => share.verify_or_wipe(commitment).is_err(),
+                            Err(_) => true,
+                        };
+
+                        if !rejected {
+                            report.verified += 1;
+                        } else {
+                            // At most one complaint is issued against each sender,
+                            // so that no more than n complaints are ever emitted.
+                            if complaints.iter().any(|complaint| complaint.accused_index == encrypted_share.sender_index) {
+                                break;
+                            }
+                            complaints.push(on_invalid_share(state, encrypted_share, pk, *dh_key)?);
+                            break;
+                        }
+                    }
+                }
+                if let (Ok(share), false) = (decrypted_share, rejected) {
+                    my_secret_shares.push(share);
+                }
+
+                Ok(())
+            },
+        );
+
+        report.complaints = complaints.len();
+
+        result?;
+
+        if !complaints.is_empty() {
+            return Err(Error::Complaint(complaints))
+        }
+
+        self.state.my_secret_shares = Some(my_secret_shares);
+
+        Ok(DistributedKeyGeneration::<RoundTwo, H> {
+            state: self.state,
+            data: RoundTwo {},
+            hash: PhantomData,
+        })
+    }
+
+    /// Serialise this DKG to a Vec of bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = self.state.to_bytes();
+        res.push(1u8);
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `DistributedKeyGeneration::<RoundOne>`
+    pub fn from_bytes(bytes: &[u8]) -> Result<DistributedKeyGeneration::<RoundOne, H>, Error> {
+        let state = ActualState::from_bytes(bytes)?;
+        let data = if bytes[bytes.len() - 1] == 1 {
+            RoundOne::new()
+        } else {
+            return Err(Error::SerialisationError)
+        };
+
+        Ok(
+            DistributedKeyGeneration::<RoundOne, H> {
+                state: Box::new(state),
+                data,
+                hash: PhantomData,
+            }
+        )
+    }
+}
+
+/// A secret share calculated by evaluating a polynomial with secret
+/// coefficients for some indeterminant.
+#[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
+#[zeroize(drop)]
+pub struct SecretShare {
+    /// The index of the share maker.
+    pub sender_index: u32,
+    /// The participant index that this secret share was calculated for.
+    pub receiver_index: u32,
+    /// The final evaluation of the polynomial for the participant-respective
+    /// indeterminant.
+    pub(crate) polynomial_evaluation: Scalar,
+}
+
+impl SecretShare {
+    /// Evaluate the polynomial, `f(x)` for the secret coefficients at the value of `x`.
+    //
+    // XXX [PAPER] [CFRG] The participant index CANNOT be 0, or the secret share ends up being Scalar::zero().
+    pub(crate) fn evaluate_polynomial(sender_index: &u32, receiver_index: &u32, coefficients: &Coefficients) -> SecretShare {
+        let term: Scalar = (*receiver_index).into();
+        let mut sum: Scalar = Scalar::zero();
+
+        // Evaluate using Horner's method.
+        for (receiver_index, coefficient) in coefficients.0.iter().rev().enumerate() {
+            // The secret is the constant term in the polynomial
+            sum += coefficient;
+
+            if receiver_index != (coefficients.0.len() - 1) {
+                sum *= term;
+            }
+        }
+        SecretShare { sender_index: *sender_index, receiver_index: *receiver_index, polynomial_evaluation: sum }
+    }
+
+    /// Verify that this secret share was correctly computed w.r.t. some secret
+    /// polynomial coefficients attested to by some `commitment`.
+    pub(crate) fn verify(&self, commitment: &VerifiableSecretSharingCommitment) -> Result<(), Error> {
+        let lhs = &RISTRETTO_BASEPOINT_TABLE * &self.polynomial_evaluation;
+        let rhs = commitment.evaluate_hiding_vartime(&self.receiver_index.into());
+
+        match lhs.compress() == rhs.compress() {
+            true => Ok(()),
+            false => Err(Error::ShareVerificationError),
+        }
+    }
+
+    /// Verify this secret share against some `commitment` as
+    /// [`SecretShare::verify`] does, wiping its polynomial evaluation from
+    /// memory if it is rejected.
+    pub(crate) fn verify_or_wipe(&mut self, commitment: &VerifiableSecretSharingCommitment) -> Result<(), Error> {
+        let result = self.verify(commitment);
+
+        if result.is_err() {
+            self.zeroize();
+        }
+
+        result
+    }
+
+    /// Check whether this secret share corresponds to the given individual
+    /// public key, i.e. whether `share * B == pk.share` for the same
+    /// participant index.
+    pub fn matches_public_key(&self, pk: &IndividualPublicKey) -> bool {
+        let lhs = &RISTRETTO_BASEPOINT_TABLE * &self.polynomial_evaluation;
+
+        self.receiver_index == pk.index && lhs.compress() == pk.share.compress()
+    }
+
+    /// Serialise this secret share to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 40] {
+        let mut res = [0u8; 40];
+        res[0..4].copy_from_slice(&self.sender_index.to_le_bytes());
+        res[4..8].copy_from_slice(&self.receiver_index.to_le_bytes());
+        res[8..40].copy_from_slice(&self.polynomial_evaluation.to_bytes());
+
+        res
+    }
+
+    /// Serialise this secret share into the provided buffer, without allocating.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, or an error if the buffer is too small.
+    pub fn write_to(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if out.len() < 40 {
+            return Err(Error::SerialisationError);
+        }
+        let mut bytes = self.to_bytes();
+        out[..40].copy_from_slice(&bytes);
+        bytes.zeroize();
+        Ok(40)
+    }
+
+    /// Deserialise this slice of bytes to a `SecretShare`
+    pub fn from_bytes(bytes: &[u8; 40]) -> Result<SecretShare, Error> {
+        let sender_index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+
+        let receiver_index = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+
+        let polynomial_evaluation = Scalar::from_canonical_bytes(
+            bytes[8..40]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+            ).ok_or(Error::SerialisationError)?;
+
+        Ok(SecretShare {
+            sender_index,
+            receiver_index,
+            polynomial_evaluation,
+        })
+    }
+}
+
+
+/// A secret share encrypted with a participant's public key
+///
+/// Shares are encrypted with AES-CTR, which has no associated data, under a
+/// key derived from the sender's and the receiver's DH keys. These keys are
+/// generated afresh for every key generation by [`Participant::new_dealer`]
+/// and [`Participant::new_signer`], which prevents a share from being
+/// replayed into another session between the same parties. When DH keys may
+/// be reused, an [`AuthenticatedEncryptedSecretShare`] binds the share to the
+/// parameters and the context string of its session instead.
+#[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
+#[zeroize(drop)]
+pub struct EncryptedSecretShare {
+    /// The index of the share maker.
+    pub sender_index: u32,
+    /// The participant index that this secret share was calculated for.
+    pub receiver_index: u32,
+    /// The nonce to be used for decryption with AES-CTR mode.
+    pub nonce: [u8; 16],
+    /// The encrypted polynomial evaluation.
+    pub(crate) encrypted_polynomial_evaluation: [u8; 32],
+}
+
+impl EncryptedSecretShare {
+    /// Serialise this encrypted secret share to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 56] {
+        let mut res = [0u8; 56];
+        res[0..4].copy_from_slice(&self.sender_index.to_le_bytes());
+        res[4..8].copy_from_slice(&self.receiver_index.to_le_bytes());
+        res[8..24].copy_from_slice(&self.nonce.clone());
+        res[24..56].copy_from_slice(&self.encrypted_polynomial_evaluation.clone());
+
+        res
+    }
+
+    /// Serialise this encrypted secret share into the provided buffer, without allocating.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, or an error if the buffer is too small.
+    pub fn write_to(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if out.len() < 56 {
+            return Err(Error::SerialisationError);
+        }
+        out[..56].copy_from_slice(&self.to_bytes());
+        Ok(56)
+    }
+
+    /// Deserialise this slice of bytes to a `EncryptedSecretShare`
+    pub fn from_bytes(bytes: &[u8; 56]) -> Result<EncryptedSecretShare, Error> {
+        let sender_index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let receiver_index = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let nonce = bytes[8..24]
+            .try_into()
+            .map_err(|_| Error::SerialisationError)?;
+        let encrypted_polynomial_evaluation = bytes[24..56]
+            .try_into()
+            .map_err(|_| Error::SerialisationError)?;
+
+        Ok(EncryptedSecretShare {
+            sender_index,
+            receiver_index,
+            nonce,
+            encrypted_polynomial_evaluation,
+        })
+    }
+
+    /// Compute the authentication tag of this share under the Diffie-Hellman
+    /// key `dh_key` shared between its sender and its receiver, for posting it
+    /// to a public bulletin board.
+    ///
+    /// The tag also covers the `parameters` and the `context_string` of the
+    /// key generation, so that the share is rejected by any other session.
+    pub fn authenticate(
+        &self,
+        dh_key: &[u8; 32],
+        parameters: &Parameters,
+        context_string: &str,
+    ) -> Result<AuthenticatedEncryptedSecretShare, Error> {
+        Ok(AuthenticatedEncryptedSecretShare {
+            share: self.clone(),
+            tag: compute_share_tag(self, dh_key, parameters, context_string)?,
+        })
+    }
+}
+
+/// Compute the tag of an encrypted share, as HMAC-SHA512 truncated to 32
+/// bytes over the session's parameters and length-prefixed context string,
+/// followed by the serialised share. The MAC key is expanded from the DH key
+/// with a different HKDF info than the AES key.
+fn compute_share_tag(
+    encrypted_share: &EncryptedSecretShare,
+    dh_key: &[u8; 32],
+    parameters: &Parameters,
+    context_string: &str,
+) -> Result<[u8; 32], Error> {
+    let context_len: u32 = context_string.len().try_into().map_err(|_| Error::SerialisationError)?;
+
+    let mut mac_key = [0u8; 64];
+    let hkdf = Hkdf::<Sha512>::new(None, &dh_key[..]);
+    hkdf.expand(b"ICE-FROST-share-mac", &mut mac_key).map_err(|_| Error::KdfError)?;
+
+    // This unwrap() cannot fail, since HMAC accepts keys of any length.
+    let mut mac = Hmac::<Sha512>::new_from_slice(&mac_key[..]).unwrap();
+    mac_key.zeroize();
+    mac.update(&parameters.to_bytes()[..]);
+    mac.update(&context_len.to_le_bytes()[..]);
+    mac.update(context_string.as_bytes());
+    mac.update(&encrypted_share.to_bytes()[..]);
+
+    let mut tag = [0u8; 32];
+    tag.copy_from_slice(&mac.finalize().into_bytes()[..32]);
+
+    Ok(tag)
+}
+
+/// An [`EncryptedSecretShare`] along with a MAC over it, keyed with the
+/// Diffie-Hellman key shared between its sender and its receiver.
+///
+/// AES-CTR alone is malleable: when shares are posted to a public bulletin
+/// board, the tag lets the receiver detect that a share was tampered with
+/// through [`AuthenticatedEncryptedSecretShare::verify_integrity`], before
+/// attempting to decrypt it. It does not authenticate the dealer to third
+/// parties, since the receiver could have computed it as well.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthenticatedEncryptedSecretShare {
+    /// The encrypted share.
+    pub(crate) share: EncryptedSecretShare,
+    /// The MAC over the serialised share.
+    pub(crate) tag: [u8; 32],
+}
+
+impl AuthenticatedEncryptedSecretShare {
+    /// Get the encrypted share, which should only be used once its integrity
+    /// has been verified.
+    pub fn share(&self) -> &EncryptedSecretShare {
+        &self.share
+    }
+
+    /// Check the tag of this share under the Diffie-Hellman key `dh_key`
+    /// shared between its sender and its receiver, for a key generation with
+    /// the given `parameters` and `context_string`.
+    ///
+    /// The tags are compared in constant time.
+    pub fn verify_integrity(&self, dh_key: &[u8; 32], parameters: &Parameters, context_string: &str) -> Result<(), Error> {
+        let tag = compute_share_tag(&self.share, dh_key, parameters, context_string)?;
+
+        match bool::from(tag.ct_eq(&self.tag)) {
+            true => Ok(()),
+            false => Err(Error::IntegrityError),
+        }
+    }
+
+    /// Verify the integrity of this share, and only then decrypt it.
+    pub fn decrypt(&self, dh_key: &[u8; 32], parameters: &Parameters, context_string: &str) -> Result<SecretShare, Error> {
+        self.verify_integrity(dh_key, parameters, context_string)?;
+
+        decrypt_share(&self.share, dh_key)
+    }
+
+    /// Serialise this authenticated share to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 88] {
+        let mut res = [0u8; 88];
+        res[0..56].copy_from_slice(&self.share.to_bytes());
+        res[56..88].copy_from_slice(&self.tag);
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to an `AuthenticatedEncryptedSecretShare`
+    pub fn from_bytes(bytes: &[u8; 88]) -> Result<AuthenticatedEncryptedSecretShare, Error> {
+        let share = EncryptedSecretShare::from_bytes(
+            bytes[0..56]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        )?;
+        let tag = bytes[56..88]
+            .try_into()
+            .map_err(|_| Error::SerialisationError)?;
+
+        Ok(AuthenticatedEncryptedSecretShare { share, tag })
+    }
+}
+
+/// A fragment of a serialised [`EncryptedSecretShare`], for transports whose
+/// messages are too small to carry a whole share.
+///
+/// All fragments of a share carry the same `session_id` and `total`, and are
+/// numbered from `0` to `total - 1` through their `index`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareFragment {
+    /// An identifier shared by all fragments of the same share.
+    pub session_id: u64,
+    /// The total number of fragments the share was split into.
+    pub total: u32,
+    /// The position of this fragment amongst the share's fragments.
+    pub index: u32,
+    /// The bytes of the serialised share carried by this fragment.
+    pub data: Vec<u8>,
+}
+
+impl ShareFragment {
+    /// Split the byte form of an `encrypted_share` into fragments carrying at
+    /// most `max_fragment_len` bytes each.
+    pub fn fragment(
+        encrypted_share: &EncryptedSecretShare,
+        session_id: u64,
+        max_fragment_len: usize,
+    ) -> Result<Vec<ShareFragment>, Error> {
+        if max_fragment_len == 0 {
+            return Err(Error::SerialisationError);
+        }
+
+        let bytes = encrypted_share.to_bytes();
+        let chunks = bytes.chunks(max_fragment_len);
+        let total = chunks.len() as u32;
+
+        Ok(chunks
+            .enumerate()
+            .map(|(index, data)| ShareFragment {
+                session_id,
+                total,
+                index: index as u32,
+                data: data.to_vec(),
+            })
+            .collect())
+    }
+
+    /// Reassemble an [`EncryptedSecretShare`] from its `fragments`, which may
+    /// be given in any order.
+    ///
+    /// # Returns
+    ///
+    /// An error if the fragments belong to different sessions, if any of them
+    /// is missing or duplicated, or if their contents do not form a share.
+    pub fn reassemble(fragments: &[ShareFragment]) -> Result<EncryptedSecretShare, Error> {
+        let first = fragments.first().ok_or(Error::SerialisationError)?;
+
+        if fragments.len() != first.total as usize {
+            return Err(Error::SerialisationError);
+        }
+
+        let mut ordered: Vec<&ShareFragment> = fragments.iter().collect();
+        ordered.sort_by_key(|fragment| fragment.index);
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(56);
+        for (position, fragment) in ordered.iter().enumerate() {
+            if fragment.session_id != first.session_id
+                || fragment.total != first.total
+                || fragment.index as usize != position
+            {
+                return Err(Error::SerialisationError);
+            }
+            bytes.extend_from_slice(&fragment.data);
+        }
+
+        let bytes: [u8; 56] = bytes[..].try_into().map_err(|_| Error::SerialisationError)?;
+
+        EncryptedSecretShare::from_bytes(&bytes)
+    }
+
+    /// Serialise this fragment to a Vec of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(20 + self.data.len());
+        res.extend_from_slice(&self.session_id.to_le_bytes());
+        res.extend_from_slice(&self.total.to_le_bytes());
+        res.extend_from_slice(&self.index.to_le_bytes());
+        res.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        res.extend_from_slice(&self.data);
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `ShareFragment`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ShareFragment, Error> {
+        let session_id = u64::from_le_bytes(
+            bytes.get(0..8)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let total = u32::from_le_bytes(
+            bytes.get(8..12)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let index = u32::from_le_bytes(
+            bytes.get(12..16)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let len = u32::from_le_bytes(
+            bytes.get(16..20)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        ) as usize;
+        let data = bytes.get(20..)
+            .filter(|data| data.len() == len)
+            .ok_or(Error::SerialisationError)?
+            .to_vec();
+
+        Ok(ShareFragment { session_id, total, index, data })
+    }
+}
+
+/// The number of 8-bit chunks a secret share is split into, in a
+/// [`PubliclyVerifiableShare`].
+const PVSS_CHUNKS: usize = 32;
+
+/// A secret share encrypted to a participant's DH public key, such that anyone
+/// can check that it is consistent with the dealer's public commitment.
+///
+/// The share \\(s\\) is split into 8-bit chunks \\(s\_j\\), each of which is
+/// encrypted in the exponent with ElGamal as
+/// \\((g^{r\_j}, pk^{r\_j} \cdot g^{s\_j})\\). Combining the chunk ciphertexts
+/// with weights \\(2^{8j}\\) yields an encryption of \\(g^s\\) under randomness
+/// \\(r = \sum\_j 2^{8j} r\_j\\), and the dealer proves with an equality of
+/// discrete logarithms that it decrypts to the value committed to for the
+/// recipient. The recipient decrypts each chunk and recovers \\(s\_j\\) with a
+/// [`PvssDecryptionTable`].
+///
+/// # Note
+///
+/// The chunks are not proven to lie in range. Since the recovered chunks are
+/// recombined modulo the group order, a share which verifies publicly can only
+/// fail to decrypt if the dealer encrypted an out of range chunk. The
+/// recipient then publishes a [`PvssComplaint`], proving the decryption of
+/// that chunk, from which anyone can blame the dealer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PubliclyVerifiableShare {
+    /// The index of the share maker.
+    pub sender_index: u32,
+    /// The participant index that this secret share was calculated for.
+    pub receiver_index: u32,
+    /// The ElGamal encryptions of the chunks of the share.
+    pub(crate) chunks: Vec<(RistrettoPoint, RistrettoPoint)>,
+    /// The proof of equality of discrete logarithms that the combined chunks
+    /// decrypt to the committed share.
+    pub(crate) proof: DleqProof,
+}
+
+impl PubliclyVerifiableShare {
+    /// Compute the share of `receiver_index` for the polynomial with the given
+    /// `coefficients`, and encrypt it to the receiver's `recipient_dh_public_key`.
+    pub fn new(
+        sender_index: u32,
+        receiver_index: u32,
+        coefficients: &Coefficients,
+        recipient_dh_public_key: &DHPublicKey,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> Self
+    {
+        let mut share = SecretShare::evaluate_polynomial(&sender_index, &receiver_index, coefficients);
+        let mut share_bytes = share.polynomial_evaluation.to_bytes();
+
+        let mut chunks: Vec<(RistrettoPoint, RistrettoPoint)> = Vec::with_capacity(PVSS_CHUNKS);
+        let mut r = Scalar::zero();
+        let mut weight = Scalar::one();
+
+        for byte in share_bytes.iter() {
+            let r_j = Scalar::random(&mut rng);
+
+            chunks.push((
+                &RISTRETTO_BASEPOINT_TABLE * &r_j,
+                (recipient_dh_public_key.0 * r_j) + (&RISTRETTO_BASEPOINT_TABLE * &Scalar::from(*byte)),
+            ));
+
+            r += weight * r_j;
+            weight *= Scalar::from(256u16);
+        }
+
+        share_bytes.zeroize();
+        share.zeroize();
+
+        let (c1, c2) = combine_chunks(&chunks);
+
+        // Prove that log_g(c1) = log_pk(c2 - g^s), where g^s is publicly
+        // computable from the dealer's commitment.
+        let proof = DleqProof::prove(
+            &r,
+            &recipient_dh_public_key.0,
+            pvss_transcript(sender_index, receiver_index, recipient_dh_public_key, &c1, &c2),
+            &mut rng,
+        );
+        r.zeroize();
+
+        PubliclyVerifiableShare {
+            sender_index,
+            receiver_index,
+            chunks,
+            proof,
+        }
+    }
+
+    /// Publicly verify that this share decrypts, under the recipient's
+    /// `recipient_dh_public_key`, to the value committed to by the sender's
+    /// `commitment`.
+    pub fn verify(
+        &self,
+        commitment: &VerifiableSecretSharingCommitment,
+        recipient_dh_public_key: &DHPublicKey,
+    ) -> Result<(), Error>
+    {
+        if commitment.index != self.sender_index || self.chunks.len() != PVSS_CHUNKS {
+            return Err(Error::ShareVerificationError);
+        }
+
+        let committed_share = commitment.evaluate_hiding_vartime(&self.receiver_index.into());
+        let (c1, c2) = combine_chunks(&self.chunks);
+
+        self.proof.verify(
+            &c1,
+            &recipient_dh_public_key.0,
+            &(c2 - committed_share),
+            pvss_transcript(self.sender_index, self.receiver_index, recipient_dh_public_key, &c1, &c2),
+        ).map_err(|_| Error::ShareVerificationError)
+    }
+
+    /// Decrypt this share with the recipient's `dh_private_key`, looking the
+    /// chunks up in the given `table`.
+    ///
+    /// # Returns
+    ///
+    /// The decrypted [`SecretShare`], or an [`Error::DecryptionError`] if one
+    /// of the chunks is out of range, in which case the recipient should
+    /// publish a [`PvssComplaint`].
+    pub fn decrypt(&self, dh_private_key: &DHPrivateKey, table: &PvssDecryptionTable) -> Result<SecretShare, Error> {
+        if self.chunks.len() != PVSS_CHUNKS {
+            return Err(Error::DecryptionError);
+        }
+
+        let mut share_bytes = [0u8; 32];
+
+        for (byte, (c1, c2)) in share_bytes.iter_mut().zip(self.chunks.iter()) {
+            match table.lookup(&(c2 - (c1 * dh_private_key.0))) {
+                Some(value) => *byte = value,
+                None => {
+                    share_bytes.zeroize();
+                    return Err(Error::DecryptionError);
+                }
+            }
+        }
+
+        // The chunks of an honest share are its canonical encoding, but any
+        // other in range chunks combining to the committed share are accepted.
+        let polynomial_evaluation = Scalar::from_bytes_mod_order(share_bytes);
+        share_bytes.zeroize();
+
+        Ok(SecretShare {
+            sender_index: self.sender_index,
+            receiver_index: self.receiver_index,
+            polynomial_evaluation,
+        })
+    }
+
+    /// Serialise this publicly verifiable share to a Vec of bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res: Vec<u8> = Vec::with_capacity(8 + self.chunks.len() * 64 + 96);
+        res.extend_from_slice(&self.sender_index.to_le_bytes());
+        res.extend_from_slice(&self.receiver_index.to_le_bytes());
+        for (c1, c2) in self.chunks.iter() {
+            res.extend_from_slice(&c1.compress().to_bytes());
+            res.extend_from_slice(&c2.compress().to_bytes());
+        }
+        res.extend_from_slice(&self.proof.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `PubliclyVerifiableShare`
+    pub fn from_bytes(bytes: &[u8]) -> Result<PubliclyVerifiableShare, Error> {
+        if bytes.len() != 8 + PVSS_CHUNKS * 64 + 96 {
+            return Err(Error::SerialisationError);
+        }
+
+        let sender_index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let receiver_index = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+
+        let decompress = |index_slice: usize| -> Result<RistrettoPoint, Error> {
+            CompressedRistretto::from_slice(&bytes[index_slice..index_slice + 32])
+                .decompress()
+                .ok_or(Error::SerialisationError)
+        };
+
+        let mut chunks: Vec<(RistrettoPoint, RistrettoPoint)> = Vec::with_capacity(PVSS_CHUNKS);
+        let mut index_slice = 8usize;
+        for _ in 0..PVSS_CHUNKS {
+            chunks.push((decompress(index_slice)?, decompress(index_slice + 32)?));
+            index_slice += 64;
+        }
+
+        let proof = DleqProof::from_bytes(
+            &bytes[index_slice..index_slice + 96]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        )?;
+
+        Ok(PubliclyVerifiableShare { sender_index, receiver_index, chunks, proof })
+    }
+}
+
+/// Combine the chunk ciphertexts of a [`PubliclyVerifiableShare`] with weights
+/// \\(2^{8j}\\) into a single ElGamal ciphertext.
+fn combine_chunks(chunks: &[(RistrettoPoint, RistrettoPoint)]) -> (RistrettoPoint, RistrettoPoint) {
+    let mut weights: Vec<Scalar> = Vec::with_capacity(chunks.len());
+    let mut weight = Scalar::one();
+    for _ in 0..chunks.len() {
+        weights.push(weight);
+        weight *= Scalar::from(256u16);
+    }
+
+    (
+        RistrettoPoint::vartime_multiscalar_mul(weights.iter(), chunks.iter().map(|c| c.0)),
+        RistrettoPoint::vartime_multiscalar_mul(weights.iter(), chunks.iter().map(|c| c.1)),
+    )
+}
+
+/// Start the transcript of the proof of a [`PubliclyVerifiableShare`].
+fn pvss_transcript(
+    sender_index: u32,
+    receiver_index: u32,
+    recipient_dh_public_key: &DHPublicKey,
+    c1: &RistrettoPoint,
+    c2: &RistrettoPoint,
+) -> Sha512
+{
+    let mut h = Sha512::new();
+    h.update(b"ICE-FROST-PVSS");
+    h.update(sender_index.to_le_bytes());
+    h.update(receiver_index.to_le_bytes());
+    h.update(recipient_dh_public_key.to_bytes());
+    h.update(c1.compress().to_bytes());
+    h.update(c2.compress().to_bytes());
+
+    h
+}
+
+/// A lookup table of the 256 values a chunk of a [`PubliclyVerifiableShare`]
+/// decrypts to, which is meant to be built once and reused for decrypting
+/// all the shares.
+#[derive(Clone, Debug)]
+pub struct PvssDecryptionTable(Vec<([u8; 32], u8)>);
+
+impl PvssDecryptionTable {
+    /// Build the table of the encodings of \\(g^v\\), for \\(0 \leq v < 256\\).
+    pub fn new() -> PvssDecryptionTable {
+        let mut table: Vec<([u8; 32], u8)> = (0..=255u8)
+            .map(|v| ((&RISTRETTO_BASEPOINT_TABLE * &Scalar::from(v)).compress().to_bytes(), v))
+            .collect();
+        table.sort_unstable();
+
+        PvssDecryptionTable(table)
+    }
+
+    /// Find the value \\(v\\) such that `point` is \\(g^v\\), if it is in range.
+    pub(crate) fn lookup(&self, point: &RistrettoPoint) -> Option<u8> {
+        let encoding = point.compress().to_bytes();
+
+        self.0
+            .binary_search_by(|(entry, _)| entry.cmp(&encoding))
+            .ok()
+            .map(|position| self.0[position].1)
+    }
+}
+
+impl Default for PvssDecryptionTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A non-interactive proof of equality of discrete logarithms, showing that
+/// \\(\log\_g(P\_1) = \log\_B(P\_2)\\) for the basepoint \\(g\\) and some other
+/// base \\(B\\), as used by [`PubliclyVerifiableShare`] and [`PvssComplaint`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DleqProof {
+    /// a1 = g^w.
+    pub(crate) a1: RistrettoPoint,
+    /// a2 = B^w.
+    pub(crate) a2: RistrettoPoint,
+    /// z = w + h.x
+    pub(crate) z: Scalar,
+}
+
+impl DleqProof {
+    /// Prove the knowledge of `secret` such that \\(P\_1 = g^x\\) and
+    /// \\(P\_2 = B^x\\) for the given `base`, where the `transcript` already
+    /// holds the statement.
+    ///
+    /// The random nonce of the proof is zeroed once the proof is built.
+    fn prove(
+        secret: &Scalar,
+        base: &RistrettoPoint,
+        transcript: Sha512,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> DleqProof
+    {
+        let mut w = Scalar::random(&mut rng);
+
+        let a1 = &RISTRETTO_BASEPOINT_TABLE * &w;
+        let a2 = base * w;
+
+        let h = dleq_challenge(transcript, &a1, &a2);
+        let z = w + h * secret;
+
+        w.zeroize();
+
+        DleqProof { a1, a2, z }
+    }
+
+    /// Verify this proof that \\(\log\_g(P\_1) = \log\_B(P\_2)\\), for the
+    /// points `p1` and `p2`, the given `base` and the `transcript` holding the
+    /// statement.
+    fn verify(
+        &self,
+        p1: &RistrettoPoint,
+        base: &RistrettoPoint,
+        p2: &RistrettoPoint,
+        transcript: Sha512,
+    ) -> Result<(), Error>
+    {
+        let h = dleq_challenge(transcript, &self.a1, &self.a2);
+
+        if self.a1 + p1 * h != &RISTRETTO_BASEPOINT_TABLE * &self.z {
+            return Err(Error::InvalidProofOfKnowledge);
+        }
+
+        if self.a2 + p2 * h != base * self.z {
+            return Err(Error::InvalidProofOfKnowledge);
+        }
+
+        Ok(())
+    }
+
+    /// Serialise this proof to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut res = [0u8; 96];
+        res[0..32].copy_from_slice(&self.a1.compress().to_bytes());
+        res[32..64].copy_from_slice(&self.a2.compress().to_bytes());
+        res[64..96].copy_from_slice(&self.z.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this array of bytes to a `DleqProof`
+    pub fn from_bytes(bytes: &[u8; 96]) -> Result<DleqProof, Error> {
+        let decompress = |slice: &[u8]| -> Result<RistrettoPoint, Error> {
+            CompressedRistretto::from_slice(slice).decompress().ok_or(Error::SerialisationError)
+        };
+
+        let a1 = decompress(&bytes[0..32])?;
+        let a2 = decompress(&bytes[32..64])?;
+        let z = Scalar::from_canonical_bytes(
+            bytes[64..96]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ).ok_or(Error::SerialisationError)?;
+
+        Ok(DleqProof { a1, a2, z })
+    }
+}
+
+/// Finish the `transcript` of a [`DleqProof`] into its challenge.
+fn dleq_challenge(mut transcript: Sha512, a1: &RistrettoPoint, a2: &RistrettoPoint) -> Scalar {
+    transcript.update(a1.compress().to_bytes());
+    transcript.update(a2.compress().to_bytes());
+
+    Scalar::from_hash(transcript)
+}
+
+/// A complaint against the dealer of a [`PubliclyVerifiableShare`] which
+/// verifies publicly, but one chunk of which is out of range and cannot be
+/// decrypted.
+///
+/// The recipient reveals the decryption \\(D\_j = c\_{2,j} - sk \cdot c\_{1,j}\\)
+/// of the faulty chunk, and proves with a [`DleqProof`] that it used the
+/// private key of its DH public key. Anyone can then check that \\(D\_j\\)
+/// is not in a [`PvssDecryptionTable`], and blame the dealer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PvssComplaint {
+    /// The index of the complaint maker, i.e. the recipient of the share.
+    pub maker_index: u32,
+    /// The index of the accused dealer.
+    pub accused_index: u32,
+    /// The position of the faulty chunk in the share.
+    pub chunk_index: u32,
+    /// The decryption of the faulty chunk.
+    pub(crate) decrypted_chunk: RistrettoPoint,
+    /// The proof that the chunk was decrypted with the recipient's DH private key.
+    pub(crate) proof: DleqProof,
+}
+
+impl PvssComplaint {
+    /// Build the complaint of the recipient of a `share` which cannot be
+    /// decrypted with its `dh_private_key`.
+    ///
+    /// # Returns
+    ///
+    /// The complaint against the first faulty chunk, or an
+    /// [`Error::ComplaintVerificationError`] if every chunk is in range, in
+    /// which case there is nothing to complain about.
+    pub fn new(
+        share: &PubliclyVerifiableShare,
+        dh_private_key: &DHPrivateKey,
+        table: &PvssDecryptionTable,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> Result<PvssComplaint, Error>
+    {
+        let dh_public_key = DHPublicKey(&RISTRETTO_BASEPOINT_TABLE * &dh_private_key.0);
+
+        for (chunk_index, (c1, c2)) in share.chunks.iter().enumerate() {
+            let decrypted_chunk = c2 - (c1 * dh_private_key.0);
+
+            if table.lookup(&decrypted_chunk).is_none() {
+                let chunk_index = chunk_index as u32;
+                let transcript = pvss_complaint_transcript(
+                    share, &dh_public_key, chunk_index, &decrypted_chunk);
+                let proof = DleqProof::prove(&dh_private_key.0, c1, transcript, &mut rng);
+
+                return Ok(PvssComplaint {
+                    maker_index: share.receiver_index,
+                    accused_index: share.sender_index,
+                    chunk_index,
+                    decrypted_chunk,
+                    proof,
+                });
+            }
+        }
+
+        Err(Error::ComplaintVerificationError)
+    }
+
+    /// Determine who misbehaved, between the dealer of the `share` and the
+    /// maker of this complaint, whose DH public key is
+    /// `recipient_dh_public_key`.
+    ///
+    /// The `share` is assumed to have been publicly verified beforehand with
+    /// [`PubliclyVerifiableShare::verify`], a share failing this check being
+    /// the dealer's fault regardless of any complaint.
+    ///
+    /// # Returns
+    ///
+    /// The index of the dealer if the complaint is valid, otherwise the one of
+    /// the complaint maker.
+    pub fn blame(
+        &self,
+        share: &PubliclyVerifiableShare,
+        recipient_dh_public_key: &DHPublicKey,
+        table: &PvssDecryptionTable,
+    ) -> u32 {
+        let chunk = match share.chunks.get(self.chunk_index as usize) {
+            Some(chunk) => chunk,
+            None => return self.maker_index,
+        };
+
+        if self.maker_index != share.receiver_index || self.accused_index != share.sender_index {
+            return self.maker_index;
+        }
+
+        let transcript = pvss_complaint_transcript(
+            share, recipient_dh_public_key, self.chunk_index, &self.decrypted_chunk);
+        let (c1, c2) = chunk;
+
+        if self.proof.verify(&recipient_dh_public_key.0, c1, &(c2 - self.decrypted_chunk), transcript).is_err() {
+            return self.maker_index;
+        }
+
+        match table.lookup(&self.decrypted_chunk) {
+            Some(_) => self.maker_index,
+            None => self.accused_index,
+        }
+    }
+
+    /// Serialise this complaint to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 140] {
+        let mut res = [0u8; 140];
+        res[0..4].copy_from_slice(&self.maker_index.to_le_bytes());
+        res[4..8].copy_from_slice(&self.accused_index.to_le_bytes());
+        res[8..12].copy_from_slice(&self.chunk_index.to_le_bytes());
+        res[12..44].copy_from_slice(&self.decrypted_chunk.compress().to_bytes());
+        res[44..140].copy_from_slice(&self.proof.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this array of bytes to a `PvssComplaint`
+    pub fn from_bytes(bytes: &[u8; 140]) -> Result<PvssComplaint, Error> {
+        let read_u32 = |slice: &[u8]| -> Result<u32, Error> {
+            Ok(u32::from_le_bytes(slice.try_into().map_err(|_| Error::SerialisationError)?))
+        };
+
+        let decrypted_chunk = CompressedRistretto::from_slice(&bytes[12..44])
+            .decompress()
+            .ok_or(Error::SerialisationError)?;
+        let proof = DleqProof::from_bytes(
+            &bytes[44..140]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        )?;
+
+        Ok(PvssComplaint {
+            maker_index: read_u32(&bytes[0..4])?,
+            accused_index: read_u32(&bytes[4..8])?,
+            chunk_index: read_u32(&bytes[8..12])?,
+            decrypted_chunk,
+            proof,
+        })
+    }
+}
+
+/// Start the transcript of the proof of a [`PvssComplaint`].
+fn pvss_complaint_transcript(
+    share: &PubliclyVerifiableShare,
+    recipient_dh_public_key: &DHPublicKey,
+    chunk_index: u32,
+    decrypted_chunk: &RistrettoPoint,
+) -> Sha512
+{
+    let mut h = Sha512::new();
+    h.update(b"ICE-FROST-PVSS-complaint");
+    h.update(share.sender_index.to_le_bytes());
+    h.update(share.receiver_index.to_le_bytes());
+    h.update(chunk_index.to_le_bytes());
+    h.update(recipient_dh_public_key.to_bytes());
+    for (c1, c2) in share.chunks.iter() {
+        h.update(c1.compress().to_bytes());
+        h.update(c2.compress().to_bytes());
+    }
+    h.update(decrypted_chunk.compress().to_bytes());
+
+    h
+}
+
+/// Compute the challenge of a [`ComplaintProof`] with the hash function `H`.
+fn complaint_challenge<H: DkgHash>(
+    pk_i: &RistrettoPoint,
+    pk_l: &RistrettoPoint,
+    dh_key: &[u8; 32],
+    a1: &RistrettoPoint,
+    a2: &RistrettoPoint,
+) -> Scalar
+{
+    let mut h = H::new();
+    h.update(pk_i.compress().to_bytes());
+    h.update(pk_l.compress().to_bytes());
+    h.update(dh_key);
+    h.update(a1.compress().to_bytes());
+    h.update(a2.compress().to_bytes());
+
+    Scalar::from_hash(h)
+}
+
+/// A proof that a generated complaint is valid. 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplaintProof {
+    /// a1 = g^r.
+    pub a1: RistrettoPoint,
+    /// a2 = pk_l^r.
+    pub a2: RistrettoPoint,
+    /// z = r + H(pk_i, pk_l, k_il).sh_i
+    pub z: Scalar,
+}
+
+impl ComplaintProof {
+    /// Prove that `dh_key` is the Diffie-Hellman secret between the complaint
+    /// maker, with keys `dh_private_key` and `dh_public_key`, and the accused
+    /// participant with `accused_dh_public_key`.
+    ///
+    /// The random nonce of the proof is zeroed once the proof is built.
+    ///
+    /// # Returns
+    ///
+    /// The proof, or an [`Error::RandomnessError`] if `rng` fails to provide
+    /// the nonce.
+    fn prove<H: DkgHash>(
+        dh_private_key: &DHPrivateKey,
+        dh_public_key: &DHPublicKey,
+        accused_dh_public_key: &DHPublicKey,
+        dh_key: &[u8; 32],
+        rng: impl RngCore + CryptoRng,
+    ) -> Result<ComplaintProof, Error>
+    {
+        let mut r = try_random_scalar(rng)?;
+
+        let a1 = &RISTRETTO_BASEPOINT_TABLE * &r;
+        let a2 = accused_dh_public_key.0 * r;
+
+        let h = complaint_challenge::<H>(dh_public_key, accused_dh_public_key, dh_key, &a1, &a2);
+        let z = r + h * dh_private_key.0;
+
+        r.zeroize();
+
+        Ok(ComplaintProof { a1, a2, z })
+    }
+
+    /// Serialise this complaint proof to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut res = [0u8; 96];
+        res[0..32].copy_from_slice(&self.a1.compress().to_bytes());
+        res[32..64].copy_from_slice(&self.a2.compress().to_bytes());
+        res[64..96].copy_from_slice(&self.z.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `ComplaintProof`
+    pub fn from_bytes(bytes: &[u8; 96]) -> Result<ComplaintProof, Error> {
+        let mut array = [0u8; 32];
+        array.copy_from_slice(&bytes[0..32]);
+        let a1 = CompressedRistretto(array)
+            .decompress()
+            .ok_or(Error::SerialisationError)?;
+
+        array.copy_from_slice(&bytes[32..64]);
+        let a2 = CompressedRistretto(array)
+            .decompress()
+            .ok_or(Error::SerialisationError)?;
+
+        array.copy_from_slice(&bytes[64..96]);
+        let z = Scalar::from_canonical_bytes(array)
+                .ok_or(Error::SerialisationError)?;
+
+        Ok(ComplaintProof { a1, a2, z })
+    }
+}
+
+/// A complaint generated when a participant receives a bad share.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Complaint {
+    /// The index of the complaint maker.
+    pub maker_index: u32,
+    /// The index of the alleged misbehaving participant.
+    pub accused_index: u32,
+    /// The shared DH key.
+    ///
+    /// This key cannot be omitted from the complaint: computing it from the
+    /// maker's and the accused's DH public keys alone is the computational
+    /// Diffie-Hellman problem, and other participants need it both to check
+    /// the complaint proof and to decrypt the disputed share when assigning
+    /// blame.
+    pub dh_key: [u8; 32],
+    /// The complaint proof.
+    pub proof: ComplaintProof,
+}
+
+impl Complaint {
+    /// A complaint is valid if:
+    /// --  a1 + h.pk_i = z.g
+    /// --  a2 + h.k_il = z.pk_l
+    ///
+    /// This is a proof of equality of discrete logarithms, which guarantees that
+    /// `dh_key` is the genuine Diffie-Hellman secret k_il = sk_i.pk_l between the
+    /// complaint maker and the accused participant, and not an arbitrary key
+    /// chosen to make an honest share decrypt incorrectly.
+    pub fn verify(
+        &self, 
+        pk_i: &RistrettoPoint,
+        pk_l: &RistrettoPoint,
+    ) -> Result<(), Error> {
+        self.verify_with_hash::<Sha512>(pk_i, pk_l)
+    }
+
+    /// Same as [`Complaint::verify`], for a complaint made with the hash
+    /// function `H`.
+    pub fn verify_with_hash<H: DkgHash>(
+        &self,
+        pk_i: &RistrettoPoint,
+        pk_l: &RistrettoPoint,
+    ) -> Result<(), Error> {
+        let h = complaint_challenge::<H>(pk_i, pk_l, &self.dh_key, &self.proof.a1, &self.proof.a2);
+
+        if self.proof.a1 + pk_i * h != &RISTRETTO_BASEPOINT_TABLE * &self.proof.z {
+            return Err(Error::ComplaintVerificationError)
+        }
+
+        if let Some(key_as_point) = CompressedRistretto::from_slice(&self.dh_key).decompress() {
+            if self.proof.a2 + key_as_point * h != pk_l * self.proof.z {
+                return Err(Error::ComplaintVerificationError)
+            }
+        } else {
+            return Err(Error::ComplaintVerificationError)
+        }
+
+        Ok(())
+    }
+
+    /// Serialise this complaint to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 136] {
+        let mut res = [0u8; 136];
+        res[0..4].copy_from_slice(&self.maker_index.to_le_bytes());
+        res[4..8].copy_from_slice(&self.accused_index.to_le_bytes());
+        res[8..40].copy_from_slice(&self.dh_key.clone());
+        res[40..136].copy_from_slice(&self.proof.to_bytes());
+
+        res
+    }
+
+    /// Serialise this complaint into the provided buffer, without allocating.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, or an error if the buffer is too small.
+    pub fn write_to(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if out.len() < 136 {
+            return Err(Error::SerialisationError);
+        }
+        out[..136].copy_from_slice(&self.to_bytes());
+        Ok(136)
+    }
+
+    /// Deserialise this slice of bytes to a `Complaint`
+    pub fn from_bytes(bytes: &[u8; 136]) -> Result<Complaint, Error> {
+        let maker_index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let accused_index = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+        let dh_key = bytes[8..40]
+            .try_into()
+            .map_err(|_| Error::SerialisationError)?;
+        let proof = ComplaintProof::from_bytes(
+            &bytes[40..136].try_into().map_err(|_| Error::SerialisationError)?)?;
+
+        Ok(Complaint {
+            maker_index,
+            accused_index,
+            dh_key,
+            proof,
+        })
+    }
+}
+
+/// The outcome of resolving a single [`Complaint`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlameVerdict {
+    /// The complaint was valid and the share sent by the accused participant
+    /// could not be decrypted or did not match their commitment.
+    AccusedGuilty,
+    /// The complaint proof was valid, but the share sent by the accused
+    /// participant was correct.
+    MakerLied,
+    /// The complaint proof did not verify, or referred to unknown participants.
+    ProofInvalid,
+}
+
+/// A report on the resolution of a [`Complaint`], keeping track of who accused
+/// whom and why the blame was assigned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BlameReport {
+    /// The index of the complaint maker.
+    pub maker: u32,
+    /// The index of the alleged misbehaving participant.
+    pub accused: u32,
+    /// The verdict reached for this complaint.
+    pub verdict: BlameVerdict,
+}
+
+impl BlameReport {
+    /// Return the index of the participant blamed by this report.
+    pub fn blamed_index(&self) -> u32 {
+        match self.verdict {
+            BlameVerdict::AccusedGuilty => self.accused,
+            BlameVerdict::MakerLied | BlameVerdict::ProofInvalid => self.maker,
+        }
+    }
+}
+
+/// During round two each participant verifies their secret shares they received
+/// from each other participant.
+#[derive(Clone, Debug)]
+pub struct RoundTwo {}
+
+/// Counts of the shares processed by a participant when progressing to round
+/// two, as returned by [`DistributedKeyGeneration::<RoundOne>::to_round_two_with_report`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RoundTwoReport {
+    /// The number of encrypted shares given to this participant.
+    pub received: usize,
+    /// The number of shares which could be decrypted.
+    pub decrypted: usize,
+    /// The number of shares which verified against their sender's commitment.
+    pub verified: usize,
+    /// The number of complaints issued.
+    pub complaints: usize,
+}
+
+impl<H: DkgHash> DistributedKeyGeneration<RoundTwo, H> {
+    /// Calculate this threshold signing protocol participant's long-lived
+    /// secret signing keyshare and the group's public verification key.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (group_key, secret_key) = state.finish()?;
+    /// ```
+    pub fn finish(mut self) -> Result<(GroupKey, SecretKey), Error> {
+        let secret_key = self.calculate_signing_key()?;
+        let group_key = self.calculate_group_key()?;
+
+        self.state.my_secret_shares.zeroize();
+
+        Ok((group_key, secret_key))
+    }
+
+    /// Calculate this threshold signing protocol participant's long-lived
+    /// secret signing keyshare only, skipping the interpolation of the group's
+    /// public verification key.
+    ///
+    /// This is meant for participants which already know the [`GroupKey`],
+    /// and otherwise behaves as [`DistributedKeyGeneration::<RoundTwo>::finish`].
+    pub fn finish_secret_key_only(mut self) -> Result<SecretKey, Error> {
+        let secret_key = self.calculate_signing_key()?;
+
+        self.state.my_secret_shares.zeroize();
+
+        Ok(secret_key)
+    }
+
+    /// Calculate the group's public verification key from the commitments
+    /// collected by this participant, without consuming the state.
+    ///
+    /// This lets the [`GroupKey`] be retrieved, e.g. by a verifier trusting
+    /// this participant, before [`DistributedKeyGeneration::<RoundTwo>::finish`]
+    /// is called.
+    pub fn group_key(&self) -> Result<GroupKey, Error> {
+        self.calculate_group_key()
+    }
+
+    /// Calculate this threshold signing participant's long-lived secret signing
+    /// key by interpolating all of the polynomial evaluations from the other
+    /// participants.
+    pub(crate) fn calculate_signing_key(&self) -> Result<SecretKey, Error> {
+        let my_secret_shares = self.state.my_secret_shares
+            .as_ref()
+            .ok_or_else(|| Error::Custom("Could not retrieve participant's secret shares".to_string()))?;
+
+        // Interpolating less than a threshold of shares, or none at all, would
+        // silently yield a wrong signing key.
+        if my_secret_shares.len() < self.state.parameters.t as usize {
+            return Err(Error::MissingShares);
+        }
+
+        let mut index_vector: Vec<u32> = Vec::new();
+
+        for share in my_secret_shares.iter() {
+            index_vector.push(share.sender_index);
+        }
+
+        let mut key = Scalar::zero();
+
+        for share in my_secret_shares.iter() {
+            let coeff = match calculate_lagrange_coefficients(&share.sender_index, &index_vector) {
+                Ok(s) => s,
+                Err(error) => return Err(Error::Custom(error.to_string())),
+            };
+            key += share.polynomial_evaluation * coeff;
+        }
+
+        Ok(SecretKey { index: self.state.index, key })
+    }
+
+    /// Calculate the group public key used for verifying threshold signatures.
+    ///
+    /// # Returns
+    ///
+    /// A [`GroupKey`] for the set of participants, or an
+    /// [`Error::MismatchedSession`] if any of the commitments does not carry
+    /// a proof of knowledge for the context string of this session, unless
+    /// this state was restored from the legacy encoding without it.
+    ///
+    /// my_commitment is needed for now, but won't be when the distinction 
+    /// dealers/signers is implemented.
+    pub(crate) fn calculate_group_key(&self) -> Result<GroupKey, Error> {
+        let their_commitments = self.state.their_commitments
+            .as_ref()
+            .ok_or_else(|| Error::Custom("Could not retrieve participant's commitments".to_string()))?;
+
+        self.check_session(their_commitments)?;
+
+        interpolate_group_key(their_commitments)
+    }
+
+    /// Check that all of `their_commitments` belong to this key generation
+    /// session, by verifying their proofs of knowledge of the secret keys
+    /// against its context string, as in round one.
+    ///
+    /// The proofs are made by the owners of the commitments, so that a
+    /// commitment from a session with another context string cannot be
+    /// mixed into this one, even by whoever holds the serialised state.
+    ///
+    /// States restored from the legacy encoding hold neither the context
+    /// string nor the proofs, so that their commitments are only checked as
+    /// before, when the participants were validated in round one.
+    fn check_session(&self, their_commitments: &[VerifiableSecretSharingCommitment]) -> Result<(), Error> {
+        let context_string = match &self.state.context_string {
+            Some(context_string) => context_string,
+            None => return Ok(()),
+        };
+
+        for (position, commitment) in their_commitments.iter().enumerate() {
+            let bound = match (self.state.their_commitment_proofs.get(position), commitment.public_key()) {
+                (Some(proof), Some(public_key)) => {
+                    proof.verify_with_hash::<H>(&commitment.index, public_key, context_string).is_ok()
+                },
+                _ => false,
+            };
+
+            if !bound {
+                return Err(Error::MismatchedSession(commitment.index));
+            }
+        }
+
+        Ok(())
+    }
+
+
+    /// Every participant can verify a complaint and determine who is the malicious
+    /// party. The relevant encrypted share is assumed to exist and publicly retrievable
+    /// by any participant.
+    ///
+    /// # Returns
+    ///
+    /// The index of the malicious party, or an error if this state does not
+    /// hold the commitments of the other participants.
+    pub fn blame(
+        &self,
+        encrypted_share: &EncryptedSecretShare,
+        complaint: &Complaint,
+    ) -> Result<u32, Error> {
+        Ok(self.resolve_complaint(encrypted_share, complaint)?.blamed_index())
+    }
+
+    /// Resolve a single complaint into a [`BlameReport`], recording the
+    /// complaint maker, the accused participant and the reached verdict.
+    ///
+    /// The relevant encrypted share is assumed to exist and publicly retrievable
+    /// by any participant. This fails if this state does not hold the
+    /// commitments of the other participants.
+    pub fn resolve_complaint(
+        &self,
+        encrypted_share: &EncryptedSecretShare,
+        complaint: &Complaint,
+    ) -> Result<BlameReport, Error> {
+        let verdict = self.complaint_verdict(encrypted_share, complaint)?;
+
+        Ok(BlameReport {
+            maker: complaint.maker_index,
+            accused: complaint.accused_index,
+            verdict,
+        })
+    }
+
+    /// Resolve a batch of complaints, each given along with the encrypted
+    /// share it refers to.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of [`BlameReport`]s, one per complaint and in the same order,
+    /// or an error if this state does not hold the commitments of the other
+    /// participants.
+    pub fn resolve_complaints(
+        &self,
+        complaints: &[(EncryptedSecretShare, Complaint)],
+    ) -> Result<Vec<BlameReport>, Error> {
+        complaints
+            .iter()
+            .map(|(encrypted_share, complaint)| self.resolve_complaint(encrypted_share, complaint))
+            .collect()
+    }
+
+    fn complaint_verdict(
+        &self,
+        encrypted_share: &EncryptedSecretShare,
+        complaint: &Complaint,
+    ) -> Result<BlameVerdict, Error> {
+        let mut pk_maker = RistrettoPoint::identity();
+        let mut pk_accused = RistrettoPoint::identity();
+        let mut commitment_accused = VerifiableSecretSharingCommitment { index: 0, points: Vec::new() };
+
+        let their_commitments = self.state.their_commitments
+            .as_ref()
+            .ok_or_else(|| Error::Custom("Could not retrieve participant's commitments".to_string()))?;
+
+        for commitment in their_commitments.iter() {
+            if commitment.index == complaint.accused_index {
+                commitment_accused = commitment.clone();
+            }
+        }
+
+        if commitment_accused.points.is_empty() {
+            return Ok(BlameVerdict::ProofInvalid);
+        }
+
+        if let Some(pk) = self.state.their_dh_public_key(complaint.maker_index) {
+            pk_maker = **pk;
+        }
+        if complaint.accused_index != complaint.maker_index {
+            if let Some(pk) = self.state.their_dh_public_key(complaint.accused_index) {
+                pk_accused = **pk;
+            }
+        }
+
+        if pk_maker == RistrettoPoint::identity() || pk_accused == RistrettoPoint::identity() {
+            return Ok(BlameVerdict::ProofInvalid)
+        }
+
+        // The disputed share must be the one sent by the accused to the maker.
+        if encrypted_share.sender_index != complaint.accused_index ||
+            encrypted_share.receiver_index != complaint.maker_index
+        {
+            return Ok(BlameVerdict::ProofInvalid)
+        }
+
+        // Ensure that the dh_key is the genuine DH secret between the maker and
+        // the accused before trusting the result of the decryption below.
+        if complaint.verify_with_hash::<H>(&pk_maker, &pk_accused).is_err() {
+            return Ok(BlameVerdict::ProofInvalid)
+        }
+
+        let share = decrypt_share(encrypted_share, &complaint.dh_key);
+        if share.is_err() {
+            return Ok(BlameVerdict::AccusedGuilty)
+        }
+        match share.unwrap().verify(&commitment_accused) {
+            Ok(()) => Ok(BlameVerdict::MakerLied),
+            Err(_) => Ok(BlameVerdict::AccusedGuilty),
+        }
+    }
+
+    /// Serialise this DKG to a Vec of bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = self.state.to_bytes();
+        res.push(2u8);
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `DistributedKeyGeneration::<RoundTwo>`
+    pub fn from_bytes(bytes: &[u8]) -> Result<DistributedKeyGeneration::<RoundTwo, H>, Error> {
+        let state = ActualState::from_bytes(bytes)?;
+        let data = if bytes[bytes.len() - 1] == 2 {
+            RoundTwo {}
+        } else {
+            return Err(Error::SerialisationError)
+        };
+
+        Ok(
+            DistributedKeyGeneration::<RoundTwo, H> {
+                state: Box::new(state),
+                data,
+                hash: PhantomData,
+            }
+        )
+    }
+}
+
+/// A public verification share for a participant.
+///
+/// Any participant can recalculate the public verification share, which is the
+/// public half of a [`SecretKey`], of any other participant in the protocol.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndividualPublicKey {
+    /// The participant index to which this key belongs.
+    pub index: u32,
+    /// The public verification share.
+    pub share: RistrettoPoint,
+}
+
+impl IndividualPublicKey {
+    /// Any participant can compute the public verification share of any other participant.
+    ///
+    /// This is done by re-computing each [`IndividualPublicKey`] as \\(Y\_i\\) s.t.:
+    ///
+    /// \\[
+    /// Y\_i = \prod\_{j=1}^{n} \prod\_{k=0}^{t-1} \phi\_{jk}^{i^{k} \mod q}
+    /// \\]
+    ///
+    /// for each [`Participant`] index \\(i\\).
+    ///
+    /// # Inputs
+    ///
+    /// * A vector of `commitments` regarding the secret polynomial
+    ///   [`Coefficients`] that this [`IndividualPublicKey`] was generated with.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with either an empty `Ok` or `Err` value, depending on
+    /// whether or not the verification was successful.
+    pub fn verify(
+        &self,
+        commitments: &[VerifiableSecretSharingCommitment],
+    ) -> Result<(), Error>
+    {
+        let rhs = interpolate_commitments(self.index, commitments)?;
+
+        match self.share.compress() == rhs.compress() {
+            true => Ok(()),
+            false => Err(Error::ShareVerificationError),
+        }
+    }
+
+    /// Find which of the `commitments` prevent this [`IndividualPublicKey`]
+    /// from verifying.
+    ///
+    /// Each commitment is left out in turn, and the key is verified against
+    /// the remaining ones. This pinpoints a commitment which was not taken
+    /// into account when the key was derived, such as one from a dealer that
+    /// was excluded, or a corrupted copy of it. Since the key interpolates the
+    /// commitments of all dealers, a single corrupted commitment cannot be
+    /// told apart from the correct one it replaces otherwise.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the commitments whose omission makes the verification
+    /// pass, which is empty if the key already verifies, or if no single
+    /// commitment is to blame.
+    pub fn diagnose(&self, commitments: &[VerifiableSecretSharingCommitment]) -> Vec<u32> {
+        if self.verify(commitments).is_ok() {
+            return Vec::new();
+        }
+
+        let mut remaining: Vec<VerifiableSecretSharingCommitment> = Vec::with_capacity(commitments.len());
+        let mut faulty: Vec<u32> = Vec::new();
+
+        for (position, commitment) in commitments.iter().enumerate() {
+            remaining.clear();
+            remaining.extend(commitments[..position].iter().cloned());
+            remaining.extend(commitments[position + 1..].iter().cloned());
+
+            if !remaining.is_empty() && self.verify(&remaining).is_ok() {
+                faulty.push(commitment.index);
+            }
+        }
+
+        faulty
+    }
+
+    /// Any participant can compute the public verification share of any other participant.
+    ///
+    /// This is done by re-computing each [`IndividualPublicKey`] as \\(Y\_i\\) s.t.:
+    ///
+    /// \\[
+    /// Y\_i = \prod\_{j=1}^{n} \prod\_{k=0}^{t-1} \phi\_{jk}^{i^{k} \mod q}
+    /// \\]
+    ///
+    /// for each [`Participant`] index \\(i\\).
+    ///
+    /// # Inputs
+    ///
+    /// * A `participant_index` and
+    /// * A vector of `commitments` regarding the secret polynomial
+    ///   [`Coefficients`] that the [`IndividualPublicKey`] will be generated from.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with either the `IndividualPublicKey`, or an
+    /// [`Error::InvalidParticipantIndex`] naming the first duplicate or zero
+    /// index among the `commitments`.
+    pub fn generate_from_commitments(
+        participant_index: u32,
+        commitments: &[VerifiableSecretSharingCommitment],
+    ) -> Result<Self, Error>
+    {
+        let share = interpolate_commitments(participant_index, commitments)?;
+
+        Ok(IndividualPublicKey {
+            index: participant_index,
+            share,
+        })
+    }
+
+    /// Compute the public verification share of the participant with the
+    /// given `participant_index`, as [`IndividualPublicKey::generate_from_commitments`]
+    /// does, for `commitments` made with the given `parameters`.
+    ///
+    /// The commitments are expected to hold \\(t\\) points each, as checked by
+    /// [`VerifiableSecretSharingCommitment::validate`], which lets all the
+    /// intermediate buffers be allocated once with their final size.
+    pub fn generate_from_commitments_with_parameters(
+        participant_index: u32,
+        commitments: &[VerifiableSecretSharingCommitment],
+        parameters: &Parameters,
+    ) -> Result<Self, Error>
+    {
+        let share = interpolate_commitments_of_len(participant_index, commitments, parameters.t as usize)?;
+
+        Ok(IndividualPublicKey {
+            index: participant_index,
+            share,
+        })
+    }
+
+    /// Serialise this individual public key to an array of bytes.
+    pub fn to_bytes(&self) -> [u8; 36] {
+        let mut res = [0u8; 36];
+        res[0..4].copy_from_slice(&self.index.to_le_bytes());
+        res[4..36].copy_from_slice(&self.share.compress().to_bytes());
+
+        res
+    }
+
+    /// Serialise this individual public key into the provided buffer, without allocating.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, or an error if the buffer is too small.
+    pub fn write_to(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if out.len() < 36 {
+            return Err(Error::SerialisationError);
+        }
+        out[..36].copy_from_slice(&self.to_bytes());
+        Ok(36)
+    }
+
+    /// Deserialise this individual public key from an array of bytes.
+    pub fn from_bytes(bytes: &[u8; 36]) -> Result<IndividualPublicKey, Error> {
+        let index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+
+        let share = CompressedRistretto(
+            bytes[4..36]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ).decompress().ok_or(Error::SerialisationError)?;
+
+        Ok(IndividualPublicKey { index, share })
+    }
+
+    /// Derive the public verification share corresponding to
+    /// [`SecretKey::derive_child`], from the parent `group_key` and `tweak`.
+    pub fn derive_child(&self, group_key: &GroupKey, tweak: &[u8]) -> IndividualPublicKey {
+        IndividualPublicKey {
+            index: self.index,
+            share: self.share + &RISTRETTO_BASEPOINT_TABLE * &compute_child_tweak(group_key, tweak),
+        }
+    }
+}
+
+/// A secret key, used by one participant in a threshold signature scheme, to sign a message.
+#[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
+#[zeroize(drop)]
+pub struct SecretKey {
+    /// The participant index to which this key belongs.
+    pub(crate) index: u32,
+    /// The participant's long-lived secret share of the group signing key.
+    pub(crate) key: Scalar,
+}
+
+impl SecretKey {
+    /// Derive the corresponding public key for this secret key.
+    pub fn to_public(&self) -> IndividualPublicKey {
+        let share = &RISTRETTO_BASEPOINT_TABLE * &self.key;
+
+        IndividualPublicKey {
+            index: self.index,
+            share,
+        }
+    }
+
+    /// Check whether this secret key corresponds to the given individual
+    /// public key.
+    pub fn matches_public(&self, pk: &IndividualPublicKey) -> bool {
+        let public = self.to_public();
+
+        public.index == pk.index && public.share.compress() == pk.share.compress()
+    }
+
+    /// Convert the single authoritative `share` received by a participant to
+    /// its secret key, with the share's `receiver_index` as index.
+    ///
+    /// This is only valid when the share is the whole of the participant's
+    /// signing key, i.e. when it was issued by a trusted dealer, and after
+    /// verifying it against the dealer's commitment. When several dealers
+    /// took part in the key generation, the participant's shares must instead
+    /// be summed by [`DistributedKeyGeneration::<RoundTwo>::finish`].
+    pub fn from_secret_share(share: &SecretShare) -> SecretKey {
+        SecretKey {
+            index: share.receiver_index,
+            key: share.polynomial_evaluation,
+        }
+    }
+
+    /// Serialise this secret key to an array of bytes.
+    pub fn to_bytes(&self) -> [u8; 36] {
+        let mut res = [0u8; 36];
+        res[0..4].copy_from_slice(&self.index.to_le_bytes());
+        res[4..36].copy_from_slice(&self.key.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this secret key from an array of bytes.
+    pub fn from_bytes(bytes: &[u8; 36]) -> Result<SecretKey, Error> {
+        let index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+
+        let key = Scalar::from_canonical_bytes(bytes[4..36]
+            .try_into()
+            .map_err(|_| Error::SerialisationError)?
+        ).ok_or(Error::SerialisationError)?;
+
+        Ok(SecretKey { index, key })
+    }
+
+    /// Compute the secret key of the participant with the given `index`, for a
+    /// known `group_secret`, as in a trusted setup.
+    ///
+    /// The shared polynomial is \\(f(x) = s + a\_1 x + \\ldots + a\_{t-1} x^{t-1}\\),
+    /// where \\(s\\) is the `group_secret` and the `coefficients` are the
+    /// \\(t-1\\) non-constant coefficients \\(a\_1, \\ldots, a\_{t-1}\\). The same
+    /// `coefficients` must be used for every participant.
+    ///
+    /// # Note
+    ///
+    /// This is mostly intended for testing. The party running it knows the
+    /// group secret, which a distributed key generation is meant to avoid.
+    pub fn from_group_secret(group_secret: &Scalar, index: u32, coefficients: &Coefficients) -> SecretKey {
+        let mut full_coefficients: Vec<Scalar> = Vec::with_capacity(coefficients.0.len() + 1);
+        full_coefficients.push(*group_secret);
+        full_coefficients.extend_from_slice(&coefficients.0);
+        let full_coefficients = Coefficients(full_coefficients);
+
+        let share = SecretShare::evaluate_polynomial(&index, &index, &full_coefficients);
+
+        SecretKey { index, key: share.polynomial_evaluation }
+    }
+
+    /// Derive this participant's secret share of the child group key obtained
+    /// with [`GroupKey::derive_child`] from the parent `group_key` and `tweak`.
+    ///
+    /// Since the tweak is added to the constant term of the shared polynomial,
+    /// every share is shifted by the same tweak scalar, and any threshold of
+    /// child shares interpolates to the child group secret key.
+    pub fn derive_child(&self, group_key: &GroupKey, tweak: &[u8]) -> SecretKey {
+        SecretKey {
+            index: self.index,
+            key: self.key + compute_child_tweak(group_key, tweak),
+        }
+    }
+
+    /// Attest that this participant holds a valid share of the `group_key`,
+    /// by producing a Schnorr signature under this secret key over the group
+    /// key and the given `context`.
+    ///
+    /// The [`Attestation`] is checked by any other participant with
+    /// [`Attestation::verify`] against this participant's [`IndividualPublicKey`].
+    pub fn attest(
+        &self,
+        group_key: &GroupKey,
+        context: &[u8],
+        mut rng: impl RngCore + CryptoRng,
+    ) -> Attestation {
+        let public_key = self.to_public();
+
+        let mut k = Scalar::random(&mut rng);
+        let M = &k * &RISTRETTO_BASEPOINT_TABLE;
+
+        let challenge = attestation_challenge(&public_key, group_key, context, &M);
+        let response = k + self.key * challenge;
+
+        k.zeroize();
+
+        Attestation { challenge, response }
+    }
+}
+
+/// A Schnorr signature under a participant's [`SecretKey`], attesting that
+/// the participant completed the distributed key generation with a valid
+/// share of a [`GroupKey`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    /// The challenge of the Schnorr signature.
+    challenge: Scalar,
+    /// The response of the Schnorr signature.
+    response: Scalar,
+}
+
+impl Attestation {
+    /// Verify this attestation for the participant with the given
+    /// `individual_public_key`, the `group_key` and the `context`.
+    pub fn verify(
+        &self,
+        individual_public_key: &IndividualPublicKey,
+        group_key: &GroupKey,
+        context: &[u8],
+    ) -> Result<(), Error> {
+        let M_prime = (&RISTRETTO_BASEPOINT_TABLE * &self.response) - individual_public_key.share * self.challenge;
+
+        if self.challenge == attestation_challenge(individual_public_key, group_key, context, &M_prime) {
+            return Ok(());
+        }
+
+        Err(Error::InvalidProofOfKnowledge)
+    }
+
+    /// Serialise this attestation to an array of bytes.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut res = [0u8; 64];
+        res[0..32].copy_from_slice(&self.challenge.to_bytes());
+        res[32..64].copy_from_slice(&self.response.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to an `Attestation`.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Attestation, Error> {
+        let challenge = Scalar::from_canonical_bytes(
+            bytes[0..32]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ).ok_or(Error::SerialisationError)?;
+
+        let response = Scalar::from_canonical_bytes(
+            bytes[32..64]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ).ok_or(Error::SerialisationError)?;
+
+        Ok(Attestation { challenge, response })
+    }
+}
+
+/// Compute the challenge of an [`Attestation`].
+fn attestation_challenge(
+    individual_public_key: &IndividualPublicKey,
+    group_key: &GroupKey,
+    context: &[u8],
+    M: &RistrettoPoint,
+) -> Scalar {
+    let mut h = Sha512::new();
+
+    h.update(b"ICE-FROST-attestation");
+    h.update(individual_public_key.to_bytes());
+    h.update(group_key.to_bytes());
+    h.update((context.len() as u64).to_le_bytes());
+    h.update(context);
+    h.update(M.compress().to_bytes());
+
+    Scalar::from_hash(h)
+}
+
+/// An acknowledgement by the receiver of a secret share that it received the
+/// share of a dealer, and whether the share verified against the dealer's
+/// commitment, signed with a Schnorr signature under the receiver's DH key.
+///
+/// The signature covers the encrypted share itself and the context string of
+/// the key generation session, so that a receipt cannot be replayed for
+/// another share of the same dealer, nor in another session.
+///
+/// Dealers collect these receipts before proceeding, to find out which of
+/// the recipients still lack a valid share with [`unacknowledged_recipients`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ShareReceipt {
+    /// The participant index of the receiver of the share.
+    pub receiver_index: u32,
+    /// The participant index of the dealer of the share.
+    pub sender_index: u32,
+    /// Whether the share decrypted and verified successfully.
+    pub verified: bool,
+    /// The challenge and the response of the Schnorr signature.
+    signature: (Scalar, Scalar),
+}
+
+impl ShareReceipt {
+    /// Acknowledge the `encrypted_share` received during the key generation
+    /// session with the given `context_string`, signing the receipt with the
+    /// receiver's `dh_private_key`.
+    pub fn new(
+        encrypted_share: &EncryptedSecretShare,
+        verified: bool,
+        dh_private_key: &DHPrivateKey,
+        context_string: &str,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> ShareReceipt {
+        let dh_public_key = DHPublicKey(&RISTRETTO_BASEPOINT_TABLE * dh_private_key);
+
+        let mut k = Scalar::random(&mut rng);
+        let M = &k * &RISTRETTO_BASEPOINT_TABLE;
+
+        let challenge = share_receipt_challenge(encrypted_share, verified, &dh_public_key, context_string, &M);
+        let response = k + dh_private_key.0 * challenge;
+
+        k.zeroize();
+
+        ShareReceipt {
+            receiver_index: encrypted_share.receiver_index,
+            sender_index: encrypted_share.sender_index,
+            verified,
+            signature: (challenge, response),
+        }
+    }
+
+    /// Verify the signature of this receipt for the `encrypted_share` sent
+    /// during the key generation session with the given `context_string`,
+    /// under the `dh_public_key` of its receiver.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Ok` if the receipt acknowledges this share, otherwise an
+    /// [`Error::InvalidShareReceipt`] naming the receiver.
+    pub fn verify(
+        &self,
+        encrypted_share: &EncryptedSecretShare,
+        dh_public_key: &DHPublicKey,
+        context_string: &str,
+    ) -> Result<(), Error> {
+        let invalid = Error::InvalidShareReceipt(self.receiver_index);
+
+        if encrypted_share.sender_index != self.sender_index || encrypted_share.receiver_index != self.receiver_index {
+            return Err(invalid);
+        }
+
+        let (challenge, response) = self.signature;
+        let M_prime = (&RISTRETTO_BASEPOINT_TABLE * &response) - dh_public_key.0 * challenge;
+
+        if challenge == share_receipt_challenge(encrypted_share, self.verified, dh_public_key, context_string, &M_prime) {
+            return Ok(());
+        }
+
+        Err(invalid)
+    }
+
+    /// Serialise this receipt to an array of bytes.
+    pub fn to_bytes(&self) -> [u8; 73] {
+        let mut res = [0u8; 73];
+        res[0..4].copy_from_slice(&self.receiver_index.to_le_bytes());
+        res[4..8].copy_from_slice(&self.sender_index.to_le_bytes());
+        res[8] = self.verified as u8;
+        res[9..41].copy_from_slice(&self.signature.0.to_bytes());
+        res[41..73].copy_from_slice(&self.signature.1.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `ShareReceipt`.
+    pub fn from_bytes(bytes: &[u8; 73]) -> Result<ShareReceipt, Error> {
+        let receiver_index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        );
+        let sender_index = u32::from_le_bytes(
+            bytes[4..8]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        );
+        let verified = match bytes[8] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::SerialisationError),
+        };
+
+        let challenge = Scalar::from_canonical_bytes(
+            bytes[9..41]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ).ok_or(Error::SerialisationError)?;
+
+        let response = Scalar::from_canonical_bytes(
+            bytes[41..73]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        ).ok_or(Error::SerialisationError)?;
+
+        Ok(ShareReceipt { receiver_index, sender_index, verified, signature: (challenge, response) })
+    }
+}
+
+/// Compute the challenge of a [`ShareReceipt`].
+fn share_receipt_challenge(
+    encrypted_share: &EncryptedSecretShare,
+    verified: bool,
+    dh_public_key: &DHPublicKey,
+    context_string: &str,
+    M: &RistrettoPoint,
+) -> Scalar {
+    let mut h = Sha512::new();
+
+    h.update(b"ICE-FROST-share-receipt");
+    h.update((context_string.len() as u64).to_le_bytes());
+    h.update(context_string.as_bytes());
+    h.update(encrypted_share.to_bytes());
+    h.update([verified as u8]);
+    h.update(dh_public_key.to_bytes());
+    h.update(M.compress().to_bytes());
+
+    Scalar::from_hash(h)
+}
+
+/// Find the `recipients` of the `encrypted_shares` of a dealer which did not
+/// acknowledge a verified share, i.e. for which the `receipts` hold no receipt
+/// of their share in the session with the given `context_string`, with a
+/// valid signature under the recipient's DH key and a `verified` flag.
+///
+/// # Returns
+///
+/// The sorted indices of the recipients missing such a receipt, including
+/// those which were sent no share at all.
+pub fn unacknowledged_recipients(
+    encrypted_shares: &[EncryptedSecretShare],
+    recipients: &[Participant],
+    receipts: &[ShareReceipt],
+    context_string: &str,
+) -> Vec<u32> {
+    let mut missing: Vec<u32> = recipients
+        .iter()
+        .filter(|recipient| {
+            let share = match encrypted_shares.iter().find(|share| share.receiver_index == recipient.index) {
+                Some(share) => share,
+                None => return true,
+            };
+
+            !receipts.iter().any(|receipt| {
+                receipt.verified && receipt.verify(share, &recipient.dh_public_key, context_string).is_ok()
+            })
+        })
+        .map(|recipient| recipient.index)
+        .collect();
+    missing.sort_unstable();
+
+    missing
+}
+
+impl From<IndividualPublicKey> for [u8; 36] {
+    fn from(source: IndividualPublicKey) -> [u8; 36] {
+        source.to_bytes()
+    }
+}
+
+impl From<&IndividualPublicKey> for [u8; 36] {
+    fn from(source: &IndividualPublicKey) -> [u8; 36] {
+        source.to_bytes()
+    }
+}
+
+impl From<&SecretKey> for IndividualPublicKey {
+    fn from(source: &SecretKey) -> IndividualPublicKey {
+        source.to_public()
+    }
+}
+
+/// A public key, used to verify a signature made by a threshold of a group of participants.
+///
+/// The compressed encoding of the key is cached alongside the point, so that
+/// it can be borrowed as a byte slice through [`AsRef<[u8]>`](AsRef).
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct GroupKey(pub(crate) RistrettoPoint, [u8; 32]);
+
+impl PartialEq for GroupKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl AsRef<[u8]> for GroupKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.1
+    }
+}
+
+impl From<GroupKey> for [u8; 32] {
+    fn from(source: GroupKey) -> [u8; 32] {
+        source.1
+    }
+}
+
+impl From<&GroupKey> for [u8; 32] {
+    fn from(source: &GroupKey) -> [u8; 32] {
+        source.1
+    }
+}
+
+impl GroupKey {
+    /// Construct a group public key from a point, caching its encoding.
+    pub(crate) fn new(point: RistrettoPoint) -> GroupKey {
+        GroupKey(point, point.compress().to_bytes())
+    }
+
+    /// Serialise this group public key to an array of bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.1
+    }
+
+    /// Serialise this group public key into the provided buffer, without allocating.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, or an error if the buffer is too small.
+    pub fn write_to(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if out.len() < 32 {
+            return Err(Error::SerialisationError);
+        }
+        out[..32].copy_from_slice(&self.to_bytes());
+        Ok(32)
+    }
+
+    /// Deserialise this group public key from an array of bytes.
+    ///
+    /// As for [`DHPublicKey::from_bytes`], the Ristretto encoding guarantees
+    /// that a decoded key is free of any small-order component.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<GroupKey, Error> {
+        let point = CompressedRistretto(*bytes).decompress().ok_or(Error::SerialisationError)?;
+
+        Ok(GroupKey(point, *bytes))
+    }
+
+    /// Compute the negation \\(-Y\\) of this group key.
+    ///
+    /// # Sign convention
+    ///
+    /// The crate never normalises the sign of a group key: the group key is
+    /// \\(Y = a_0 \cdot B\\) for the group secret key \\(a_0\\), exactly as
+    /// computed by the key generation, and threshold signatures \\((R, z)\\)
+    /// verify as \\(z \cdot B = R + c \cdot Y\\). A signature which only
+    /// verifies under the negated key was therefore not produced by this
+    /// group's signing path.
+    pub fn negate(&self) -> GroupKey {
+        GroupKey::new(-self.0)
+    }
+
+    /// Derive a child group key from this group key and an arbitrary `tweak`,
+    /// as \\(Y' = Y + \\tau \\cdot B\\) where the tweak scalar \\(\\tau\\) is
+    /// obtained by hashing the parent group key along with the `tweak`.
+    ///
+    /// The corresponding secret shares are obtained by each participant with
+    /// [`SecretKey::derive_child`].
+    pub fn derive_child(&self, tweak: &[u8]) -> GroupKey {
+        GroupKey::new(self.0 + &RISTRETTO_BASEPOINT_TABLE * &compute_child_tweak(self, tweak))
+    }
+}
+
+/// Compute the tweak scalar used to derive a child key from a parent `group_key`.
+fn compute_child_tweak(group_key: &GroupKey, tweak: &[u8]) -> Scalar {
+    let mut h = Sha512::new();
+
+    h.update(b"ICE-FROST-child-key");
+    h.update(group_key.to_bytes());
+    h.update(tweak);
+
+    Scalar::from_hash(h)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+    use rand::rngs::OsRng;
+
+    use crate::test_utils::replayable;
+    use crate::test_utils::all_encrypted_shares;
+    use crate::test_utils::new_dealers;
+    use crate::test_utils::new_initial_states;
+    use crate::test_utils::run_dkg;
+    use crate::test_utils::run_round_one;
+    use crate::test_utils::ControlledRng;
+    use crate::test_utils::FailingRng;
+    use crate::test_utils::RecordingRng;
+    use crate::test_utils::StreamRng;
+
+    #[test]
+    fn nizk_of_secret_key() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng = OsRng;
+
+        let (p, _, _) = Participant::new_dealer(&params, 0, "Φ", &mut rng);
+        let result = p.proof_of_secret_key.as_ref().unwrap().verify(&p.index, p.public_key().unwrap(), "Φ");
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn secret_share_from_one_coefficients() {
+        let mut coeffs: Vec<Scalar> = Vec::new();
+
+        for _ in 0..5 {
+            coeffs.push(Scalar::one());
+        }
+
+        let coefficients = Coefficients(coeffs);
+        let share = SecretShare::evaluate_polynomial(&1, &1, &coefficients);
+
+        assert!(share.polynomial_evaluation == Scalar::from(5u8));
+
+        let mut commitments = VerifiableSecretSharingCommitment { index: 1, points: Vec::new() };
+
+        for i in 0..5 {
+            commitments.points.push(&RISTRETTO_BASEPOINT_TABLE * &coefficients.0[i]);
+        }
+
+        assert!(share.verify(&commitments).is_ok());
+    }
+
+    #[test]
+    fn secret_share_participant_index_zero() {
+        let mut coeffs: Vec<Scalar> = Vec::new();
+
+        for _ in 0..5 {
+            coeffs.push(Scalar::one());
+        }
+
+        let coefficients = Coefficients(coeffs);
+        let share = SecretShare::evaluate_polynomial(&1, &0, &coefficients);
+
+        assert!(share.polynomial_evaluation == Scalar::one());
+
+        let mut commitments = VerifiableSecretSharingCommitment { index: 1, points: Vec::new() };
+
+        for i in 0..5 {
+            commitments.points.push(&RISTRETTO_BASEPOINT_TABLE * &coefficients.0[i]);
+        }
+
+        assert!(share.verify(&commitments).is_ok());
+    }
+
+    #[test]
+    fn single_party_keygen() {
+        let params = Parameters { n: 1, t: 1 };
+        let mut rng = OsRng;
+
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+
+        p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").unwrap();
+
+        let participants: Vec<Participant> = vec![p1.clone()];
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                 &p1_dh_sk,
+                                                                 &p1.index,
+                                                                 &p1coeffs,
+                                                                 &participants,
+                                                                 "Φ",
+                                                                 &mut rng).unwrap();
+        let p1_my_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().unwrap().clone();
+        let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap();
+        let result = p1_state.finish();
+
+        assert!(result.is_ok());
+
+        let (p1_group_key, p1_secret_key) = result.unwrap();
+
+        assert!(p1_group_key.0.compress() == (&p1_secret_key.key * &RISTRETTO_BASEPOINT_TABLE).compress());
+    }
+
+    #[test]
+    fn keygen_3_out_of_5() {
+        let params = Parameters { n: 5, t: 3 };
+        let mut rng = OsRng;
+
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+        let (p4, p4coeffs, p4_dh_sk) = Participant::new_dealer(&params, 4, "Φ", &mut rng);
+        let (p5, p5coeffs, p5_dh_sk) = Participant::new_dealer(&params, 5, "Φ", &mut rng);
+
+        p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").unwrap();
+        p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").unwrap();
+        p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").unwrap();
+        p4.proof_of_secret_key.as_ref().unwrap().verify(&p4.index, p4.public_key().unwrap(), "Φ").unwrap();
+        p5.proof_of_secret_key.as_ref().unwrap().verify(&p5.index, p5.public_key().unwrap(), "Φ").unwrap();
+
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone(), p4.clone(), p5.clone());
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                 &p1_dh_sk,
+                                                                 &p1.index,
+                                                                 &p1coeffs,
+                                                                 &participants,
+                                                                 "Φ",
+                                                                 &mut rng).unwrap();
+        let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().unwrap();
+
+        let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                 &p2_dh_sk,
+                                                                 &p2.index,
+                                                                 &p2coeffs,
+                                                                 &participants,
+                                                                 "Φ",
+                                                                 &mut rng).unwrap();
+        let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().unwrap();
+
+        let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                  &p3_dh_sk,
+                                                                  &p3.index,
+                                                                  &p3coeffs,
+                                                                  &participants,
+                                                                  "Φ",
+                                                                  &mut rng).unwrap();
+        let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().unwrap();
+
+        let (p4_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                 &p4_dh_sk,
+                                                                 &p4.index,
+                                                                 &p4coeffs,
+                                                                 &participants,
+                                                                 "Φ",
+                                                                 &mut rng).unwrap();
+        let p4_their_encrypted_secret_shares = p4_state.their_encrypted_secret_shares().unwrap();
+
+        let (p5_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                 &p5_dh_sk,
+                                                                 &p5.index,
+                                                                 &p5coeffs,
+                                                                 &participants,
+                                                                 "Φ",
+                                                                 &mut rng).unwrap();
+        let p5_their_encrypted_secret_shares = p5_state.their_encrypted_secret_shares().unwrap();
+
+        let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                       p2_their_encrypted_secret_shares[0].clone(),
+                                       p3_their_encrypted_secret_shares[0].clone(),
+                                       p4_their_encrypted_secret_shares[0].clone(),
+                                       p5_their_encrypted_secret_shares[0].clone());
+
+        let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
+                                       p2_their_encrypted_secret_shares[1].clone(),
+                                       p3_their_encrypted_secret_shares[1].clone(),
+                                       p4_their_encrypted_secret_shares[1].clone(),
+                                       p5_their_encrypted_secret_shares[1].clone());
+
+        let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                       p2_their_encrypted_secret_shares[2].clone(),
+                                       p3_their_encrypted_secret_shares[2].clone(),
+                                       p4_their_encrypted_secret_shares[2].clone(),
+                                       p5_their_encrypted_secret_shares[2].clone());
+
+        let p4_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[3].clone(),
+                                       p2_their_encrypted_secret_shares[3].clone(),
+                                       p3_their_encrypted_secret_shares[3].clone(),
+                                       p4_their_encrypted_secret_shares[3].clone(),
+                                       p5_their_encrypted_secret_shares[3].clone());
+
+        let p5_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[4].clone(),
+                                       p2_their_encrypted_secret_shares[4].clone(),
+                                       p3_their_encrypted_secret_shares[4].clone(),
+                                       p4_their_encrypted_secret_shares[4].clone(),
+                                       p5_their_encrypted_secret_shares[4].clone());
+
+        let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap();
+        let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).unwrap();
+        let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).unwrap();
+        let p4_state = p4_state.to_round_two(p4_my_encrypted_secret_shares, &mut rng).unwrap();
+        let p5_state = p5_state.to_round_two(p5_my_encrypted_secret_shares, &mut rng).unwrap();
+
+        let (p1_group_key, p1_secret_key) = p1_state.finish().unwrap();
+        let (p2_group_key, p2_secret_key) = p2_state.finish().unwrap();
+        let (p3_group_key, p3_secret_key) = p3_state.finish().unwrap();
+        let (p4_group_key, p4_secret_key) = p4_state.finish().unwrap();
+        let (p5_group_key, p5_secret_key) = p5_state.finish().unwrap();
+
+        assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
+        assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+        assert!(p3_group_key.0.compress() == p4_group_key.0.compress());
+        assert!(p4_group_key.0.compress() == p5_group_key.0.compress());
+
+        let mut group_secret_key = Scalar::zero();
+        let indices = [1, 2, 3, 4, 5];
+
+        group_secret_key += calculate_lagrange_coefficients(&1, &indices).unwrap()*p1_secret_key.key;
+        group_secret_key += calculate_lagrange_coefficients(&2, &indices).unwrap()*p2_secret_key.key;
+        group_secret_key += calculate_lagrange_coefficients(&3, &indices).unwrap()*p3_secret_key.key;
+        group_secret_key += calculate_lagrange_coefficients(&4, &indices).unwrap()*p4_secret_key.key;
+        group_secret_key += calculate_lagrange_coefficients(&5, &indices).unwrap()*p5_secret_key.key;
+
+        let group_key = &group_secret_key * &RISTRETTO_BASEPOINT_TABLE;
+
+        assert!(p5_group_key.0.compress() == group_key.compress())
+    }
+
+
+    #[test]
+    fn keygen_collect_shares_for() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng = OsRng;
+
+        let dealers: Vec<_> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng))
+            .collect();
+        let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+
+        let all_dealers_shares: Vec<Vec<EncryptedSecretShare>> = dealers
+            .iter()
+            .map(|(p, coefficients, dh_sk)| {
+                let (state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+                    &params, dh_sk, &p.index, coefficients, &participants, "Φ", &mut rng).unwrap();
+                state.their_encrypted_secret_shares().unwrap().clone()
+            })
+            .collect();
+
+        for receiver in 1..=params.n {
+            let shares = collect_shares_for(receiver, &all_dealers_shares);
+
+            // One share from each dealer, in the order of the dealers.
+            assert_eq!(shares.len(), dealers.len());
+            for (share, (dealer, _, _)) in shares.iter().zip(dealers.iter()) {
+                assert_eq!(share.sender_index, dealer.index);
+                assert_eq!(share.receiver_index, receiver);
+            }
+        }
+
+        assert!(collect_shares_for(params.n + 1, &all_dealers_shares).is_empty());
+        assert!(collect_shares_for(1, &[]).is_empty());
+    }
+
+    #[test]
+    fn keygen_2_out_of_3() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng = OsRng;
+
+            let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
+            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
+            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &p1_dh_sk,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &p2_dh_sk,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &p3_dh_sk,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                           p2_their_encrypted_secret_shares[0].clone(),
+                                           p3_their_encrypted_secret_shares[0].clone());
+            let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
+                                           p2_their_encrypted_secret_shares[1].clone(),
+                                           p3_their_encrypted_secret_shares[1].clone());
+            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                           p2_their_encrypted_secret_shares[2].clone(),
+                                           p3_their_encrypted_secret_shares[2].clone());
+
+            let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+            let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
+            let (p2_group_key, _p2_secret_key) = p2_state.finish().or(Err(()))?;
+            let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+
+            assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
+            assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+
+            Ok(())
+        }
+        assert!(do_test().is_ok());
+    }
+
+    #[test]
+    fn keygen_static_2_out_of_3_with_common_participants() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng = OsRng;
+
+            let (dealer1, dealer1coeffs, dealer1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (dealer2, dealer2coeffs, dealer2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (dealer3, dealer3coeffs, dealer3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+            dealer1.proof_of_secret_key.as_ref().unwrap().verify(&dealer1.index, dealer1.public_key().unwrap(), "Φ").or(Err(()))?;
+            dealer2.proof_of_secret_key.as_ref().unwrap().verify(&dealer2.index, dealer2.public_key().unwrap(), "Φ").or(Err(()))?;
+            dealer3.proof_of_secret_key.as_ref().unwrap().verify(&dealer3.index, dealer3.public_key().unwrap(), "Φ").or(Err(()))?;
+
+            let dealers: Vec<Participant> = vec!(dealer1.clone(), dealer2.clone(), dealer3.clone());
+            let (dealer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dealer1_dh_sk,
+                                                                     &dealer1.index,
+                                                                     &dealer1coeffs,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let dealer1_their_encrypted_secret_shares = dealer1_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (dealer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dealer2_dh_sk,
+                                                                     &dealer2.index,
+                                                                     &dealer2coeffs,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let dealer2_their_encrypted_secret_shares = dealer2_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (dealer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dealer3_dh_sk,
+                                                                     &dealer3.index,
+                                                                     &dealer3coeffs,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let dealer3_their_encrypted_secret_shares = dealer3_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let dealer1_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[0].clone(),
+                                                          dealer2_their_encrypted_secret_shares[0].clone(),
+                                                          dealer3_their_encrypted_secret_shares[0].clone());
+            let dealer2_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[1].clone(),
+                                                          dealer2_their_encrypted_secret_shares[1].clone(),
+                                                          dealer3_their_encrypted_secret_shares[1].clone());
+            let dealer3_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[2].clone(),
+                                                          dealer2_their_encrypted_secret_shares[2].clone(),
+                                                          dealer3_their_encrypted_secret_shares[2].clone());
+
+            let dealer1_state = dealer1_state.to_round_two(dealer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let dealer2_state = dealer2_state.to_round_two(dealer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let dealer3_state = dealer3_state.to_round_two(dealer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+            let (dealer1_group_key, dealer1_secret_key) = dealer1_state.finish().or(Err(()))?;
+            let (dealer2_group_key, dealer2_secret_key) = dealer2_state.finish().or(Err(()))?;
+            let (dealer3_group_key, dealer3_secret_key) = dealer3_state.finish().or(Err(()))?;
+
+            assert!(dealer1_group_key.0.compress() == dealer2_group_key.0.compress());
+            assert!(dealer2_group_key.0.compress() == dealer3_group_key.0.compress());
+
+            let (signer1, signer1_dh_sk) = Participant::new_signer(&params, 1, "Φ", &mut rng);
+            let (signer2, signer2_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+            // Dealer 3 is also a participant of the next set of signers
+            let (signer3, signer3_dh_sk) = (dealer3.clone(), dealer3_dh_sk);
+
+            let signers: Vec<Participant> = vec!(signer1.clone(), signer2.clone(), signer3.clone());
+
+            let (dealer1_for_signers, dealer1_encrypted_shares_for_signers, _participant_lists) =
+                Participant::reshare(&params, dealer1_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+            let (dealer2_for_signers, dealer2_encrypted_shares_for_signers, _participant_lists) =
+                Participant::reshare(&params, dealer2_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+            let (dealer3_for_signers, dealer3_encrypted_shares_for_signers, _participant_lists) =
+                Participant::reshare(&params, dealer3_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+
+            let dealers: Vec<Participant> = vec!(dealer1_for_signers, dealer2_for_signers, dealer3_for_signers);
+            let (signer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params,
+                                                                     &signer1_dh_sk,
+                                                                     &signer1.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let (signer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params,
+                                                                     &signer2_dh_sk,
+                                                                     &signer2.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let (signer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params,
+                                                                     &signer3_dh_sk,
+                                                                     &signer3.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let signer1_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[0].clone(),
+                                                          dealer2_encrypted_shares_for_signers[0].clone(),
+                                                          dealer3_encrypted_shares_for_signers[0].clone());
+            let signer2_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[1].clone(),
+                                                          dealer2_encrypted_shares_for_signers[1].clone(),
+                                                          dealer3_encrypted_shares_for_signers[1].clone());
+            let signer3_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[2].clone(),
+                                                          dealer2_encrypted_shares_for_signers[2].clone(),
+                                                          dealer3_encrypted_shares_for_signers[2].clone());
+
+            let signer1_state = signer1_state.to_round_two(signer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let signer2_state = signer2_state.to_round_two(signer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let signer3_state = signer3_state.to_round_two(signer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+            let (signer1_group_key, _signer1_secret_key) = signer1_state.finish().or(Err(()))?;
+            let (signer2_group_key, _signer2_secret_key) = signer2_state.finish().or(Err(()))?;
+            let (signer3_group_key, _signer3_secret_key) = signer3_state.finish().or(Err(()))?;
+
+            assert!(signer1_group_key.0.compress() == signer2_group_key.0.compress());
+            assert!(signer2_group_key.0.compress() == signer3_group_key.0.compress());
+
+            assert!(signer1_group_key.0.compress() == dealer1_group_key.0.compress());
+
+            Ok(())
+        }
+        assert!(do_test().is_ok());
+    }
+
+    #[test]
+    fn keygen_static_2_out_of_3_into_3_out_of_5() {
+        fn do_test() -> Result<(), ()> {
+            let params_dealers = Parameters { n: 3, t: 2 };
+            let mut rng = OsRng;
+
+            let (dealer1, dealer1coeffs, dealer1_dh_sk) = Participant::new_dealer(&params_dealers, 1, "Φ", &mut rng);
+            let (dealer2, dealer2coeffs, dealer2_dh_sk) = Participant::new_dealer(&params_dealers, 2, "Φ", &mut rng);
+            let (dealer3, dealer3coeffs, dealer3_dh_sk) = Participant::new_dealer(&params_dealers, 3, "Φ", &mut rng);
+
+            dealer1.proof_of_secret_key.as_ref().unwrap().verify(&dealer1.index, dealer1.public_key().unwrap(), "Φ").or(Err(()))?;
+            dealer2.proof_of_secret_key.as_ref().unwrap().verify(&dealer2.index, dealer2.public_key().unwrap(), "Φ").or(Err(()))?;
+            dealer3.proof_of_secret_key.as_ref().unwrap().verify(&dealer3.index, dealer3.public_key().unwrap(), "Φ").or(Err(()))?;
+
+            let dealers: Vec<Participant> = vec!(dealer1.clone(), dealer2.clone(), dealer3.clone());
+            let (dealer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params_dealers,
+                                                                     &dealer1_dh_sk,
+                                                                     &dealer1.index,
+                                                                     &dealer1coeffs,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let dealer1_their_encrypted_secret_shares = dealer1_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (dealer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params_dealers,
+                                                                     &dealer2_dh_sk,
+                                                                     &dealer2.index,
+                                                                     &dealer2coeffs,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let dealer2_their_encrypted_secret_shares = dealer2_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (dealer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params_dealers,
+                                                                     &dealer3_dh_sk,
+                                                                     &dealer3.index,
+                                                                     &dealer3coeffs,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let dealer3_their_encrypted_secret_shares = dealer3_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let dealer1_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[0].clone(),
+                                                          dealer2_their_encrypted_secret_shares[0].clone(),
+                                                          dealer3_their_encrypted_secret_shares[0].clone());
+            let dealer2_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[1].clone(),
+                                                          dealer2_their_encrypted_secret_shares[1].clone(),
+                                                          dealer3_their_encrypted_secret_shares[1].clone());
+            let dealer3_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[2].clone(),
+                                                          dealer2_their_encrypted_secret_shares[2].clone(),
+                                                          dealer3_their_encrypted_secret_shares[2].clone());
+
+            let dealer1_state = dealer1_state.to_round_two(dealer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let dealer2_state = dealer2_state.to_round_two(dealer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let dealer3_state = dealer3_state.to_round_two(dealer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+            let (dealer1_group_key, dealer1_secret_key) = dealer1_state.finish().or(Err(()))?;
+            let (dealer2_group_key, dealer2_secret_key) = dealer2_state.finish().or(Err(()))?;
+            let (dealer3_group_key, dealer3_secret_key) = dealer3_state.finish().or(Err(()))?;
+
+            assert!(dealer1_group_key.0.compress() == dealer2_group_key.0.compress());
+            assert!(dealer2_group_key.0.compress() == dealer3_group_key.0.compress());
+
+            let params_signers = Parameters { n: 5, t: 3 };
+            let (signer1, signer1_dh_sk) = Participant::new_signer(&params_signers, 1, "Φ", &mut rng);
+            let (signer2, signer2_dh_sk) = Participant::new_signer(&params_signers, 2, "Φ", &mut rng);
+            let (signer3, signer3_dh_sk) = Participant::new_signer(&params_signers, 3, "Φ", &mut rng);
+            let (signer4, signer4_dh_sk) = Participant::new_signer(&params_signers, 4, "Φ", &mut rng);
+            let (signer5, signer5_dh_sk) = Participant::new_signer(&params_signers, 5, "Φ", &mut rng);
+
+            let signers: Vec<Participant> = vec!(signer1.clone(), signer2.clone(), signer3.clone(), signer4.clone(), signer5.clone());
+
+            let (dealer1_for_signers, dealer1_encrypted_shares_for_signers, _participant_lists) =
+                Participant::reshare(&params_signers, dealer1_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+            let (dealer2_for_signers, dealer2_encrypted_shares_for_signers, _participant_lists) =
+                Participant::reshare(&params_signers, dealer2_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+            let (dealer3_for_signers, dealer3_encrypted_shares_for_signers, _participant_lists) =
+                Participant::reshare(&params_signers, dealer3_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+
+            let dealers: Vec<Participant> = vec!(dealer1_for_signers, dealer2_for_signers, dealer3_for_signers);
+            let (signer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
+                                                                     &signer1_dh_sk,
+                                                                     &signer1.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let (signer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
+                                                                     &signer2_dh_sk,
+                                                                     &signer2.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let (signer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
+                                                                     &signer3_dh_sk,
+                                                                     &signer3.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let (signer4_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
+                                                                     &signer4_dh_sk,
+                                                                     &signer4.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let (signer5_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
+                                                                     &signer5_dh_sk,
+                                                                     &signer5.index,
+                                                                     &dealers,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+
+            let signer1_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[0].clone(),
+                                                          dealer2_encrypted_shares_for_signers[0].clone(),
+                                                          dealer3_encrypted_shares_for_signers[0].clone());
+            let signer2_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[1].clone(),
+                                                          dealer2_encrypted_shares_for_signers[1].clone(),
+                                                          dealer3_encrypted_shares_for_signers[1].clone());
+            let signer3_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[2].clone(),
+                                                          dealer2_encrypted_shares_for_signers[2].clone(),
+                                                          dealer3_encrypted_shares_for_signers[2].clone());
+            let signer4_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[3].clone(),
+                                                          dealer2_encrypted_shares_for_signers[3].clone(),
+                                                          dealer3_encrypted_shares_for_signers[3].clone());
+            let signer5_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[4].clone(),
+                                                          dealer2_encrypted_shares_for_signers[4].clone(),
+                                                          dealer3_encrypted_shares_for_signers[4].clone());
+
+            let signer1_state = signer1_state.to_round_two(signer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let signer2_state = signer2_state.to_round_two(signer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let signer3_state = signer3_state.to_round_two(signer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let signer4_state = signer4_state.to_round_two(signer4_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let signer5_state = signer5_state.to_round_two(signer5_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+            let (signer1_group_key, _signer1_secret_key) = signer1_state.finish().or(Err(()))?;
+            let (signer2_group_key, _signer2_secret_key) = signer2_state.finish().or(Err(()))?;
+            let (signer3_group_key, _signer3_secret_key) = signer3_state.finish().or(Err(()))?;
+            let (signer4_group_key, _signer4_secret_key) = signer4_state.finish().or(Err(()))?;
+            let (signer5_group_key, _signer5_secret_key) = signer5_state.finish().or(Err(()))?;
+
+            assert!(signer1_group_key.0.compress() == signer2_group_key.0.compress());
+            assert!(signer2_group_key.0.compress() == signer3_group_key.0.compress());
+            assert!(signer3_group_key.0.compress() == signer4_group_key.0.compress());
+            assert!(signer4_group_key.0.compress() == signer5_group_key.0.compress());
+
+            assert!(signer1_group_key.0.compress() == dealer1_group_key.0.compress());
+
+            Ok(())
+        }
+        assert!(do_test().is_ok());
+    }
+
+    #[test]
+    fn encrypt_and_decrypt() {
+        let mut rng: OsRng = OsRng;
+
+        let original_share = SecretShare { sender_index: 1,
+                                           receiver_index: 2,
+                                           polynomial_evaluation: Scalar::random(&mut rng)};
+
+        let mut key = [0u8; 32];
+        rng.fill(&mut key);
+
+        let encrypted_share = encrypt_share(&original_share, &key, &mut rng).unwrap();
+        let decrypted_share = decrypt_share(&encrypted_share, &key);
+
+        assert!(decrypted_share.is_ok());
+        assert!(original_share.polynomial_evaluation == decrypted_share.unwrap().polynomial_evaluation);
+    }
+
+    #[test]
+    fn keygen_2_out_of_3_with_random_keys() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
+
+            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
+            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
+            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk1,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk2,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &dh_sk3,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                           p2_their_encrypted_secret_shares[0].clone(),
+                                           p3_their_encrypted_secret_shares[0].clone());
+            let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
+                                           p2_their_encrypted_secret_shares[1].clone(),
+                                           p3_their_encrypted_secret_shares[1].clone());
+            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                           p2_their_encrypted_secret_shares[2].clone(),
+                                           p3_their_encrypted_secret_shares[2].clone());
+
+            let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+            let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
+            let (p2_group_key, _p2_secret_key) = p2_state.finish().or(Err(()))?;
+            let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+
+            assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
+            assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+
+            Ok(())
+        }
+        assert!(do_test().is_ok());
+    }
+
+    #[test]
+    fn keygen_verify_complaint() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
+
+            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
+            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
+            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk1,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk2,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &dh_sk3,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let mut complaint: Complaint;
+
+            // Wrong decryption from nonce
+            {
+                let mut wrong_encrypted_secret_share = p1_their_encrypted_secret_shares[1].clone();
+                wrong_encrypted_secret_share.nonce = [42; 16];
+                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                               p2_their_encrypted_secret_shares[0].clone(),
+                                               p3_their_encrypted_secret_shares[0].clone());
+                // Wrong share inserted here!
+                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
+                                               p2_their_encrypted_secret_shares[1].clone(),
+                                               p3_their_encrypted_secret_shares[1].clone());
+                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                               p2_their_encrypted_secret_shares[2].clone(),
+                                               p3_their_encrypted_secret_shares[2].clone());
+
+                let p1_state = replayable(&p1_state).to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+                let p3_state = replayable(&p3_state).to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+                let complaints = replayable(&p2_state).to_round_two(p2_my_encrypted_secret_shares, &mut rng);
+                assert!(complaints.is_err());
+                let complaints = complaints.unwrap_err();
+                if let Error::Complaint(complaints) = complaints {
+                    assert!(complaints.len() == 1);
+
+                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]).unwrap();
+                    assert!(bad_index == 1);
+
+                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
+                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+
+                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
+
+                    // Copy for next test and change dh_key
+                    complaint = complaints[0].clone();
+                    complaint.dh_key[0] += 1;
+                } else {
+                    return Err(())
+                }
+            }
+
+            // Wrong decryption of polynomial evaluation
+            {
+                let mut wrong_encrypted_secret_share = p1_their_encrypted_secret_shares[1].clone();
+                wrong_encrypted_secret_share.encrypted_polynomial_evaluation = [42; 32];
+                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                               p2_their_encrypted_secret_shares[0].clone(),
+                                               p3_their_encrypted_secret_shares[0].clone());
+                // Wrong share inserted here!
+                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
+                                               p2_their_encrypted_secret_shares[1].clone(),
+                                               p3_their_encrypted_secret_shares[1].clone());
+                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                               p2_their_encrypted_secret_shares[2].clone(),
+                                               p3_their_encrypted_secret_shares[2].clone());
+
+                let p1_state = replayable(&p1_state).to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+                let p3_state = replayable(&p3_state).to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+                let complaints = replayable(&p2_state).to_round_two(p2_my_encrypted_secret_shares, &mut rng);
+                assert!(complaints.is_err());
+                let complaints = complaints.unwrap_err();
+                if let Error::Complaint(complaints) = complaints {
+                    assert!(complaints.len() == 1);
+
+                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]).unwrap();
+                    assert!(bad_index == 1);
+
+                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
+                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+
+                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
+                } else {
+                    return Err(())
+                }
+            }
+
+            // Wrong encrypted share
+            {
+                let dh_key = (p1.dh_public_key.0 * dh_sk1.0).compress().to_bytes();
+                let wrong_encrypted_secret_share = encrypt_share(
+                    &SecretShare {
+                        sender_index: 1,
+                        receiver_index: 2,
+                        polynomial_evaluation: Scalar::from(42u32)
+                    },
+                    &dh_key,
+                    &mut rng,
+                ).unwrap();
+                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                               p2_their_encrypted_secret_shares[0].clone(),
+                                               p3_their_encrypted_secret_shares[0].clone());
+                // Wrong share inserted here!
+                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
+                                               p2_their_encrypted_secret_shares[1].clone(),
+                                               p3_their_encrypted_secret_shares[1].clone());
+                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                               p2_their_encrypted_secret_shares[2].clone(),
+                                               p3_their_encrypted_secret_shares[2].clone());
+
+                let p1_state = replayable(&p1_state).to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+                let p3_state = replayable(&p3_state).to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+                let complaints = replayable(&p2_state).to_round_two(p2_my_encrypted_secret_shares, &mut rng);
+                assert!(complaints.is_err());
+                let complaints = complaints.unwrap_err();
+                if let Error::Complaint(complaints) = complaints {
+                    assert!(complaints.len() == 1);
+
+                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]).unwrap();
+                    assert!(bad_index == 1);
+
+                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
+                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+
+                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
+                } else {
+                    return Err(())
+                }
+            }
+
+            // Wrong complaint leads to blaming the complaint maker
+            {
+                let _p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                               p2_their_encrypted_secret_shares[0].clone(),
+                                               p3_their_encrypted_secret_shares[0].clone());
+                let _p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                               p2_their_encrypted_secret_shares[1].clone(),
+                                               p3_their_encrypted_secret_shares[1].clone());
+                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                               p2_their_encrypted_secret_shares[2].clone(),
+                                               p3_their_encrypted_secret_shares[2].clone());
+
+                let p3_state = replayable(&p3_state).to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+                let bad_index = p3_state.blame(&p1_their_encrypted_secret_shares[0], &complaint).unwrap();
+                assert!(bad_index == 2);
+            }
+
+            Ok(())
+        }
+        assert!(do_test().is_ok());
+    }
+
+    #[test]
+    fn keygen_resolve_complaints() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
+
+            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk1,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk2,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &dh_sk3,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            // Participant 1 sends a bad share to participant 2.
+            let mut wrong_encrypted_secret_share = p1_their_encrypted_secret_shares[1].clone();
+            wrong_encrypted_secret_share.nonce = [42; 16];
+            let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
+                                           p2_their_encrypted_secret_shares[1].clone(),
+                                           p3_their_encrypted_secret_shares[1].clone());
+            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                           p2_their_encrypted_secret_shares[2].clone(),
+                                           p3_their_encrypted_secret_shares[2].clone());
+
+            let p3_state = p3_state.clone().to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+            let honest_complaint = match p2_state.clone().to_round_two(p2_my_encrypted_secret_shares, &mut rng) {
+                Err(Error::Complaint(complaints)) => complaints[0].clone(),
+                _ => return Err(()),
+            };
+
+            // Participant 2 also falsely accuses participant 3, with a valid proof.
+            let dh_key = (p3.dh_public_key.0 * dh_sk2.0).compress().to_bytes();
+            let r = Scalar::random(&mut rng);
+            let a1 = &RISTRETTO_BASEPOINT_TABLE * &r;
+            let a2 = p3.dh_public_key.0 * r;
+
+            let mut h = Sha512::new();
+            h.update(p2.dh_public_key.compress().to_bytes());
+            h.update(p3.dh_public_key.compress().to_bytes());
+            h.update(dh_key);
+            h.update(a1.compress().to_bytes());
+            h.update(a2.compress().to_bytes());
+            let h = Scalar::from_hash(h);
+
+            let false_complaint = Complaint {
+                maker_index: 2,
+                accused_index: 3,
+                dh_key,
+                proof: ComplaintProof { a1, a2, z: r + h * dh_sk2.0 },
+            };
+
+            // Participant 2 finally accuses participant 1 with a tampered proof.
+            let mut invalid_complaint = honest_complaint.clone();
+            invalid_complaint.proof.z += Scalar::one();
+
+            let reports = p3_state.resolve_complaints(&[
+                (wrong_encrypted_secret_share.clone(), honest_complaint),
+                (p3_their_encrypted_secret_shares[1].clone(), false_complaint),
+                (wrong_encrypted_secret_share, invalid_complaint),
+            ]).unwrap();
+
+            assert_eq!(reports.len(), 3);
+            assert_eq!(reports[0], BlameReport { maker: 2, accused: 1, verdict: BlameVerdict::AccusedGuilty });
+            assert_eq!(reports[1], BlameReport { maker: 2, accused: 3, verdict: BlameVerdict::MakerLied });
+            assert_eq!(reports[2], BlameReport { maker: 2, accused: 1, verdict: BlameVerdict::ProofInvalid });
+
+            assert_eq!(reports[0].blamed_index(), 1);
+            assert_eq!(reports[1].blamed_index(), 2);
+            assert_eq!(reports[2].blamed_index(), 2);
+
+            Ok(())
+        }
+        assert!(do_test().is_ok());
+    }
+
+    #[test]
+    fn serialisation() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
+
+            let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
+            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
+            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &p1_dh_sk,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &p2_dh_sk,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &p3_dh_sk,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+
+            {
+                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                           p2_their_encrypted_secret_shares[0].clone(),
+                                           p3_their_encrypted_secret_shares[0].clone());
+                let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
+                                           p2_their_encrypted_secret_shares[1].clone(),
+                                           p3_their_encrypted_secret_shares[1].clone());
+                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                           p2_their_encrypted_secret_shares[2].clone(),
+                                           p3_their_encrypted_secret_shares[2].clone());
+
+                // Check serialisation
+
+                let bytes = p1.to_bytes();
+                assert_eq!(p1, Participant::from_bytes(&bytes).unwrap());
+
+                let bytes = p1coeffs.to_bytes();
+                let p1coeffs_deserialised = Coefficients::from_bytes(&bytes).unwrap();
+                assert_eq!(p1coeffs.0.len(), p1coeffs_deserialised.0.len());
+                for i in 0..p1coeffs.0.len() {
+                    assert_eq!(p1coeffs.0[i], p1coeffs_deserialised.0[i]);
+                }
+
+                let bytes = p1_dh_sk.to_bytes();
+                assert_eq!(p1_dh_sk, DHPrivateKey::from_bytes(&bytes).unwrap());
+
+                let bytes = p1.proof_of_secret_key.as_ref().unwrap().to_bytes();
+                assert_eq!(p1.proof_of_secret_key.unwrap(), NizkOfSecretKey::from_bytes(&bytes).unwrap());
+
+                let bytes = p1_state.their_encrypted_secret_shares().unwrap()[0].to_bytes();
+                assert_eq!(p1_state.their_encrypted_secret_shares().unwrap()[0], EncryptedSecretShare::from_bytes(&bytes).unwrap());
+
+                let bytes = p1_state.to_bytes();
+                assert_eq!(*p1_state.state, *DistributedKeyGeneration::<RoundOne>::from_bytes(&bytes).unwrap().state);
+
+                // Continue KeyGen
+
+                let p1_state = replayable(&p1_state).to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+                let p2_state = replayable(&p2_state).to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+                let p3_state = replayable(&p3_state).to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+                let (p1_group_key, _p1_secret_key) = p1_state.clone().finish().or(Err(()))?;
+                let (p2_group_key, _p2_secret_key) = p2_state.finish().or(Err(()))?;
+                let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+
+                assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
+                assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+
+                // Check serialisation
+                let bytes = p1_group_key.to_bytes();
+                assert_eq!(p1_group_key, GroupKey::from_bytes(&bytes).unwrap());
+
+                let bytes = p1_state.to_bytes();
+                assert_eq!(*p1_state.state, *DistributedKeyGeneration::<RoundTwo>::from_bytes(&bytes).unwrap().state);
+            }
+
+            {
+                let wrong_encrypted_secret_share = EncryptedSecretShare {sender_index: 1,
+                                                                         receiver_index: 2,
+                                                                         nonce: [0; 16],
+                                                                         encrypted_polynomial_evaluation: [0; 32]};
+
+                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                           p2_their_encrypted_secret_shares[0].clone(),
+                                           p3_their_encrypted_secret_shares[0].clone());
+                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
+                                           p2_their_encrypted_secret_shares[1].clone(),
+                                           p3_their_encrypted_secret_shares[1].clone());
+                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                           p2_their_encrypted_secret_shares[2].clone(),
+                                           p3_their_encrypted_secret_shares[2].clone());
+
+                let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+                let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+
+                let complaints = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng);
+                assert!(complaints.is_err());
+                let complaints = complaints.unwrap_err();
+                if let Error::Complaint(complaints) = complaints {
+                    assert!(complaints.len() == 1);
+
+                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]).unwrap();
 
-    #[allow(clippy::too_many_arguments)]
-    fn new_state_internal(
-        parameters: &Parameters,
-        dh_private_key: &DHPrivateKey,
-        my_index: &u32,
-        my_coefficients: Option<&Coefficients>,
-        participants: &[Participant],
-        context_string: &str,
-        from_dealer: bool,
-        from_signer: bool,
-        mut rng: impl RngCore + CryptoRng,
-    ) -> Result<(Self, DKGParticipantList), Error>
-    {
-        let mut their_commitments: Vec<VerifiableSecretSharingCommitment> = Vec::with_capacity(parameters.t as usize);
-        let mut their_dh_public_keys: Vec<(u32, DHPublicKey)> = Vec::with_capacity(parameters.t as usize);
-        let mut valid_participants: Vec<Participant> = Vec::with_capacity(parameters.n as usize);
-        let mut misbehaving_participants: Vec<u32> = Vec::new();
+                    assert!(bad_index == 1);
 
-        let dh_public_key = DHPublicKey(&RISTRETTO_BASEPOINT_TABLE * dh_private_key);
+                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
+                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
 
-        // Bail if we didn't get enough participants.
-        if participants.len() != parameters.n as usize {
-            return Err(Error::InvalidNumberOfParticipants(participants.len(), parameters.n));
-        }
+                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
 
-        // Check the public keys and the DH keys of the participants.
-        for p in participants.iter() {
-            // Always check the DH keys of the participants
-            match p.proof_of_dh_private_key.verify(&p.index, &p.dh_public_key, context_string) {
-                Ok(_)  => {
-                    // Signers additionally check the public keys of the signers
-                    if from_signer {
-                        let public_key = match p.public_key() {
-                            Some(key) => key,
-                            None      => {
-                                misbehaving_participants.push(p.index);
-                                continue;
-                            }
-                        };
-                        match p.proof_of_secret_key.as_ref().unwrap().verify(&p.index, public_key, context_string) {
-                            Ok(_)  => {
-                                valid_participants.push(p.clone());
-                                their_commitments.push(p.commitments.as_ref().unwrap().clone());
-                                their_dh_public_keys.push((p.index, p.dh_public_key.clone()));
-                            },
-                            Err(_) => misbehaving_participants.push(p.index),
-                        }
-                    } else {
-                        valid_participants.push(p.clone());
-                        their_dh_public_keys.push((p.index, p.dh_public_key.clone()));
-                    }
-                },
-                Err(_) => misbehaving_participants.push(p.index),
+                    // Check serialisation
+
+                    let bytes = complaints[0].proof.to_bytes();
+                    assert_eq!(complaints[0].proof, ComplaintProof::from_bytes(&bytes).unwrap());
+
+                    let bytes = complaints[0].to_bytes();
+                    assert_eq!(complaints[0], Complaint::from_bytes(&bytes).unwrap());
+
+                    Ok(())
+                } else {
+                    Err(())
+                }
             }
         }
 
-        // [DIFFERENT_TO_PAPER] If too many participants were misbehaving, return an error along their indices.
-        if valid_participants.len() < parameters.t as usize {
-            return Err(Error::TooManyInvalidParticipants(misbehaving_participants));
-        }
+        assert!(do_test().is_ok());
+    }
 
-        if !from_dealer && from_signer {
-            let state = ActualState {
-                parameters: *parameters,
-                index: *my_index,
-                dh_private_key: dh_private_key.clone(),
-                dh_public_key,
-                their_commitments: Some(their_commitments),
-                their_dh_public_keys,
-                their_encrypted_secret_shares: None,
-                my_secret_shares: None,
-            };
+    #[test]
+    fn individual_public_key_share() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
 
-            return Ok(
-                (
-                    DistributedKeyGeneration::<RoundOne> {
-                        state: Box::new(state),
-                        data: RoundOne {},
-                    },
-                    DKGParticipantList {
-                        valid_participants,
-                        misbehaving_participants:
-                            if misbehaving_participants.is_empty() {
-                                None
-                            } else {
-                                Some(misbehaving_participants)
-                            },
-                    }
-                )
-            )
-        }
+            let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        // [DIFFERENT_TO_PAPER] We pre-calculate the secret shares from Round 2
-        // Step 1 here since it doesn't require additional online activity.
-        // RICE-FROST: We also encrypt them into their_encrypted_secret_shares.
-        //
-        // Round 2
-        // Step 1: Each P_i securely sends to each other participant P_l a secret share
-        //         (l, f_i(l)) and keeps (i, f_i(i)) for themselves.
-        let mut their_encrypted_secret_shares: Vec<EncryptedSecretShare> = Vec::with_capacity(parameters.n as usize - 1);
+            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
+            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
+            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
 
-        // XXX need a way to index their_encrypted_secret_shares
-        for p in participants.iter() {
-            let share = SecretShare::evaluate_polynomial(my_index, &p.index, my_coefficients.unwrap());
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &p1_dh_sk,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
 
-            let dh_key = (p.dh_public_key.0 * dh_private_key.0).compress().to_bytes();
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &p2_dh_sk,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
 
-            their_encrypted_secret_shares.push(encrypt_share(&share, &dh_key, &mut rng));
-        }
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &p3_dh_sk,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
 
-        let state = ActualState {
-            parameters: *parameters,
-            index: *my_index,
-            dh_private_key: dh_private_key.clone(),
-            dh_public_key,
-            their_commitments: if !from_signer { None } else { Some(their_commitments) },
-            their_dh_public_keys,
-            their_encrypted_secret_shares: Some(their_encrypted_secret_shares),
-            my_secret_shares: None,
-        };
+            let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                           p2_their_encrypted_secret_shares[0].clone(),
+                                           p3_their_encrypted_secret_shares[0].clone());
+            let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
+                                           p2_their_encrypted_secret_shares[1].clone(),
+                                           p3_their_encrypted_secret_shares[1].clone());
+            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                           p2_their_encrypted_secret_shares[2].clone(),
+                                           p3_their_encrypted_secret_shares[2].clone());
 
-        Ok(
-            (
-                DistributedKeyGeneration::<RoundOne> {
-                    state: Box::new(state),
-                    data: RoundOne {},
-                },
-                DKGParticipantList {
-                    valid_participants,
-                    misbehaving_participants:
-                        if misbehaving_participants.is_empty() {
-                            None
-                        } else {
-                            Some(misbehaving_participants)
-                        },
-                }
-            )
-        )
-    }
+            let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
 
-    /// Retrieve an encrypted secret share for each other participant, to be given to them
-    /// at the end of `DistributedKeyGeneration::<RoundOne>`.
-    pub fn their_encrypted_secret_shares(&self) -> Result<&Vec<EncryptedSecretShare>, Error> {
-        self.state.their_encrypted_secret_shares.as_ref().ok_or(Error::NoEncryptedShares)
-    }
+            let (p1_group_key, p1_secret_key) = p1_state.finish().or(Err(()))?;
+            let (p2_group_key, p2_secret_key) = p2_state.finish().or(Err(()))?;
+            let (p3_group_key, p3_secret_key) = p3_state.finish().or(Err(()))?;
 
-    /// Progress to round two of the DKG protocol once we have sent each encrypted share
-    /// from `DistributedKeyGeneration::<RoundOne>.their_encrypted_secret_shares()` to its
-    /// respective other participant, and collected our shares from the other
-    /// participants in turn.
-    #[allow(clippy::wrong_self_convention)]
-    pub fn to_round_two(
-        mut self,
-        my_encrypted_secret_shares: Vec<EncryptedSecretShare>,
-        mut rng: impl RngCore + CryptoRng,
-    ) -> Result<DistributedKeyGeneration<RoundTwo>, Error>
-    {
-        // Zero out the other participants encrypted secret shares from memory.
-        if self.state.their_encrypted_secret_shares.is_some() {
-            self.state.their_encrypted_secret_shares.unwrap().zeroize();
-            // XXX Does setting this to None always call drop()?
-            self.state.their_encrypted_secret_shares = None;
-        }
+            assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
+            assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
 
-        // RICE-FROST
+            // Check the validity of each IndividualPublicKey
 
-        let mut complaints: Vec<Complaint> = Vec::new();
-        
-        if my_encrypted_secret_shares.len() != self.state.parameters.n as usize {
-            return Err(Error::MissingShares);
-        }
+            let p1_public_key = p1_secret_key.to_public();
+            let p2_public_key = p2_secret_key.to_public();
+            let p3_public_key = p3_secret_key.to_public();
 
-        let mut my_secret_shares: Vec<SecretShare> = Vec::new();
+            // The order does not matter
+            let commitments = [p2.commitments.unwrap(), p3.commitments.unwrap(), p1.commitments.unwrap()];
 
-        // Step 2.1: Each P_i decrypts their shares with
-        //           key k_il = pk_l^sk_i
-        for encrypted_share in my_encrypted_secret_shares.iter(){
-            for pk in self.state.their_dh_public_keys.iter(){
-                if pk.0 == encrypted_share.sender_index {
-                    let dh_key = (*pk.1 * self.state.dh_private_key.0).compress().to_bytes();
-
-                    // Step 2.2: Each share is verified by calculating:
-                    //           g^{f_l(i)} ?= \Prod_{k=0}^{t-1} \phi_{lk}^{i^{k} mod q},
-                    //           creating a complaint if the check fails.
-                    let decrypted_share = decrypt_share(encrypted_share, &dh_key);
-                    let decrypted_share_ref = &decrypted_share;
-                    
-                    for commitment in self.state.their_commitments.as_ref().unwrap().iter() {
-                        if commitment.index == encrypted_share.sender_index {
-                            // If the decrypted share is incorrect, P_i builds
-                            // a complaint
-
-                            if decrypted_share.is_err() || decrypted_share_ref.as_ref().unwrap().verify(commitment).is_err() {
-
-                                let r = Scalar::random(&mut rng);
-
-                                let a1 = &RISTRETTO_BASEPOINT_TABLE * &r;
-                                let a2 = *pk.1 * r;
-
-                                let mut h = Sha512::new();
-                                h.update(self.state.dh_public_key.compress().to_bytes());
-                                h.update(pk.1.compress().to_bytes());
-                                h.update(dh_key);
-                                h.update(a1.compress().to_bytes());
-                                h.update(a2.compress().to_bytes());
-
-                                let h = Scalar::from_hash(h);
-
-                                complaints.push(
-                                    Complaint {
-                                        maker_index: encrypted_share.receiver_index,
-                                        accused_index: pk.0,
-                                        dh_key,
-                                        proof: ComplaintProof {
-                                            a1,
-                                            a2,
-                                            z: r + h * self.state.dh_private_key.0,
-                                        }
-                                    }
-                                );
-                                break;
-                            }
-                        }
-                    }
-                    if let Ok(share) = decrypted_share {
-                        my_secret_shares.push(share);
-                    }
-                }
-            }
-        }
+            assert!(p1_public_key.verify(&commitments).is_ok());
+            assert!(p2_public_key.verify(&commitments).is_ok());
+            assert!(p3_public_key.verify(&commitments).is_ok());
 
-        if !complaints.is_empty() {
-            return Err(Error::Complaint(complaints))
+            assert!(p1_public_key.verify(&commitments[1..]).is_err());
+
+            // Check that the generated IndividualPublicKey from other participants match
+            let p1_recovered_public_key = IndividualPublicKey::generate_from_commitments(1, &commitments).unwrap();
+            let p2_recovered_public_key = IndividualPublicKey::generate_from_commitments(2, &commitments).unwrap();
+            let p3_recovered_public_key = IndividualPublicKey::generate_from_commitments(3, &commitments).unwrap();
+
+            assert_eq!(p1_public_key, p1_recovered_public_key);
+            assert_eq!(p2_public_key, p2_recovered_public_key);
+            assert_eq!(p3_public_key, p3_recovered_public_key);
+
+            Ok(())
         }
+        assert!(do_test().is_ok());
+    }
 
-        self.state.my_secret_shares = Some(my_secret_shares);
+    #[test]
+    fn write_to_buffer() {
+        let mut rng: OsRng = OsRng;
 
-        Ok(DistributedKeyGeneration::<RoundTwo> {
-            state: self.state,
-            data: RoundTwo {},
-        })
+        let secret_share = SecretShare {
+            sender_index: 1,
+            receiver_index: 2,
+            polynomial_evaluation: Scalar::random(&mut rng),
+        };
+        let encrypted_share = encrypt_share(&secret_share, &[7u8; 32], &mut rng).unwrap();
+        let secret_key = SecretKey { index: 2, key: Scalar::random(&mut rng) };
+        let public_key = secret_key.to_public();
+        let group_key = GroupKey::new(&RISTRETTO_BASEPOINT_TABLE * &Scalar::random(&mut rng));
+        let complaint = Complaint {
+            maker_index: 2,
+            accused_index: 1,
+            dh_key: [3u8; 32],
+            proof: ComplaintProof {
+                a1: &RISTRETTO_BASEPOINT_TABLE * &Scalar::random(&mut rng),
+                a2: &RISTRETTO_BASEPOINT_TABLE * &Scalar::random(&mut rng),
+                z: Scalar::random(&mut rng),
+            },
+        };
+
+        let mut buffer = [0u8; 136];
+
+        // Exact-fit buffers
+        assert_eq!(secret_share.write_to(&mut buffer[..40]), Ok(40));
+        assert_eq!(&buffer[..40], &secret_share.to_bytes()[..]);
+        assert_eq!(encrypted_share.write_to(&mut buffer[..56]), Ok(56));
+        assert_eq!(&buffer[..56], &encrypted_share.to_bytes()[..]);
+        assert_eq!(public_key.write_to(&mut buffer[..36]), Ok(36));
+        assert_eq!(&buffer[..36], &public_key.to_bytes()[..]);
+        assert_eq!(group_key.write_to(&mut buffer[..32]), Ok(32));
+        assert_eq!(&buffer[..32], &group_key.to_bytes()[..]);
+        assert_eq!(complaint.write_to(&mut buffer[..]), Ok(136));
+        assert_eq!(&buffer[..], &complaint.to_bytes()[..]);
+
+        // Larger buffers only have their prefix written
+        let mut buffer = [0u8; 64];
+        assert_eq!(group_key.write_to(&mut buffer), Ok(32));
+        assert_eq!(&buffer[32..], &[0u8; 32][..]);
+
+        // Too-small buffers
+        assert_eq!(secret_share.write_to(&mut buffer[..39]), Err(Error::SerialisationError));
+        assert_eq!(encrypted_share.write_to(&mut buffer[..55]), Err(Error::SerialisationError));
+        assert_eq!(public_key.write_to(&mut buffer[..35]), Err(Error::SerialisationError));
+        assert_eq!(group_key.write_to(&mut buffer[..31]), Err(Error::SerialisationError));
+        assert_eq!(complaint.write_to(&mut buffer), Err(Error::SerialisationError));
     }
 
-    /// Serialise this DKG to a Vec of bytes
-    pub fn to_bytes(&self) -> Vec<u8> {
-        let mut res = self.state.to_bytes();
-        res.push(1u8);
+    #[test]
+    fn keygen_verify_reshare() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
+
+            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk1,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
 
-        res
-    }
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk2,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
 
-    /// Deserialise this slice of bytes to a `DistributedKeyGeneration::<RoundOne>`
-    pub fn from_bytes(bytes: &[u8]) -> Result<DistributedKeyGeneration::<RoundOne>, Error> {
-        let state = ActualState::from_bytes(bytes)?;
-        let data = if bytes[bytes.len() - 1] == 1 {
-            RoundOne {}
-        } else {
-            return Err(Error::SerialisationError)
-        };
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &dh_sk3,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
 
-        Ok(
-            DistributedKeyGeneration::<RoundOne> {
-                state: Box::new(state),
-                data,
-            }
-        )
-    }
-}
+            let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
+                                           p2_their_encrypted_secret_shares[0].clone(),
+                                           p3_their_encrypted_secret_shares[0].clone());
 
-/// A secret share calculated by evaluating a polynomial with secret
-/// coefficients for some indeterminant.
-#[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
-#[zeroize(drop)]
-pub struct SecretShare {
-    /// The index of the share maker.
-    pub sender_index: u32,
-    /// The participant index that this secret share was calculated for.
-    pub receiver_index: u32,
-    /// The final evaluation of the polynomial for the participant-respective
-    /// indeterminant.
-    pub(crate) polynomial_evaluation: Scalar,
-}
+            let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+            let (_p1_group_key, p1_secret_key) = p1_state.finish().or(Err(()))?;
 
-impl SecretShare {
-    /// Evaluate the polynomial, `f(x)` for the secret coefficients at the value of `x`.
-    //
-    // XXX [PAPER] [CFRG] The participant index CANNOT be 0, or the secret share ends up being Scalar::zero().
-    pub(crate) fn evaluate_polynomial(sender_index: &u32, receiver_index: &u32, coefficients: &Coefficients) -> SecretShare {
-        let term: Scalar = (*receiver_index).into();
-        let mut sum: Scalar = Scalar::zero();
+            let old_commitments = [
+                p1.commitments.clone().unwrap(),
+                p2.commitments.clone().unwrap(),
+                p3.commitments.clone().unwrap(),
+            ];
 
-        // Evaluate using Horner's method.
-        for (receiver_index, coefficient) in coefficients.0.iter().rev().enumerate() {
-            // The secret is the constant term in the polynomial
-            sum += coefficient;
+            let (signer1, _signer1_dh_sk) = Participant::new_signer(&params, 1, "Φ", &mut rng);
+            let (signer2, _signer2_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+            let (signer3, _signer3_dh_sk) = Participant::new_signer(&params, 3, "Φ", &mut rng);
+            let signers: Vec<Participant> = vec!(signer1, signer2, signer3);
 
-            if receiver_index != (coefficients.0.len() - 1) {
-                sum *= term;
-            }
-        }
-        SecretShare { sender_index: *sender_index, receiver_index: *receiver_index, polynomial_evaluation: sum }
-    }
+            // A dealer resharing its genuine secret share is accepted.
+            let (dealer1, _dealer1_encrypted_shares, _participant_lists) =
+                Participant::reshare(&params, p1_secret_key.clone(), &signers, "Φ", &mut rng).or(Err(()))?;
 
-    /// Verify that this secret share was correctly computed w.r.t. some secret
-    /// polynomial coefficients attested to by some `commitment`.
-    pub(crate) fn verify(&self, commitment: &VerifiableSecretSharingCommitment) -> Result<(), Error> {
-        let lhs = &RISTRETTO_BASEPOINT_TABLE * &self.polynomial_evaluation;
-        let term: Scalar = self.receiver_index.into();
-        let mut rhs: RistrettoPoint = RistrettoPoint::identity();
+            assert!(dealer1.verify_reshare(&old_commitments).is_ok());
+            assert!(verify_reshare(&old_commitments, dealer1.commitments.as_ref().unwrap(), 1).is_ok());
+            assert!(verify_reshare(&old_commitments, dealer1.commitments.as_ref().unwrap(), 2).is_err());
 
-        for (index, com) in commitment.points.iter().rev().enumerate() {
-            rhs += com;
+            // A dealer substituting a random secret is detected.
+            let random_secret_key = SecretKey { index: 1, key: Scalar::random(&mut rng) };
+            let (cheating_dealer1, _dealer1_encrypted_shares, _participant_lists) =
+                Participant::reshare(&params, random_secret_key, &signers, "Φ", &mut rng).or(Err(()))?;
 
-            if index != (commitment.points.len() - 1) {
-                rhs *= term;
-            }
-        }
+            assert!(cheating_dealer1.verify_reshare(&old_commitments).is_err());
 
-        match lhs.compress() == rhs.compress() {
-            true => Ok(()),
-            false => Err(Error::ShareVerificationError),
+            Ok(())
         }
+        assert!(do_test().is_ok());
     }
 
-    /// Serialise this secret share to an array of bytes
-    pub fn to_bytes(&self) -> [u8; 40] {
-        let mut res = [0u8; 40];
-        res[0..4].copy_from_slice(&self.sender_index.to_le_bytes());
-        res[4..8].copy_from_slice(&self.receiver_index.to_le_bytes());
-        res[8..40].copy_from_slice(&self.polynomial_evaluation.to_bytes());
-
-        res
-    }
+    #[test]
+    fn keygen_pending_senders() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
 
-    /// Deserialise this slice of bytes to a `SecretShare`
-    pub fn from_bytes(bytes: &[u8; 40]) -> Result<SecretShare, Error> {
-        let sender_index = u32::from_le_bytes(
-            bytes[0..4]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
+            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, _p3coeffs, _dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        let receiver_index = u32::from_le_bytes(
-            bytes[4..8]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (mut p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk1,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?.clone();
 
-        let polynomial_evaluation = Scalar::from_canonical_bytes(
-            bytes[8..40]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?
-            ).ok_or(Error::SerialisationError)?;
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk2,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
 
-        Ok(SecretShare {
-            sender_index,
-            receiver_index,
-            polynomial_evaluation,
-        })
-    }
-}
+            assert_eq!(p1_state.pending_senders(), vec![1, 2, 3]);
 
+            p1_state.receive_encrypted_share(p2_their_encrypted_secret_shares[0].clone()).or(Err(()))?;
+            assert_eq!(p1_state.pending_senders(), vec![1, 3]);
 
-/// A secret share encrypted with a participant's public key
-#[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
-#[zeroize(drop)]
-pub struct EncryptedSecretShare {
-    /// The index of the share maker.
-    pub sender_index: u32,
-    /// The participant index that this secret share was calculated for.
-    pub receiver_index: u32,
-    /// The nonce to be used for decryption with AES-CTR mode.
-    pub nonce: [u8; 16],
-    /// The encrypted polynomial evaluation.
-    pub(crate) encrypted_polynomial_evaluation: [u8; 32],
-}
+            p1_state.receive_encrypted_share(p1_their_encrypted_secret_shares[0].clone()).or(Err(()))?;
+            assert_eq!(p1_state.pending_senders(), vec![3]);
 
-impl EncryptedSecretShare {
-    /// Serialise this encrypted secret share to an array of bytes
-    pub fn to_bytes(&self) -> [u8; 56] {
-        let mut res = [0u8; 56];
-        res[0..4].copy_from_slice(&self.sender_index.to_le_bytes());
-        res[4..8].copy_from_slice(&self.receiver_index.to_le_bytes());
-        res[8..24].copy_from_slice(&self.nonce.clone());
-        res[24..56].copy_from_slice(&self.encrypted_polynomial_evaluation.clone());
+            // Receiving the same share twice does not change anything.
+            p1_state.receive_encrypted_share(p2_their_encrypted_secret_shares[0].clone()).or(Err(()))?;
+            assert_eq!(p1_state.pending_senders(), vec![3]);
+            assert_eq!(p1_state.received_encrypted_shares().len(), 2);
 
-        res
-    }
+            // Shares intended for another participant are rejected.
+            assert!(p1_state.receive_encrypted_share(p2_their_encrypted_secret_shares[1].clone()).is_err());
+            assert_eq!(p1_state.pending_senders(), vec![3]);
 
-    /// Deserialise this slice of bytes to a `EncryptedSecretShare`
-    pub fn from_bytes(bytes: &[u8; 56]) -> Result<EncryptedSecretShare, Error> {
-        let sender_index = u32::from_le_bytes(
-            bytes[0..4]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
-        let receiver_index = u32::from_le_bytes(
-            bytes[4..8]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
-        let nonce = bytes[8..24]
-            .try_into()
-            .map_err(|_| Error::SerialisationError)?;
-        let encrypted_polynomial_evaluation = bytes[24..56]
-            .try_into()
-            .map_err(|_| Error::SerialisationError)?;
+            // The pending senders survive serialisation.
+            let p1_state = DistributedKeyGeneration::<RoundOne>::from_bytes(&p1_state.to_bytes()).or(Err(()))?;
+            assert_eq!(p1_state.pending_senders(), vec![3]);
 
-        Ok(EncryptedSecretShare {
-            sender_index,
-            receiver_index,
-            nonce,
-            encrypted_polynomial_evaluation,
-        })
+            Ok(())
+        }
+        assert!(do_test().is_ok());
     }
-}
 
-/// A proof that a generated complaint is valid. 
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct ComplaintProof {
-    /// a1 = g^r.
-    pub a1: RistrettoPoint,
-    /// a2 = pk_l^r.
-    pub a2: RistrettoPoint,
-    /// z = r + H(pk_i, pk_l, k_il).sh_i
-    pub z: Scalar,
-}
+    #[test]
+    fn keygen_blame_forged_dh_key() {
+        fn do_test() -> Result<(), ()> {
+            let params = Parameters { n: 3, t: 2 };
+            let mut rng: OsRng = OsRng;
 
-impl ComplaintProof {
-    /// Serialise this complaint proof to an array of bytes
-    pub fn to_bytes(&self) -> [u8; 96] {
-        let mut res = [0u8; 96];
-        res[0..32].copy_from_slice(&self.a1.compress().to_bytes());
-        res[32..64].copy_from_slice(&self.a2.compress().to_bytes());
-        res[64..96].copy_from_slice(&self.z.to_bytes());
+            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+            let (p3, p3coeffs, dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        res
-    }
+            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk1,
+                                                                     &p1.index,
+                                                                     &p1coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
 
-    /// Deserialise this slice of bytes to a `ComplaintProof`
-    pub fn from_bytes(bytes: &[u8; 96]) -> Result<ComplaintProof, Error> {
-        let mut array = [0u8; 32];
-        array.copy_from_slice(&bytes[0..32]);
-        let a1 = CompressedRistretto(array)
-            .decompress()
-            .ok_or(Error::SerialisationError)?;
+            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                     &dh_sk2,
+                                                                     &p2.index,
+                                                                     &p2coeffs,
+                                                                     &participants,
+                                                                     "Φ",
+                                                                     &mut rng).or(Err(()))?;
+            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
 
-        array.copy_from_slice(&bytes[32..64]);
-        let a2 = CompressedRistretto(array)
-            .decompress()
-            .ok_or(Error::SerialisationError)?;
+            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
+                                                                      &dh_sk3,
+                                                                      &p3.index,
+                                                                      &p3coeffs,
+                                                                      &participants,
+                                                                      "Φ",
+                                                                      &mut rng).or(Err(()))?;
+            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
 
-        array.copy_from_slice(&bytes[64..96]);
-        let z = Scalar::from_canonical_bytes(array)
-                .ok_or(Error::SerialisationError)?;
+            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
+                                           p2_their_encrypted_secret_shares[2].clone(),
+                                           p3_their_encrypted_secret_shares[2].clone());
+            let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
 
-        Ok(ComplaintProof { a1, a2, z })
-    }
-}
+            // Participant 2 forges a DH key under which the honest share of
+            // participant 1 would not decrypt correctly, and proves knowledge
+            // of its DH private key with respect to this forged key.
+            let dh_key = (p1.dh_public_key.0 * Scalar::random(&mut rng)).compress().to_bytes();
+            let r = Scalar::random(&mut rng);
+            let a1 = &RISTRETTO_BASEPOINT_TABLE * &r;
+            let a2 = p1.dh_public_key.0 * r;
+
+            let mut h = Sha512::new();
+            h.update(p2.dh_public_key.compress().to_bytes());
+            h.update(p1.dh_public_key.compress().to_bytes());
+            h.update(dh_key);
+            h.update(a1.compress().to_bytes());
+            h.update(a2.compress().to_bytes());
+            let h = Scalar::from_hash(h);
+
+            let forged_complaint = Complaint {
+                maker_index: 2,
+                accused_index: 1,
+                dh_key,
+                proof: ComplaintProof { a1, a2, z: r + h * dh_sk2.0 },
+            };
 
-/// A complaint generated when a participant receives a bad share.
-#[derive(Clone, Debug, PartialEq)]
-pub struct Complaint {
-    /// The index of the complaint maker.
-    pub maker_index: u32,
-    /// The index of the alleged misbehaving participant.
-    pub accused_index: u32,
-    /// The shared DH key.
-    pub dh_key: [u8; 32],
-    /// The complaint proof.
-    pub proof: ComplaintProof,
-}
+            assert!(decrypt_share(&p1_their_encrypted_secret_shares[1], &dh_key)
+                .and_then(|share| share.verify(p1.commitments.as_ref().unwrap()))
+                .is_err());
 
-impl Complaint {
-    /// A complaint is valid if:
-    /// --  a1 + h.pk_i = z.g
-    /// --  a2 + h.k_il = z.pk_l
-    pub fn verify(
-        &self, 
-        pk_i: &RistrettoPoint,
-        pk_l: &RistrettoPoint,
-    ) -> Result<(), Error> {
-        let mut h = Sha512::new();
-        h.update(pk_i.compress().to_bytes());
-        h.update(pk_l.compress().to_bytes());
-        h.update(self.dh_key);
-        h.update(self.proof.a1.compress().to_bytes());
-        h.update(self.proof.a2.compress().to_bytes());
+            let report = p3_state.resolve_complaint(&p1_their_encrypted_secret_shares[1], &forged_complaint).unwrap();
+            assert_eq!(report.verdict, BlameVerdict::ProofInvalid);
+            assert_eq!(p3_state.blame(&p1_their_encrypted_secret_shares[1], &forged_complaint), Ok(2));
 
-        let h = Scalar::from_hash(h);
+            // The complaint must also refer to the share sent by the accused to the maker.
+            let report = p3_state.resolve_complaint(&p1_their_encrypted_secret_shares[2], &forged_complaint).unwrap();
+            assert_eq!(report.verdict, BlameVerdict::ProofInvalid);
 
-        if self.proof.a1 + pk_i * h != &RISTRETTO_BASEPOINT_TABLE * &self.proof.z {
-            return Err(Error::ComplaintVerificationError)
+            Ok(())
         }
+        assert!(do_test().is_ok());
+    }
 
-        if let Some(key_as_point) = CompressedRistretto::from_slice(&self.dh_key).decompress() {
-            if self.proof.a2 + key_as_point * h != pk_l * self.proof.z {
-                return Err(Error::ComplaintVerificationError)
+    #[test]
+    fn participant_metadata_serialisation() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        let (mut dealer, _dealer_coeffs, _dealer_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (mut signer, _signer_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+
+        for participant in [&mut dealer, &mut signer] {
+            assert!(participant.metadata.is_none());
+
+            let bytes = participant.to_bytes();
+            let deserialised = Participant::from_bytes(&bytes).unwrap();
+            assert_eq!(deserialised.metadata, None);
+            assert_eq!(deserialised.to_bytes(), bytes);
+
+            // Participants serialised without the metadata flag are still read.
+            let deserialised = Participant::from_bytes(&bytes[..bytes.len() - 1]).unwrap();
+            assert_eq!(deserialised.metadata, None);
+            assert_eq!(deserialised.to_bytes(), bytes);
+
+            participant.metadata = Some(b"operator: alice, address: 10.0.0.1".to_vec());
+
+            let bytes = participant.to_bytes();
+            let deserialised = Participant::from_bytes(&bytes).unwrap();
+            assert_eq!(deserialised.metadata, participant.metadata);
+            assert_eq!(deserialised.proof_of_dh_private_key, participant.proof_of_dh_private_key);
+            assert_eq!(deserialised.proof_of_secret_key, participant.proof_of_secret_key);
+            assert_eq!(deserialised.to_bytes(), bytes);
+
+            // Truncated metadata is rejected, as is a crafted length.
+            assert!(Participant::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+            let mut crafted = bytes.clone();
+            let len_position = bytes.len() - participant.metadata.as_ref().unwrap().len() - 4;
+            crafted[len_position..len_position + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+            assert_eq!(Participant::from_bytes(&crafted), Err(Error::SerialisationError));
+
+            // Truncations anywhere are rejected without panicking.
+            let without_metadata = bytes.len() - participant.metadata.as_ref().unwrap().len() - 5;
+            for len in (0..bytes.len()).filter(|&len| len != without_metadata) {
+                assert!(Participant::from_bytes(&bytes[..len]).is_err());
             }
-        } else {
-            return Err(Error::ComplaintVerificationError)
         }
 
-        Ok(())
+        // Metadata is not covered by the proofs.
+        assert!(dealer.proof_of_dh_private_key.verify(&dealer.index, &dealer.dh_public_key, "Φ").is_ok());
     }
 
-    /// Serialise this complaint to an array of bytes
-    pub fn to_bytes(&self) -> [u8; 136] {
-        let mut res = [0u8; 136];
-        res[0..4].copy_from_slice(&self.maker_index.to_le_bytes());
-        res[4..8].copy_from_slice(&self.accused_index.to_le_bytes());
-        res[8..40].copy_from_slice(&self.dh_key.clone());
-        res[40..136].copy_from_slice(&self.proof.to_bytes());
+    #[test]
+    fn rotate_dh_keypair() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        res
-    }
+        let (mut dealer, _dealer_coeffs, old_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let old_dh_pk = dealer.dh_public_key.clone();
+        let old_proof = dealer.proof_of_dh_private_key.clone();
+        let commitments = dealer.commitments.clone();
+        let proof_of_secret_key = dealer.proof_of_secret_key.clone();
 
-    /// Deserialise this slice of bytes to a `Complaint`
-    pub fn from_bytes(bytes: &[u8; 136]) -> Result<Complaint, Error> {
-        let maker_index = u32::from_le_bytes(
-            bytes[0..4]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
-        let accused_index = u32::from_le_bytes(
-            bytes[4..8]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
-        let dh_key = bytes[8..40]
-            .try_into()
-            .map_err(|_| Error::SerialisationError)?;
-        let proof = ComplaintProof::from_bytes(
-            &bytes[40..136].try_into().map_err(|_| Error::SerialisationError)?)?;
+        let (new_dh_pk, new_proof, new_dh_sk) = dealer.rotate_dh_keypair("Φ", &mut rng);
 
-        Ok(Complaint {
-            maker_index,
-            accused_index,
-            dh_key,
-            proof,
-        })
+        assert_ne!(new_dh_sk, old_dh_sk);
+        assert_ne!(new_dh_pk, old_dh_pk);
+        assert_eq!(dealer.dh_public_key, new_dh_pk);
+        assert_eq!(dealer.proof_of_dh_private_key, new_proof);
+        assert_eq!(new_dh_pk.0, &RISTRETTO_BASEPOINT_TABLE * &new_dh_sk);
+
+        // The signing material is untouched.
+        assert_eq!(dealer.commitments, commitments);
+        assert_eq!(dealer.proof_of_secret_key, proof_of_secret_key);
+
+        assert!(new_proof.verify(&dealer.index, &dealer.dh_public_key, "Φ").is_ok());
+        assert!(old_proof.verify(&dealer.index, &dealer.dh_public_key, "Φ").is_err());
+        assert!(old_proof.verify(&dealer.index, &old_dh_pk, "Φ").is_ok());
     }
-}
 
-/// During round two each participant verifies their secret shares they received
-/// from each other participant.
-#[derive(Clone, Debug)]
-pub struct RoundTwo {}
+    #[test]
+    fn rotate_dh_keypair_with_sha3() {
+        use sha3::Sha3_512;
 
-impl DistributedKeyGeneration<RoundTwo> {
-    /// Calculate this threshold signing protocol participant's long-lived
-    /// secret signing keyshare and the group's public verification key.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// let (group_key, secret_key) = state.finish()?;
-    /// ```
-    pub fn finish(mut self) -> Result<(GroupKey, SecretKey), Error> {
-        let secret_key = self.calculate_signing_key()?;
-        let group_key = self.calculate_group_key()?;
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        self.state.my_secret_shares.zeroize();
+        let (mut dealer, _dealer_coeffs, _dh_sk) =
+            Participant::new_dealer_with_hash::<Sha3_512>(&params, 1, "Φ", &mut rng);
 
-        Ok((group_key, secret_key))
+        let (new_dh_pk, new_proof, _new_dh_sk) = dealer.rotate_dh_keypair_with_hash::<Sha3_512>("Φ", &mut rng);
+
+        assert_eq!(dealer.dh_public_key, new_dh_pk);
+        assert!(new_proof.verify_with_hash::<Sha3_512>(&dealer.index, &new_dh_pk, "Φ").is_ok());
+        assert!(new_proof.verify(&dealer.index, &new_dh_pk, "Φ").is_err());
+
+        // The rotated participant passes the checks of a Sha3 session.
+        let (p2, _p2coeffs, _p2_dh_sk) = Participant::new_dealer_with_hash::<Sha3_512>(&params, 2, "Φ", &mut rng);
+        let (p3, _p3coeffs, _p3_dh_sk) = Participant::new_dealer_with_hash::<Sha3_512>(&params, 3, "Φ", &mut rng);
+        let list = verify_participant_set_with_hash::<Sha3_512>(&[dealer, p2, p3], "Φ", &params);
+        assert_eq!(list.valid_participants.len(), 3);
+        assert!(list.misbehaving_participants.is_none());
     }
 
-    /// Calculate this threshold signing participant's long-lived secret signing
-    /// key by interpolating all of the polynomial evaluations from the other
-    /// participants.
-    pub(crate) fn calculate_signing_key(&self) -> Result<SecretKey, Error> {
-        let my_secret_shares = self.state.my_secret_shares
-            .as_ref()
-            .ok_or_else(|| Error::Custom("Could not retrieve participant's secret shares".to_string()))?;
+    #[test]
+    fn commitment_from_bytes_inflated_length() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        let mut index_vector: Vec<u32> = Vec::new();
+        let (dealer, _dealer_coeffs, _dealer_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let mut bytes = dealer.commitments.unwrap().to_bytes();
 
-        for share in my_secret_shares.iter() {
-            index_vector.push(share.sender_index);
-        }
+        bytes[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(VerifiableSecretSharingCommitment::from_bytes(&bytes), Err(Error::SerialisationError));
 
-        let mut key = Scalar::zero();
+        bytes[4..8].copy_from_slice(&3u32.to_le_bytes());
+        assert_eq!(VerifiableSecretSharingCommitment::from_bytes(&bytes), Err(Error::SerialisationError));
 
-        for share in my_secret_shares.iter() {
-            let coeff = match calculate_lagrange_coefficients(&share.sender_index, &index_vector) {
-                Ok(s) => s,
-                Err(error) => return Err(Error::Custom(error.to_string())),
-            };
-            key += share.polynomial_evaluation * coeff;
-        }
+        assert_eq!(VerifiableSecretSharingCommitment::from_bytes(&bytes[..5]), Err(Error::SerialisationError));
 
-        Ok(SecretKey { index: self.state.index, key })
+        bytes[4..8].copy_from_slice(&2u32.to_le_bytes());
+        assert!(VerifiableSecretSharingCommitment::from_bytes(&bytes).is_ok());
     }
 
-    /// Calculate the group public key used for verifying threshold signatures.
-    ///
-    /// # Returns
-    ///
-    /// A [`GroupKey`] for the set of participants.
-    ///
-    /// my_commitment is needed for now, but won't be when the distinction 
-    /// dealers/signers is implemented.
-    pub(crate) fn calculate_group_key(&self) -> Result<GroupKey, Error> {
+    #[test]
+    fn keygen_rejects_out_of_range_index() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        let mut index_vector: Vec<u32> = Vec::new();
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, _p2coeffs, _p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, u32::MAX, "Φ", &mut rng);
 
-        for commitment in self.state.their_commitments.as_ref().unwrap().iter() {
-            index_vector.push(commitment.index);
-        }
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
 
-        let mut group_key = RistrettoPoint::identity();
+        let result = DistributedKeyGeneration::<_>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng,
+        );
+        assert_eq!(result.err(), Some(Error::InvalidParticipantIndex(u32::MAX)));
 
-        // The group key is the interpolation at 0 of all index 0 of the dealers' commitments.
-        for commitment in self.state.their_commitments.as_ref().unwrap().iter() {
-            let coeff = match calculate_lagrange_coefficients(&commitment.index, &index_vector) {
-                Ok(s) => s,
-                Err(error) => return Err(Error::Custom(error.to_string())),
-            };
+        let result = DistributedKeyGeneration::<_>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng,
+        );
+        assert_eq!(result.err(), Some(Error::InvalidParticipantIndex(u32::MAX)));
+    }
 
-            group_key += coeff * commitment.public_key().unwrap();
-        }
+    #[test]
+    fn keygen_cloned_state_advances_identically() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        Ok(GroupKey(group_key))
+        let (_, _, states) = run_round_one(&params, "Φ", &mut rng);
+        let p1_my_encrypted_secret_shares = collect_shares_for(1, &all_encrypted_shares(&states));
+        let p1_state = &states[0];
+
+        let p1_state_clone = p1_state.clone();
+        assert_eq!(p1_state.state, p1_state_clone.state);
+
+        // Honest shares lead to identical round two states. The clone shares
+        // with the original the flag recording that one of them was advanced,
+        // hence both are replayed from copies.
+        let p1_round_two = replayable(p1_state)
+            .to_round_two(p1_my_encrypted_secret_shares.clone(), ControlledRng(7)).unwrap();
+        let p1_clone_round_two = replayable(&p1_state_clone)
+            .to_round_two(p1_my_encrypted_secret_shares.clone(), ControlledRng(7)).unwrap();
+        assert_eq!(p1_round_two.state, p1_clone_round_two.state);
+        assert_eq!(p1_round_two.finish().unwrap(), p1_clone_round_two.finish().unwrap());
+
+        // A bad share makes both issue the very same complaint.
+        let mut bad_shares = p1_my_encrypted_secret_shares;
+        bad_shares[1].encrypted_polynomial_evaluation[0] ^= 1;
+
+        let p1_complaints = replayable(p1_state).to_round_two(bad_shares.clone(), ControlledRng(7)).err();
+        let p1_clone_complaints = replayable(&p1_state_clone).to_round_two(bad_shares, ControlledRng(7)).err();
+        assert!(matches!(p1_complaints, Some(Error::Complaint(_))));
+        assert_eq!(p1_complaints, p1_clone_complaints);
     }
 
+    #[test]
+    fn public_participant() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-    /// Every participant can verify a complaint and determine who is the malicious
-    /// party. The relevant encrypted share is assumed to exist and publicly retrievable
-    /// by any participant.
-    pub fn blame(
-        &self,
-        encrypted_share: &EncryptedSecretShare,
-        complaint: &Complaint,
-    ) -> u32 {
-        let mut pk_maker = RistrettoPoint::identity();
-        let mut pk_accused = RistrettoPoint::identity();
-        let mut commitment_accused = VerifiableSecretSharingCommitment { index: 0, points: Vec::new() };
+        let (mut dealer, _dealer_coeffs, _dealer_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (signer, _signer_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+        dealer.metadata = Some(b"alice".to_vec());
 
-        for commitment in self.state.their_commitments.as_ref().unwrap().iter() {
-            if commitment.index == complaint.accused_index {
-                commitment_accused = commitment.clone();
-            }
-        }
+        for participant in [dealer, signer].iter() {
+            let public = participant.to_public();
 
-        if commitment_accused.points.is_empty() {
-            return complaint.maker_index;
+            // Exhaustive destructuring: adding a field to `PublicParticipant`
+            // makes this fail to compile, and forces a review of its contents.
+            let PublicParticipant {
+                index,
+                dh_public_key,
+                commitments,
+                proof_of_secret_key,
+                proof_of_dh_private_key,
+                metadata,
+            } = public.clone();
+
+            assert_eq!(index, participant.index);
+            assert_eq!(dh_public_key, participant.dh_public_key);
+            assert_eq!(commitments, participant.commitments);
+            assert_eq!(proof_of_secret_key, participant.proof_of_secret_key);
+            assert_eq!(proof_of_dh_private_key, participant.proof_of_dh_private_key);
+            assert_eq!(metadata, participant.metadata);
+
+            let bytes = public.to_bytes();
+            assert_eq!(bytes, participant.to_bytes());
+            assert_eq!(PublicParticipant::from_bytes(&bytes).unwrap(), public);
         }
+    }
 
-        for (index, pk) in self.state.their_dh_public_keys.iter() {
-            if index == &complaint.maker_index {
-                pk_maker = **pk;
-            }
+    #[test]
+    fn keygen_round_two_decryption() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            else if index == &complaint.accused_index {
-                pk_accused = **pk;
-            }
-        };
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        if pk_maker == RistrettoPoint::identity() || pk_accused == RistrettoPoint::identity() {
-            return complaint.maker_index
-        }
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        // Shares are given out of order, to check that they are matched by sender index.
+        let p1_my_encrypted_secret_shares = vec!(
+            p3_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p1_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p2_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+        );
 
-        if complaint.verify(&pk_maker, &pk_accused).is_err() {
-            return complaint.maker_index
-        }
+        // Decrypt each share with a DH key computed from scratch.
+        let expected: Vec<SecretShare> = p1_my_encrypted_secret_shares
+            .iter()
+            .map(|share| {
+                let sender = participants.iter().find(|p| p.index == share.sender_index).unwrap();
+                let dh_key = (sender.dh_public_key.0 * p1_dh_sk.0).compress().to_bytes();
+                decrypt_share(share, &dh_key).unwrap()
+            })
+            .collect();
 
-        let share = decrypt_share(encrypted_share, &complaint.dh_key);
-        if share.is_err() {
-            return complaint.accused_index
-        }
-        match share.unwrap().verify(&commitment_accused) {
-            Ok(()) => complaint.maker_index,
-            Err(_) => complaint.accused_index,
+        let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap();
+        let decrypted = p1_state.state.my_secret_shares.as_ref().unwrap();
+
+        assert_eq!(decrypted.len(), expected.len());
+        for (share, expected_share) in decrypted.iter().zip(expected.iter()) {
+            assert_eq!(share.sender_index, expected_share.sender_index);
+            assert_eq!(share.receiver_index, expected_share.receiver_index);
+            assert_eq!(share.polynomial_evaluation, expected_share.polynomial_evaluation);
         }
     }
 
-    /// Serialise this DKG to a Vec of bytes
-    pub fn to_bytes(&self) -> Vec<u8> {
-        let mut res = self.state.to_bytes();
-        res.push(2u8);
+    #[test]
+    fn keygen_derive_child() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        res
-    }
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-    /// Deserialise this slice of bytes to a `DistributedKeyGeneration::<RoundTwo>`
-    pub fn from_bytes(bytes: &[u8]) -> Result<DistributedKeyGeneration::<RoundTwo>, Error> {
-        let state = ActualState::from_bytes(bytes)?;
-        let data = if bytes[bytes.len() - 1] == 2 {
-            RoundTwo {}
-        } else {
-            return Err(Error::SerialisationError)
-        };
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let states = [p1_state, p2_state, p3_state];
+        let mut secret_keys: Vec<SecretKey> = Vec::new();
+        let mut group_key = None;
+
+        for (i, state) in states.iter().enumerate() {
+            let my_encrypted_secret_shares: Vec<EncryptedSecretShare> = states
+                .iter()
+                .map(|s| s.their_encrypted_secret_shares().unwrap()[i].clone())
+                .collect();
+            let (gk, sk) = state.clone().to_round_two(my_encrypted_secret_shares, &mut rng).unwrap().finish().unwrap();
+            group_key = Some(gk);
+            secret_keys.push(sk);
+        }
+        let group_key = group_key.unwrap();
 
-        Ok(
-            DistributedKeyGeneration::<RoundTwo> {
-                state: Box::new(state),
-                data,
+        let child_group_key = group_key.derive_child(b"m/0/1");
+        assert_ne!(child_group_key, group_key);
+        assert_eq!(child_group_key, group_key.derive_child(b"m/0/1"));
+        assert_ne!(child_group_key, group_key.derive_child(b"m/0/2"));
+
+        let child_secret_keys: Vec<SecretKey> = secret_keys
+            .iter()
+            .map(|sk| sk.derive_child(&group_key, b"m/0/1"))
+            .collect();
+
+        for sk in child_secret_keys.iter() {
+            let public = secret_keys[(sk.index - 1) as usize].to_public();
+            assert_eq!(sk.to_public(), public.derive_child(&group_key, b"m/0/1"));
+        }
+
+        // Any threshold of child shares interpolates to the child group key.
+        for quorum in [[1u32, 2], [1, 3], [2, 3]].iter() {
+            let mut child_secret = Scalar::zero();
+            for index in quorum.iter() {
+                child_secret += calculate_lagrange_coefficients(index, quorum).unwrap() *
+                    child_secret_keys[(*index - 1) as usize].key;
             }
-        )
-    }
-}
 
-/// A public verification share for a participant.
-///
-/// Any participant can recalculate the public verification share, which is the
-/// public half of a [`SecretKey`], of any other participant in the protocol.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct IndividualPublicKey {
-    /// The participant index to which this key belongs.
-    pub index: u32,
-    /// The public verification share.
-    pub share: RistrettoPoint,
-}
+            assert_eq!(GroupKey::new(&RISTRETTO_BASEPOINT_TABLE * &child_secret), child_group_key);
+        }
+    }
 
-impl IndividualPublicKey {
-    /// Any participant can compute the public verification share of any other participant.
-    ///
-    /// This is done by re-computing each [`IndividualPublicKey`] as \\(Y\_i\\) s.t.:
-    ///
-    /// \\[
-    /// Y\_i = \prod\_{j=1}^{n} \prod\_{k=0}^{t-1} \phi\_{jk}^{i^{k} \mod q}
-    /// \\]
-    ///
-    /// for each [`Participant`] index \\(i\\).
-    ///
-    /// # Inputs
-    ///
-    /// * A vector of `commitments` regarding the secret polynomial
-    ///   [`Coefficients`] that this [`IndividualPublicKey`] was generated with.
-    ///
-    /// # Returns
-    ///
-    /// A `Result` with either an empty `Ok` or `Err` value, depending on
-    /// whether or not the verification was successful.
-    pub fn verify(
-        &self,
-        commitments: &[VerifiableSecretSharingCommitment],
-    ) -> Result<(), Error>
-    {
-        let mut rhs: RistrettoPoint = RistrettoPoint::identity();
-        let term: Scalar = self.index.into();
+    #[test]
+    fn keygen_new_trusting() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        let mut index_vector: Vec<u32> = Vec::new();
-        for commitment in commitments.iter() {
-            index_vector.push(commitment.index);
-        }
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        for commitment in commitments.iter() {
-            let mut tmp: RistrettoPoint = RistrettoPoint::identity();
-            for (index, com) in commitment.points.iter().rev().enumerate() {
-                tmp += com;
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, p1_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p1_trusting_state, p1_trusting_lists) = DistributedKeyGeneration::<RoundOne>::new_trusting(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _p2_lists) = DistributedKeyGeneration::<RoundOne>::new_trusting(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _p3_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        assert_eq!(p1_lists.valid_participants, p1_trusting_lists.valid_participants);
+        assert_eq!(p1_lists.misbehaving_participants, p1_trusting_lists.misbehaving_participants);
+
+        // Only the randomised encryption of the outgoing shares differs.
+        assert_eq!(p1_state.state.their_commitments, p1_trusting_state.state.their_commitments);
+        assert_eq!(p1_state.state.their_dh_public_keys, p1_trusting_state.state.their_dh_public_keys);
+
+        let p1_my_encrypted_secret_shares = vec!(
+            p1_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p2_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p3_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+        );
 
-                if index != (commitment.points.len() - 1) {
-                    tmp *= term;
-                }
-            }
+        let (group_key, secret_key) = p1_state
+            .to_round_two(p1_my_encrypted_secret_shares.clone(), &mut rng).unwrap()
+            .finish().unwrap();
+        let (trusting_group_key, trusting_secret_key) = p1_trusting_state
+            .to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap()
+            .finish().unwrap();
+
+        assert_eq!(group_key, trusting_group_key);
+        assert_eq!(secret_key, trusting_secret_key);
+
+        // Invalid proofs go unnoticed, as documented.
+        let mut forged = participants.clone();
+        forged[1].proof_of_dh_private_key = forged[2].proof_of_dh_private_key.clone();
+        let (_, lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &forged, "Φ", &mut rng).unwrap();
+        assert_eq!(lists.misbehaving_participants, Some(vec![2]));
+        let (_, lists) = DistributedKeyGeneration::<RoundOne>::new_trusting(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &forged, "Φ", &mut rng).unwrap();
+        assert_eq!(lists.misbehaving_participants, None);
+    }
 
-            let coeff = match calculate_lagrange_coefficients(&commitment.index, &index_vector) {
-                Ok(s) => s,
-                Err(error) => return Err(Error::Custom(error.to_string())),
-            };
+    #[test]
+    fn dkg_participant_list_serialisation() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            rhs += tmp * coeff;
-        }
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, _p2coeffs, _p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (mut p3, _p3coeffs, _p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+        // Make p3 misbehave with an invalid proof of knowledge of its DH private key.
+        p3.proof_of_dh_private_key = p2.proof_of_dh_private_key.clone();
+
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (_p1_state, participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        assert_eq!(participant_lists.misbehaving_participants, Some(vec![3]));
+
+        let lists = [
+            participant_lists.clone(),
+            DKGParticipantList { valid_participants: participants, misbehaving_participants: None },
+        ];
+
+        for list in lists.iter() {
+            let bytes = list.to_bytes();
+            let deserialised = DKGParticipantList::from_bytes(&bytes).unwrap();
+
+            assert_eq!(deserialised.misbehaving_participants, list.misbehaving_participants);
+            assert_eq!(deserialised.valid_participants.len(), list.valid_participants.len());
+            for (p, q) in deserialised.valid_participants.iter().zip(list.valid_participants.iter()) {
+                assert_eq!(p.to_bytes(), q.to_bytes());
+            }
+            assert_eq!(deserialised.to_bytes(), bytes);
 
-        match self.share.compress() == rhs.compress() {
-            true => Ok(()),
-            false => Err(Error::ShareVerificationError),
+            assert!(DKGParticipantList::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+            let mut trailing = bytes.clone();
+            trailing.push(0u8);
+            assert_eq!(DKGParticipantList::from_bytes(&trailing).err(), Some(Error::SerialisationError));
         }
     }
 
-    /// Any participant can compute the public verification share of any other participant.
-    ///
-    /// This is done by re-computing each [`IndividualPublicKey`] as \\(Y\_i\\) s.t.:
-    ///
-    /// \\[
-    /// Y\_i = \prod\_{j=1}^{n} \prod\_{k=0}^{t-1} \phi\_{jk}^{i^{k} \mod q}
-    /// \\]
-    ///
-    /// for each [`Participant`] index \\(i\\).
-    ///
-    /// # Inputs
-    ///
-    /// * A `participant_index` and
-    /// * A vector of `commitments` regarding the secret polynomial
-    ///   [`Coefficients`] that the [`IndividualPublicKey`] will be generated from.
-    ///
-    /// # Returns
-    ///
-    /// An `IndividualPublicKey`.
-    pub fn generate_from_commitments(
-        participant_index: u32,
-        commitments: &[VerifiableSecretSharingCommitment],
-    ) -> Self
-    {
-        let mut share: RistrettoPoint = RistrettoPoint::identity();
-        let term: Scalar = participant_index.into();
+    #[test]
+    fn commitment_evaluation_multiscalar() {
+        let mut rng: OsRng = OsRng;
 
-        let mut index_vector: Vec<u32> = Vec::new();
-        for commitment in commitments.iter() {
-            index_vector.push(commitment.index);
-        }
+        for t in [1usize, 2, 5, 256].iter() {
+            let commitments: Vec<VerifiableSecretSharingCommitment> = (1..4)
+                .map(|index| VerifiableSecretSharingCommitment {
+                    index,
+                    points: (0..*t).map(|_| RistrettoPoint::random(&mut rng)).collect(),
+                })
+                .collect();
 
-        for commitment in commitments.iter() {
-            let mut tmp: RistrettoPoint = RistrettoPoint::identity();
-            for (index, com) in commitment.points.iter().rev().enumerate() {
-                tmp += com;
+            for x in [Scalar::one(), Scalar::from(7u32), Scalar::random(&mut rng)].iter() {
+                assert_eq!(commitments[0].evaluate_hiding(x), commitments[0].evaluate_hiding_vartime(x));
+            }
 
-                if index != (commitment.points.len() - 1) {
-                    tmp *= term;
-                }
+            // Compare the interpolated public shares with a plain Horner evaluation.
+            let indices: Vec<u32> = commitments.iter().map(|c| c.index).collect();
+            let mut expected = RistrettoPoint::identity();
+            for commitment in commitments.iter() {
+                expected += commitment.evaluate_hiding(&Scalar::from(5u32)) *
+                    calculate_lagrange_coefficients(&commitment.index, &indices).unwrap();
             }
 
-            let coeff = calculate_lagrange_coefficients(&commitment.index, &index_vector).unwrap();
-            share += tmp * coeff;
+            let public_key = IndividualPublicKey::generate_from_commitments(5, &commitments).unwrap();
+            assert_eq!(public_key.share, expected);
+            assert!(public_key.verify(&commitments).is_ok());
         }
+    }
 
-        IndividualPublicKey {
-            index: participant_index,
-            share,
-        }
+    #[test]
+    fn keygen_detect_commitment_reuse() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        // The same dealer takes part in two sessions.
+        let (first, _first_coeffs, _first_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (second, _second_coeffs, _second_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+
+        let first_commitment = first.commitments.unwrap();
+        let second_commitment = second.commitments.unwrap();
+
+        assert!(first_commitment.commitments_equal(&first_commitment.clone()));
+        assert!(!first_commitment.commitments_equal(&second_commitment));
+
+        // A reused polynomial is flagged, even when announced under another index.
+        let reused = VerifiableSecretSharingCommitment { index: 2, points: first_commitment.points.clone() };
+        assert!(first_commitment.commitments_equal(&reused));
+
+        // But sharing only the constant term, as in a resharing, is not reuse.
+        let mut reshared = second_commitment.clone();
+        reshared.points[0] = first_commitment.points[0];
+        assert!(!first_commitment.commitments_equal(&reshared));
+
+        let mut truncated = first_commitment.clone();
+        truncated.points.pop();
+        assert!(!first_commitment.commitments_equal(&truncated));
     }
 
-    /// Serialise this individual public key to an array of bytes.
-    pub fn to_bytes(&self) -> [u8; 36] {
-        let mut res = [0u8; 36];
-        res[0..4].copy_from_slice(&self.index.to_le_bytes());
-        res[4..36].copy_from_slice(&self.share.compress().to_bytes());
+    #[test]
+    fn participant_ordering() {
+        #[cfg(feature = "std")]
+        use std::collections::BTreeSet;
+        #[cfg(feature = "alloc")]
+        use alloc::collections::BTreeSet;
 
-        res
+        let params = Parameters { n: 5, t: 3 };
+        let mut rng: OsRng = OsRng;
+
+        let mut participants: Vec<Participant> = [4u32, 1, 5, 3, 2]
+            .iter()
+            .map(|i| Participant::new_signer(&params, *i, "Φ", &mut rng).0)
+            .collect();
+
+        participants.sort();
+        let indices: Vec<u32> = participants.iter().map(|p| p.index).collect();
+        assert_eq!(indices, vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(participants[0].cmp(&participants[0].clone()), Ordering::Equal);
+        assert_eq!(participants[0].partial_cmp(&participants[1]), Some(Ordering::Less));
+
+        let set: BTreeSet<Participant> = participants.iter().rev().cloned().collect();
+        let indices: Vec<u32> = set.iter().map(|p| p.index).collect();
+        assert_eq!(indices, vec![1, 2, 3, 4, 5]);
     }
 
-    /// Deserialise this individual public key from an array of bytes.
-    pub fn from_bytes(bytes: &[u8; 36]) -> Result<IndividualPublicKey, Error> {
-        let index = u32::from_le_bytes(
-            bytes[0..4]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
+    #[test]
+    fn publicly_verifiable_share() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        let (dealer, dealer_coeffs, _dealer_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (receiver, receiver_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+        let (other, _other_dh_sk) = Participant::new_signer(&params, 3, "Φ", &mut rng);
+        let commitment = dealer.commitments.as_ref().unwrap();
+
+        let share = PubliclyVerifiableShare::new(1, 2, &dealer_coeffs, &receiver.dh_public_key, &mut rng);
+        assert!(share.verify(commitment, &receiver.dh_public_key).is_ok());
+
+        // The recipient recovers the actual share.
+        let table = PvssDecryptionTable::new();
+        let decrypted = share.decrypt(&receiver_dh_sk, &table).unwrap();
+        let expected = SecretShare::evaluate_polynomial(&1, &2, &dealer_coeffs);
+        assert_eq!(decrypted.polynomial_evaluation, expected.polynomial_evaluation);
+        assert!(decrypted.verify(commitment).is_ok());
+
+        let deserialised = PubliclyVerifiableShare::from_bytes(&share.to_bytes()).unwrap();
+        assert_eq!(deserialised, share);
+
+        // Anyone can notice a tampered ciphertext.
+        let mut tampered = share.clone();
+        tampered.chunks[3].1 += &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
+        assert_eq!(tampered.verify(commitment, &receiver.dh_public_key), Err(Error::ShareVerificationError));
+
+        // Or a share encrypted to the wrong recipient, or for another index.
+        assert!(share.verify(commitment, &other.dh_public_key).is_err());
+        let mut misattributed = share.clone();
+        misattributed.receiver_index = 3;
+        assert!(misattributed.verify(commitment, &receiver.dh_public_key).is_err());
+
+        // Or a share inconsistent with the dealer's commitment.
+        let (_, other_coeffs, _) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let forged = PubliclyVerifiableShare::new(1, 2, &other_coeffs, &receiver.dh_public_key, &mut rng);
+        assert!(forged.verify(commitment, &receiver.dh_public_key).is_err());
+
+        // There is nothing to complain about an honest share.
+        assert_eq!(
+            PvssComplaint::new(&share, &receiver_dh_sk, &table, &mut rng),
+            Err(Error::ComplaintVerificationError)
         );
+    }
 
-        let share = CompressedRistretto(
-            bytes[4..36]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?
-        ).decompress().ok_or(Error::SerialisationError)?;
+    #[test]
+    fn publicly_verifiable_share_with_out_of_range_chunk() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        Ok(IndividualPublicKey { index, share })
+        let (dealer, dealer_coeffs, _dealer_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (receiver, receiver_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+        let commitment = dealer.commitments.as_ref().unwrap();
+        let table = PvssDecryptionTable::new();
+
+        // The dealer moves 256 from the second chunk to the first one, which
+        // leaves the combined ciphertext, and thus the proof, unchanged.
+        let mut share = PubliclyVerifiableShare::new(1, 2, &dealer_coeffs, &receiver.dh_public_key, &mut rng);
+        share.chunks[0].1 += &RISTRETTO_BASEPOINT_TABLE * &Scalar::from(256u16);
+        share.chunks[1].1 -= &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
+
+        assert!(share.verify(commitment, &receiver.dh_public_key).is_ok());
+        assert_eq!(share.decrypt(&receiver_dh_sk, &table), Err(Error::DecryptionError));
+
+        // The recipient proves which chunk cannot be decrypted, and anyone
+        // blames the dealer.
+        let complaint = PvssComplaint::new(&share, &receiver_dh_sk, &table, &mut rng).unwrap();
+        assert_eq!(complaint.chunk_index, 0);
+        assert_eq!(complaint.blame(&share, &receiver.dh_public_key, &table), 1);
+
+        let deserialised = PvssComplaint::from_bytes(&complaint.to_bytes()).unwrap();
+        assert_eq!(deserialised, complaint);
+
+        // A complaint about an in range chunk, or with a forged decryption,
+        // turns against its maker.
+        let mut wrong_chunk = complaint.clone();
+        wrong_chunk.chunk_index = 5;
+        assert_eq!(wrong_chunk.blame(&share, &receiver.dh_public_key, &table), 2);
+
+        let mut forged = complaint.clone();
+        forged.decrypted_chunk += &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
+        assert_eq!(forged.blame(&share, &receiver.dh_public_key, &table), 2);
     }
-}
 
-/// A secret key, used by one participant in a threshold signature scheme, to sign a message.
-#[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
-#[zeroize(drop)]
-pub struct SecretKey {
-    /// The participant index to which this key belongs.
-    pub(crate) index: u32,
-    /// The participant's long-lived secret share of the group signing key.
-    pub(crate) key: Scalar,
-}
+    #[test]
+    fn keygen_reshare_with_commitment() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-impl SecretKey {
-    /// Derive the corresponding public key for this secret key.
-    pub fn to_public(&self) -> IndividualPublicKey {
-        let share = &RISTRETTO_BASEPOINT_TABLE * &self.key;
+        let (signer1, _signer1_dh_sk) = Participant::new_signer(&params, 1, "Φ", &mut rng);
+        let (signer2, _signer2_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+        let (signer3, _signer3_dh_sk) = Participant::new_signer(&params, 3, "Φ", &mut rng);
+        let signers: Vec<Participant> = vec!(signer1, signer2, signer3);
 
-        IndividualPublicKey {
-            index: self.index,
-            share,
-        }
+        let secret_key = SecretKey { index: 1, key: Scalar::random(&mut rng) };
+        let (dealer, commitment, encrypted_shares, participant_lists) =
+            Participant::reshare_with_commitment(&params, secret_key.clone(), &signers, "Φ", &mut rng).unwrap();
+
+        assert_eq!(Some(&commitment), dealer.commitments.as_ref());
+        assert_eq!(commitment.index, 1);
+        assert_eq!(commitment.public_key(), Some(&secret_key.to_public().share));
+        assert_eq!(encrypted_shares.len(), 3);
+        assert!(participant_lists.misbehaving_participants.is_none());
     }
 
-    /// Serialise this secret key to an array of bytes.
-    pub fn to_bytes(&self) -> [u8; 36] {
-        let mut res = [0u8; 36];
-        res[0..4].copy_from_slice(&self.index.to_le_bytes());
-        res[4..36].copy_from_slice(&self.key.to_bytes());
+    #[test]
+    fn keygen_state_with_inconsistent_parameters() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        res
-    }
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, _p2coeffs, _p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, _p3coeffs, _p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-    /// Deserialise this secret key from an array of bytes.
-    pub fn from_bytes(bytes: &[u8; 36]) -> Result<SecretKey, Error> {
-        let index = u32::from_le_bytes(
-            bytes[0..4]
-                .try_into()
-                .map_err(|_| Error::SerialisationError)?,
-        );
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
 
-        let key = Scalar::from_canonical_bytes(bytes[4..36]
-            .try_into()
-            .map_err(|_| Error::SerialisationError)?
-        ).ok_or(Error::SerialisationError)?;
+        let mut bytes = p1_state.to_bytes();
+        assert!(DistributedKeyGeneration::<RoundOne>::from_bytes(&bytes).is_ok());
 
-        Ok(SecretKey { index, key })
+        // The parameters come first, as n then t: corrupt t to be greater than n.
+        bytes[4..8].copy_from_slice(&4u32.to_le_bytes());
+        assert_eq!(
+            DistributedKeyGeneration::<RoundOne>::from_bytes(&bytes).err(),
+            Some(Error::SerialisationError)
+        );
     }
-}
 
-impl From<&SecretKey> for IndividualPublicKey {
-    fn from(source: &SecretKey) -> IndividualPublicKey {
-        source.to_public()
-    }
-}
+    #[test]
+    fn secret_key_from_group_secret() {
+        let mut rng: OsRng = OsRng;
 
-/// A public key, used to verify a signature made by a threshold of a group of participants.
-#[derive(Clone, Copy, Debug, Eq)]
-pub struct GroupKey(pub(crate) RistrettoPoint);
+        let group_secret = Scalar::random(&mut rng);
+        let coefficients = Coefficients(vec![Scalar::random(&mut rng), Scalar::random(&mut rng)]);
 
-impl PartialEq for GroupKey {
-    fn eq(&self, other: &Self) -> bool {
-        self.0.compress() == other.0.compress()
+        let secret_keys: Vec<SecretKey> = (1..6)
+            .map(|index| SecretKey::from_group_secret(&group_secret, index, &coefficients))
+            .collect();
+
+        // Any 3 out of 5 shares reconstruct the group secret.
+        for quorum in [[1u32, 2, 3], [1, 3, 5], [2, 4, 5]].iter() {
+            let mut secret = Scalar::zero();
+            for index in quorum.iter() {
+                let secret_key = &secret_keys[(*index - 1) as usize];
+                assert_eq!(secret_key.index, *index);
+                secret += calculate_lagrange_coefficients(index, quorum).unwrap() * secret_key.key;
+            }
+            assert_eq!(secret, group_secret);
+        }
+
+        // But 2 of them do not.
+        let mut secret = Scalar::zero();
+        for index in [1u32, 2].iter() {
+            secret += calculate_lagrange_coefficients(index, &[1, 2]).unwrap() * secret_keys[(*index - 1) as usize].key;
+        }
+        assert_ne!(secret, group_secret);
     }
-}
 
-impl GroupKey {
-    /// Serialise this group public key to an array of bytes.
-    pub fn to_bytes(&self) -> [u8; 32] {
-        self.0.compress().to_bytes()
+    #[test]
+    fn keygen_verify_key_set() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let states = [p1_state, p2_state, p3_state];
+        let mut individual_keys: Vec<IndividualPublicKey> = Vec::new();
+        let mut group_key = None;
+
+        for (i, state) in states.iter().enumerate() {
+            let my_encrypted_secret_shares: Vec<EncryptedSecretShare> = states
+                .iter()
+                .map(|s| s.their_encrypted_secret_shares().unwrap()[i].clone())
+                .collect();
+            let (gk, sk) = state.clone().to_round_two(my_encrypted_secret_shares, &mut rng).unwrap().finish().unwrap();
+            group_key = Some(gk);
+            individual_keys.push(sk.to_public());
+        }
+        let group_key = group_key.unwrap();
+
+        let commitments: Vec<VerifiableSecretSharingCommitment> = participants
+            .iter()
+            .map(|p| p.commitments.clone().unwrap())
+            .collect();
+
+        assert!(verify_key_set(&individual_keys, &commitments, &group_key, &params).is_ok());
+        assert!(verify_key_set(&individual_keys[1..], &commitments, &group_key, &params).is_ok());
+        assert!(verify_key_set(&individual_keys[2..], &commitments, &group_key, &params).is_err());
+
+        let wrong_group_key = GroupKey::new(RistrettoPoint::random(&mut rng));
+        assert_eq!(
+            verify_key_set(&individual_keys, &commitments, &wrong_group_key, &params),
+            Err(Error::InvalidGroupKey)
+        );
+
+        let mut swapped = individual_keys.clone();
+        swapped[1].share = RistrettoPoint::random(&mut rng);
+        assert_eq!(
+            verify_key_set(&swapped, &commitments, &group_key, &params),
+            Err(Error::ShareVerificationError)
+        );
     }
 
-    /// Deserialise this group public key from an array of bytes.
-    pub fn from_bytes(bytes: &[u8; 32]) -> Result<GroupKey, Error> {
-        let point = CompressedRistretto(*bytes).decompress().ok_or(Error::SerialisationError)?;
+    #[test]
+    fn keygen_estimated_round_one_bytes() {
+        let params = Parameters { n: 64, t: 33 };
+        let mut rng: OsRng = OsRng;
+
+        let mut participants: Vec<Participant> = Vec::new();
+        let mut p1_coeffs_dh_sk = None;
+        for i in 1..65 {
+            let (p, coeffs, dh_sk) = Participant::new_dealer(&params, i, "Φ", &mut rng);
+            if i == 1 {
+                p1_coeffs_dh_sk = Some((coeffs, dh_sk));
+            }
+            participants.push(p);
+        }
+        let (p1_coeffs, p1_dh_sk) = p1_coeffs_dh_sk.unwrap();
+
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &1, &p1_coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        // Measure the round-one messages actually produced, as they are sent
+        // over the wire: the broadcast participants, with their commitments,
+        // and the encrypted shares.
+        let wire_bytes: usize =
+            participants.iter().map(|p| p.to_bytes().len()).sum::<usize>() +
+            p1_state.their_encrypted_secret_shares().unwrap().iter().map(|s| s.to_bytes().len()).sum::<usize>();
+
+        let estimate = estimated_round_one_bytes(&params);
 
-        Ok(GroupKey(point))
+        // The estimate holds at least the received data, and at most the
+        // expansion of every point from its compressed encoding.
+        let expansion = size_of::<RistrettoPoint>() / 32;
+        assert!(estimate >= wire_bytes);
+        assert!(estimate <= wire_bytes * expansion);
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use rand::Rng;
-    use rand::rngs::OsRng;
+    #[test]
+    fn kdf_error_instead_of_panic() {
+        let key = [3u8; 32];
+
+        let mut okm = [0u8; 32];
+        assert!(expand_aes_key(&key, &mut okm).is_ok());
+
+        // HKDF-SHA512 cannot expand beyond 255 * 64 bytes.
+        let mut okm = vec![0u8; 255 * 64 + 1];
+        assert_eq!(expand_aes_key(&key, &mut okm), Err(Error::KdfError));
+    }
 
     #[test]
-    fn nizk_of_secret_key() {
-        let params = Parameters { n: 3, t: 2 };
+    fn group_key_byte_conversions() {
+        fn digest(data: impl AsRef<[u8]>) -> [u8; 64] {
+            let mut out = [0u8; 64];
+            out.copy_from_slice(&Sha512::digest(data.as_ref()));
+            out
+        }
+
         let mut rng = OsRng;
+        let group_key = GroupKey::new(RistrettoPoint::random(&mut rng));
 
-        let (p, _, _) = Participant::new_dealer(&params, 0, "Φ", &mut rng);
-        let result = p.proof_of_secret_key.as_ref().unwrap().verify(&p.index, p.public_key().unwrap(), "Φ");
+        assert_eq!(group_key.as_ref(), &group_key.to_bytes()[..]);
+        assert_eq!(digest(group_key), digest(group_key.to_bytes()));
 
-        assert!(result.is_ok());
+        let bytes: [u8; 32] = group_key.into();
+        assert_eq!(GroupKey::from_bytes(&bytes).unwrap(), group_key);
+
+        let public_key = SecretKey { index: 3, key: Scalar::random(&mut rng) }.to_public();
+        let bytes: [u8; 36] = (&public_key).into();
+        assert_eq!(bytes, public_key.to_bytes());
     }
 
     #[test]
-    fn secret_share_from_one_coefficients() {
-        let mut coeffs: Vec<Scalar> = Vec::new();
+    fn keygen_with_sha3() {
+        use sha3::Sha3_512;
 
-        for _ in 0..5 {
-            coeffs.push(Scalar::one());
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer_with_hash::<Sha3_512>(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer_with_hash::<Sha3_512>(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer_with_hash::<Sha3_512>(&params, 3, "Φ", &mut rng);
+
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+
+        // Proofs made with SHA3-512 are rejected by a SHA-512 instance.
+        assert!(DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).is_err());
+
+        let (p1_state, p1_lists) = DistributedKeyGeneration::<RoundOne, Sha3_512>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _) = DistributedKeyGeneration::<RoundOne, Sha3_512>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _) = DistributedKeyGeneration::<RoundOne, Sha3_512>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+        assert!(p1_lists.misbehaving_participants.is_none());
+
+        let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().unwrap().clone();
+        let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().unwrap().clone();
+        let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().unwrap().clone();
+
+        let mut states = vec!(p1_state, p2_state, p3_state);
+        let mut group_keys = Vec::new();
+        let mut secret_keys = Vec::new();
+        for (i, state) in states.drain(..).enumerate() {
+            let my_encrypted_secret_shares = vec!(
+                p1_their_encrypted_secret_shares[i].clone(),
+                p2_their_encrypted_secret_shares[i].clone(),
+                p3_their_encrypted_secret_shares[i].clone(),
+            );
+            let (group_key, secret_key) = state
+                .to_round_two(my_encrypted_secret_shares, &mut rng).unwrap()
+                .finish().unwrap();
+            group_keys.push(group_key);
+            secret_keys.push(secret_key);
         }
 
-        let coefficients = Coefficients(coeffs);
-        let share = SecretShare::evaluate_polynomial(&1, &1, &coefficients);
+        assert_eq!(group_keys[0], group_keys[1]);
+        assert_eq!(group_keys[0], group_keys[2]);
 
-        assert!(share.polynomial_evaluation == Scalar::from(5u8));
+        let individual_keys: Vec<IndividualPublicKey> = secret_keys.iter().map(|sk| sk.to_public()).collect();
+        let commitments: Vec<VerifiableSecretSharingCommitment> = participants
+            .iter()
+            .map(|p| p.commitments.clone().unwrap())
+            .collect();
+        assert!(verify_key_set(&individual_keys, &commitments, &group_keys[0], &params).is_ok());
+    }
 
-        let mut commitments = VerifiableSecretSharingCommitment { index: 1, points: Vec::new() };
+    #[test]
+    fn keygen_finish_secret_key_only() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        for i in 0..5 {
-            commitments.points.push(&RISTRETTO_BASEPOINT_TABLE * &coefficients.0[i]);
-        }
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        assert!(share.verify(&commitments).is_ok());
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let p1_my_encrypted_secret_shares = vec!(
+            p1_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p2_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p3_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+        );
+
+        let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap();
+
+        let secret_key = p1_state.clone().finish_secret_key_only().unwrap();
+        let (_group_key, expected_secret_key) = p1_state.finish().unwrap();
+
+        assert_eq!(secret_key, expected_secret_key);
     }
 
     #[test]
-    fn secret_share_participant_index_zero() {
-        let mut coeffs: Vec<Scalar> = Vec::new();
+    fn participant_commitment_index_mismatch() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, _, _) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (mut p3, _, _) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+        assert!(Participant::from_bytes(&p3.to_bytes()).is_ok());
+
+        p3.commitments.as_mut().unwrap().index = 9;
+
+        assert_eq!(Participant::from_bytes(&p3.to_bytes()), Err(Error::SerialisationError));
+        assert!(PublicParticipant::from_bytes(&p3.to_bytes()).is_err());
+
+        let participants: Vec<Participant> = vec!(p1.clone(), p2, p3);
+        let (_, lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        assert_eq!(lists.misbehaving_participants, Some(vec![3]));
+        assert_eq!(lists.valid_participants.len(), 2);
+    }
+
+    #[test]
+    fn decrypt_share_with_cached_key() {
+        let mut rng: OsRng = OsRng;
 
-        for _ in 0..5 {
-            coeffs.push(Scalar::one());
-        }
+        let mut dh_key = [0u8; 32];
+        rng.fill(&mut dh_key);
 
-        let coefficients = Coefficients(coeffs);
-        let share = SecretShare::evaluate_polynomial(&1, &0, &coefficients);
+        let mut aes_key = [0u8; 32];
+        expand_aes_key(&dh_key, &mut aes_key).unwrap();
 
-        assert!(share.polynomial_evaluation == Scalar::one());
+        // Several shares from the same sender, as for a weighted participant.
+        for receiver_index in 1..5 {
+            let share = SecretShare {
+                sender_index: 1,
+                receiver_index,
+                polynomial_evaluation: Scalar::random(&mut rng),
+            };
+            let encrypted_share = encrypt_share(&share, &dh_key, &mut rng).unwrap();
 
-        let mut commitments = VerifiableSecretSharingCommitment { index: 1, points: Vec::new() };
+            let decrypted_share = decrypt_share(&encrypted_share, &dh_key).unwrap();
+            let cached_decrypted_share = decrypt_share_with_expanded_key(&encrypted_share, &aes_key).unwrap();
 
-        for i in 0..5 {
-            commitments.points.push(&RISTRETTO_BASEPOINT_TABLE * &coefficients.0[i]);
+            assert_eq!(decrypted_share, share);
+            assert_eq!(cached_decrypted_share, share);
         }
-
-        assert!(share.verify(&commitments).is_ok());
     }
 
     #[test]
-    fn single_party_keygen() {
-        let params = Parameters { n: 1, t: 1 };
-        let mut rng = OsRng;
+    fn secret_key_attestation() {
+        let mut rng: OsRng = OsRng;
 
-        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let secret_key = SecretKey { index: 2, key: Scalar::random(&mut rng) };
+        let public_key = secret_key.to_public();
+        let group_key = GroupKey::new(RistrettoPoint::random(&mut rng));
 
-        p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").unwrap();
+        let attestation = secret_key.attest(&group_key, b"DKG completed", &mut rng);
+        assert!(attestation.verify(&public_key, &group_key, b"DKG completed").is_ok());
+        assert_eq!(Attestation::from_bytes(&attestation.to_bytes()).unwrap(), attestation);
 
-        let participants: Vec<Participant> = vec![p1.clone()];
-        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                 &p1_dh_sk,
-                                                                 &p1.index,
-                                                                 &p1coeffs,
-                                                                 &participants,
-                                                                 "Φ",
-                                                                 &mut rng).unwrap();
-        let p1_my_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().unwrap().clone();
-        let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap();
-        let result = p1_state.finish();
+        // Wrong context, group key or participant
+        assert!(attestation.verify(&public_key, &group_key, b"other context").is_err());
+        let other_group_key = GroupKey::new(RistrettoPoint::random(&mut rng));
+        assert!(attestation.verify(&public_key, &other_group_key, b"DKG completed").is_err());
 
-        assert!(result.is_ok());
+        let wrong_secret_key = SecretKey { index: 2, key: Scalar::random(&mut rng) };
+        let wrong_attestation = wrong_secret_key.attest(&group_key, b"DKG completed", &mut rng);
+        assert_eq!(
+            wrong_attestation.verify(&public_key, &group_key, b"DKG completed"),
+            Err(Error::InvalidProofOfKnowledge)
+        );
+    }
 
-        let (p1_group_key, p1_secret_key) = result.unwrap();
+    #[test]
+    fn reshare_rerandomises_polynomial() {
+        let params = Parameters { n: 3, t: 3 };
+        let mut rng: OsRng = OsRng;
 
-        assert!(p1_group_key.0.compress() == (&p1_secret_key.key * &RISTRETTO_BASEPOINT_TABLE).compress());
+        let secret_key = SecretKey { index: 1, key: Scalar::random(&mut rng) };
+
+        let (_, coeffs_a, _) = Participant::new_internal::<Sha512>(
+            &params, false, 1, Some(secret_key.key), "Φ", &mut rng);
+        let (_, coeffs_b, _) = Participant::new_internal::<Sha512>(
+            &params, false, 1, Some(secret_key.key), "Φ", &mut rng);
+        let (coeffs_a, coeffs_b) = (coeffs_a.unwrap(), coeffs_b.unwrap());
+
+        assert_eq!(coeffs_a.0[0], secret_key.key);
+        assert_eq!(coeffs_b.0[0], secret_key.key);
+        assert!(coefficients_differ(&coeffs_a, &coeffs_b));
+        assert!(!coefficients_differ(&coeffs_a, &coeffs_a));
+
+        let mut partially_equal = Coefficients(coeffs_b.0.clone());
+        partially_equal.0[2] = coeffs_a.0[2];
+        assert!(!coefficients_differ(&coeffs_a, &partially_equal));
+
+        // The same holds on the public commitments of two actual reshares.
+        let signers: Vec<Participant> = (1..4)
+            .map(|i| Participant::new_signer(&params, i, "Φ", &mut rng).0)
+            .collect();
+        let (_, commitment_a, _, _) = Participant::reshare_with_commitment(
+            &params, secret_key.clone(), &signers, "Φ", &mut rng).unwrap();
+        let (_, commitment_b, _, _) = Participant::reshare_with_commitment(
+            &params, secret_key.clone(), &signers, "Φ", &mut rng).unwrap();
+
+        assert_eq!(commitment_a.points[0], &RISTRETTO_BASEPOINT_TABLE * &secret_key.key);
+        assert_eq!(commitment_a.points[0], commitment_b.points[0]);
+        for j in 1..3 {
+            assert_ne!(commitment_a.points[j], commitment_b.points[j]);
+        }
     }
 
     #[test]
-    fn keygen_3_out_of_5() {
-        let params = Parameters { n: 5, t: 3 };
-        let mut rng = OsRng;
-
-        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
-        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
-        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
-        let (p4, p4coeffs, p4_dh_sk) = Participant::new_dealer(&params, 4, "Φ", &mut rng);
-        let (p5, p5coeffs, p5_dh_sk) = Participant::new_dealer(&params, 5, "Φ", &mut rng);
+    fn complaint_proof_nonce() {
+        let mut rng: OsRng = OsRng;
 
-        p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").unwrap();
-        p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").unwrap();
-        p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").unwrap();
-        p4.proof_of_secret_key.as_ref().unwrap().verify(&p4.index, p4.public_key().unwrap(), "Φ").unwrap();
-        p5.proof_of_secret_key.as_ref().unwrap().verify(&p5.index, p5.public_key().unwrap(), "Φ").unwrap();
+        let dh_sk = DHPrivateKey(Scalar::random(&mut rng));
+        let dh_pk = DHPublicKey(&RISTRETTO_BASEPOINT_TABLE * &dh_sk.0);
+        let accused_dh_pk = DHPublicKey(RistrettoPoint::random(&mut rng));
+        let dh_key = (accused_dh_pk.0 * dh_sk.0).compress().to_bytes();
 
-        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone(), p4.clone(), p5.clone());
-        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                 &p1_dh_sk,
-                                                                 &p1.index,
-                                                                 &p1coeffs,
-                                                                 &participants,
-                                                                 "Φ",
-                                                                 &mut rng).unwrap();
-        let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().unwrap();
+        let complaint = |proof| Complaint { maker_index: 1, accused_index: 2, dh_key, proof };
 
-        let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                 &p2_dh_sk,
-                                                                 &p2.index,
-                                                                 &p2coeffs,
-                                                                 &participants,
-                                                                 "Φ",
-                                                                 &mut rng).unwrap();
-        let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().unwrap();
+        // The nonce r is sampled afresh for each proof and zeroed right after
+        // computing z, so only a1 = g^r and a2 = pk_l^r remain.
+        let proof_1 = ComplaintProof::prove::<Sha512>(&dh_sk, &dh_pk, &accused_dh_pk, &dh_key, &mut rng).unwrap();
+        let proof_2 = ComplaintProof::prove::<Sha512>(&dh_sk, &dh_pk, &accused_dh_pk, &dh_key, &mut rng).unwrap();
 
-        let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                  &p3_dh_sk,
-                                                                  &p3.index,
-                                                                  &p3coeffs,
-                                                                  &participants,
-                                                                  "Φ",
-                                                                  &mut rng).unwrap();
-        let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().unwrap();
+        assert_ne!(proof_1.a1, proof_2.a1);
+        assert!(complaint(proof_1).verify(&dh_pk, &accused_dh_pk).is_ok());
+        assert!(complaint(proof_2).verify(&dh_pk, &accused_dh_pk).is_ok());
+    }
 
-        let (p4_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                 &p4_dh_sk,
-                                                                 &p4.index,
-                                                                 &p4coeffs,
-                                                                 &participants,
-                                                                 "Φ",
-                                                                 &mut rng).unwrap();
-        let p4_their_encrypted_secret_shares = p4_state.their_encrypted_secret_shares().unwrap();
+    #[test]
+    fn participant_commitment_points() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        let (p5_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                 &p5_dh_sk,
-                                                                 &p5.index,
-                                                                 &p5coeffs,
-                                                                 &participants,
-                                                                 "Φ",
-                                                                 &mut rng).unwrap();
-        let p5_their_encrypted_secret_shares = p5_state.their_encrypted_secret_shares().unwrap();
+        let (dealer, coeffs, _) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (signer, _) = Participant::new_signer(&params, 2, "Φ", &mut rng);
 
-        let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                       p2_their_encrypted_secret_shares[0].clone(),
-                                       p3_their_encrypted_secret_shares[0].clone(),
-                                       p4_their_encrypted_secret_shares[0].clone(),
-                                       p5_their_encrypted_secret_shares[0].clone());
+        let points = dealer.commitment_points().unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0], &RISTRETTO_BASEPOINT_TABLE * &coeffs.0[0]);
+        assert_eq!(points[1], &RISTRETTO_BASEPOINT_TABLE * &coeffs.0[1]);
 
-        let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
-                                       p2_their_encrypted_secret_shares[1].clone(),
-                                       p3_their_encrypted_secret_shares[1].clone(),
-                                       p4_their_encrypted_secret_shares[1].clone(),
-                                       p5_their_encrypted_secret_shares[1].clone());
+        assert!(signer.commitment_points().is_none());
+    }
 
-        let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                       p2_their_encrypted_secret_shares[2].clone(),
-                                       p3_their_encrypted_secret_shares[2].clone(),
-                                       p4_their_encrypted_secret_shares[2].clone(),
-                                       p5_their_encrypted_secret_shares[2].clone());
+    #[test]
+    fn keygen_signing_key_from_empty_shares() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        let p4_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[3].clone(),
-                                       p2_their_encrypted_secret_shares[3].clone(),
-                                       p3_their_encrypted_secret_shares[3].clone(),
-                                       p4_their_encrypted_secret_shares[3].clone(),
-                                       p5_their_encrypted_secret_shares[3].clone());
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        let p5_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[4].clone(),
-                                       p2_their_encrypted_secret_shares[4].clone(),
-                                       p3_their_encrypted_secret_shares[4].clone(),
-                                       p4_their_encrypted_secret_shares[4].clone(),
-                                       p5_their_encrypted_secret_shares[4].clone());
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let p1_my_encrypted_secret_shares = vec!(
+            p1_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p2_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p3_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+        );
 
         let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap();
-        let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).unwrap();
-        let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).unwrap();
-        let p4_state = p4_state.to_round_two(p4_my_encrypted_secret_shares, &mut rng).unwrap();
-        let p5_state = p5_state.to_round_two(p5_my_encrypted_secret_shares, &mut rng).unwrap();
 
-        let (p1_group_key, p1_secret_key) = p1_state.finish().unwrap();
-        let (p2_group_key, p2_secret_key) = p2_state.finish().unwrap();
-        let (p3_group_key, p3_secret_key) = p3_state.finish().unwrap();
-        let (p4_group_key, p4_secret_key) = p4_state.finish().unwrap();
-        let (p5_group_key, p5_secret_key) = p5_state.finish().unwrap();
+        let mut empty_state = p1_state.clone();
+        empty_state.state.my_secret_shares = Some(Vec::new());
+        assert_eq!(empty_state.calculate_signing_key(), Err(Error::MissingShares));
+        assert!(empty_state.finish().is_err());
 
-        assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
-        assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
-        assert!(p3_group_key.0.compress() == p4_group_key.0.compress());
-        assert!(p4_group_key.0.compress() == p5_group_key.0.compress());
+        let mut sub_threshold_state = p1_state.clone();
+        sub_threshold_state.state.my_secret_shares.as_mut().unwrap().truncate(1);
+        assert_eq!(sub_threshold_state.calculate_signing_key(), Err(Error::MissingShares));
 
-        let mut group_secret_key = Scalar::zero();
-        let indices = [1, 2, 3, 4, 5];
+        assert!(p1_state.finish().is_ok());
+    }
 
-        group_secret_key += calculate_lagrange_coefficients(&1, &indices).unwrap()*p1_secret_key.key;
-        group_secret_key += calculate_lagrange_coefficients(&2, &indices).unwrap()*p2_secret_key.key;
-        group_secret_key += calculate_lagrange_coefficients(&3, &indices).unwrap()*p3_secret_key.key;
-        group_secret_key += calculate_lagrange_coefficients(&4, &indices).unwrap()*p4_secret_key.key;
-        group_secret_key += calculate_lagrange_coefficients(&5, &indices).unwrap()*p5_secret_key.key;
+    #[test]
+    fn commitment_validate() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-        let group_key = &group_secret_key * &RISTRETTO_BASEPOINT_TABLE;
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, _, _) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (mut p3, _, _) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-        assert!(p5_group_key.0.compress() == group_key.compress())
-    }
+        assert!(p3.commitments.as_ref().unwrap().validate(&params).is_ok());
+        assert_eq!(
+            p3.commitments.as_ref().unwrap().validate(&Parameters { n: 3, t: 3 }),
+            Err(Error::InvalidCommitment)
+        );
 
+        let empty = VerifiableSecretSharingCommitment { index: 3, points: Vec::new() };
+        assert_eq!(empty.validate(&params), Err(Error::InvalidCommitment));
 
-    #[test]
-    fn keygen_2_out_of_3() {
-        fn do_test() -> Result<(), ()> {
-            let params = Parameters { n: 3, t: 2 };
-            let mut rng = OsRng;
+        p3.commitments.as_mut().unwrap().points[0] = RistrettoPoint::identity();
+        assert_eq!(p3.commitments.as_ref().unwrap().validate(&params), Err(Error::InvalidCommitment));
 
-            let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
-            let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
-            let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+        // A dealer committing to an identity public key is misbehaving.
+        let participants: Vec<Participant> = vec!(p1.clone(), p2, p3);
+        let (_, lists) = DistributedKeyGeneration::<RoundOne>::new_trusting(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
 
-            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
-            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
-            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+        assert_eq!(lists.misbehaving_participants, Some(vec![3]));
+    }
 
-            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
-            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &p1_dh_sk,
-                                                                     &p1.index,
-                                                                     &p1coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+    #[test]
+    fn keygen_to_round_two_strict() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &p2_dh_sk,
-                                                                     &p2.index,
-                                                                     &p2coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                      &p3_dh_sk,
-                                                                      &p3.index,
-                                                                      &p3coeffs,
-                                                                      &participants,
-                                                                      "Φ",
-                                                                      &mut rng).or(Err(()))?;
-            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let all_dealers_shares = vec!(
+            p1_state.their_encrypted_secret_shares().unwrap().clone(),
+            p2_state.their_encrypted_secret_shares().unwrap().clone(),
+            p3_state.their_encrypted_secret_shares().unwrap().clone(),
+        );
+        let p1_my_encrypted_secret_shares = collect_shares_for(1, &all_dealers_shares);
+
+        // Honest shares are accepted in strict mode as well.
+        let (group_key, secret_key) = replayable(&p1_state)
+            .to_round_two(p1_my_encrypted_secret_shares.clone(), &mut rng).unwrap()
+            .finish().unwrap();
+        let (strict_group_key, strict_secret_key) = replayable(&p1_state)
+            .to_round_two_strict(p1_my_encrypted_secret_shares.clone()).unwrap()
+            .finish().unwrap();
+        assert_eq!(group_key, strict_group_key);
+        assert_eq!(secret_key, strict_secret_key);
+
+        // Participant 2 sends a wrong share to participant 1.
+        let dh_key = (p2.dh_public_key.0 * p1_dh_sk.0).compress().to_bytes();
+        let wrong_share = SecretShare { sender_index: 2, receiver_index: 1, polynomial_evaluation: Scalar::from(42u32) };
+        let mut bad_shares = p1_my_encrypted_secret_shares;
+        bad_shares[1] = encrypt_share(&wrong_share, &dh_key, &mut rng).unwrap();
+
+        match replayable(&p1_state).to_round_two(bad_shares.clone(), &mut rng) {
+            Err(Error::Complaint(complaints)) => assert_eq!(complaints[0].accused_index, 2),
+            _ => panic!("a complaint should have been issued"),
+        }
 
-            let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                           p2_their_encrypted_secret_shares[0].clone(),
-                                           p3_their_encrypted_secret_shares[0].clone());
-            let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
-                                           p2_their_encrypted_secret_shares[1].clone(),
-                                           p3_their_encrypted_secret_shares[1].clone());
-            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                           p2_their_encrypted_secret_shares[2].clone(),
-                                           p3_their_encrypted_secret_shares[2].clone());
+        match p1_state.to_round_two_strict(bad_shares) {
+            Err(error) => assert_eq!(error, Error::BadShare(2)),
+            Ok(_) => panic!("the bad share should have aborted the key generation"),
+        }
+    }
 
-            let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+    #[test]
+    fn keygen_one_complaint_per_sender() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
-            let (p2_group_key, _p2_secret_key) = p2_state.finish().or(Err(()))?;
-            let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-            assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
-            assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let all_dealers_shares = vec!(
+            p1_state.their_encrypted_secret_shares().unwrap().clone(),
+            p2_state.their_encrypted_secret_shares().unwrap().clone(),
+            p3_state.their_encrypted_secret_shares().unwrap().clone(),
+        );
+        let mut p1_my_encrypted_secret_shares = collect_shares_for(1, &all_dealers_shares);
+
+        // Participant 2 sends two different bad shares in place of its own
+        // and of participant 3's.
+        let dh_key = (p2.dh_public_key.0 * p1_dh_sk.0).compress().to_bytes();
+        for (position, value) in [(1, 42u32), (2, 43u32)] {
+            let wrong_share = SecretShare { sender_index: 2, receiver_index: 1, polynomial_evaluation: Scalar::from(value) };
+            p1_my_encrypted_secret_shares[position] = encrypt_share(&wrong_share, &dh_key, &mut rng).unwrap();
+        }
 
-            Ok(())
+        match p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng) {
+            Err(Error::Complaint(complaints)) => {
+                assert_eq!(complaints.len(), 1);
+                assert_eq!(complaints[0].accused_index, 2);
+            },
+            _ => panic!("a complaint should have been issued"),
         }
-        assert!(do_test().is_ok());
     }
 
     #[test]
-    fn keygen_static_2_out_of_3_with_common_participants() {
-        fn do_test() -> Result<(), ()> {
-            let params = Parameters { n: 3, t: 2 };
-            let mut rng = OsRng;
+    fn secret_share_matches_public_key() {
+        let mut rng: OsRng = OsRng;
 
-            let (dealer1, dealer1coeffs, dealer1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
-            let (dealer2, dealer2coeffs, dealer2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
-            let (dealer3, dealer3coeffs, dealer3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+        let secret_key = SecretKey { index: 1, key: Scalar::random(&mut rng) };
+        let public_key = secret_key.to_public();
+        let other_public_key = SecretKey { index: 1, key: Scalar::random(&mut rng) }.to_public();
+        let wrong_index = IndividualPublicKey { index: 2, share: public_key.share };
 
-            dealer1.proof_of_secret_key.as_ref().unwrap().verify(&dealer1.index, dealer1.public_key().unwrap(), "Φ").or(Err(()))?;
-            dealer2.proof_of_secret_key.as_ref().unwrap().verify(&dealer2.index, dealer2.public_key().unwrap(), "Φ").or(Err(()))?;
-            dealer3.proof_of_secret_key.as_ref().unwrap().verify(&dealer3.index, dealer3.public_key().unwrap(), "Φ").or(Err(()))?;
+        assert!(secret_key.matches_public(&public_key));
+        assert!(!secret_key.matches_public(&other_public_key));
+        assert!(!secret_key.matches_public(&wrong_index));
 
-            let dealers: Vec<Participant> = vec!(dealer1.clone(), dealer2.clone(), dealer3.clone());
-            let (dealer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &dealer1_dh_sk,
-                                                                     &dealer1.index,
-                                                                     &dealer1coeffs,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let dealer1_their_encrypted_secret_shares = dealer1_state.their_encrypted_secret_shares().or(Err(()))?;
+        let share = SecretShare { sender_index: 3, receiver_index: 1, polynomial_evaluation: secret_key.key };
 
-            let (dealer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &dealer2_dh_sk,
-                                                                     &dealer2.index,
-                                                                     &dealer2coeffs,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let dealer2_their_encrypted_secret_shares = dealer2_state.their_encrypted_secret_shares().or(Err(()))?;
+        assert!(share.matches_public_key(&public_key));
+        assert!(!share.matches_public_key(&other_public_key));
+        assert!(!share.matches_public_key(&wrong_index));
+    }
 
-            let (dealer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &dealer3_dh_sk,
-                                                                     &dealer3.index,
-                                                                     &dealer3coeffs,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let dealer3_their_encrypted_secret_shares = dealer3_state.their_encrypted_secret_shares().or(Err(()))?;
+    #[test]
+    fn compute_dh_keys_matches_inline() {
+        let params = Parameters { n: 5, t: 3 };
+        let mut rng: OsRng = OsRng;
 
-            let dealer1_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[0].clone(),
-                                                          dealer2_their_encrypted_secret_shares[0].clone(),
-                                                          dealer3_their_encrypted_secret_shares[0].clone());
-            let dealer2_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[1].clone(),
-                                                          dealer2_their_encrypted_secret_shares[1].clone(),
-                                                          dealer3_their_encrypted_secret_shares[1].clone());
-            let dealer3_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[2].clone(),
-                                                          dealer2_their_encrypted_secret_shares[2].clone(),
-                                                          dealer3_their_encrypted_secret_shares[2].clone());
+        let (_, _, dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let recipients: Vec<Participant> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0)
+            .collect();
 
-            let dealer1_state = dealer1_state.to_round_two(dealer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let dealer2_state = dealer2_state.to_round_two(dealer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let dealer3_state = dealer3_state.to_round_two(dealer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        let dh_keys = compute_dh_keys(&dh_sk, recipients.iter().map(|p| &p.dh_public_key));
 
-            let (dealer1_group_key, dealer1_secret_key) = dealer1_state.finish().or(Err(()))?;
-            let (dealer2_group_key, dealer2_secret_key) = dealer2_state.finish().or(Err(()))?;
-            let (dealer3_group_key, dealer3_secret_key) = dealer3_state.finish().or(Err(()))?;
+        assert_eq!(dh_keys.len(), recipients.len());
+        for (p, dh_key) in recipients.iter().zip(dh_keys.iter()) {
+            assert_eq!(*dh_key, (p.dh_public_key.0 * dh_sk.0).compress().to_bytes());
+        }
 
-            assert!(dealer1_group_key.0.compress() == dealer2_group_key.0.compress());
-            assert!(dealer2_group_key.0.compress() == dealer3_group_key.0.compress());
+        assert!(compute_dh_keys(&dh_sk, Vec::<&DHPublicKey>::new()).is_empty());
+    }
 
-            let (signer1, signer1_dh_sk) = Participant::new_signer(&params, 1, "Φ", &mut rng);
-            let (signer2, signer2_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
-            // Dealer 3 is also a participant of the next set of signers
-            let (signer3, signer3_dh_sk) = (dealer3.clone(), dealer3_dh_sk);
+    #[test]
+    fn keygen_round_two_group_key() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let signers: Vec<Participant> = vec!(signer1.clone(), signer2.clone(), signer3.clone());
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
 
-            let (dealer1_for_signers, dealer1_encrypted_shares_for_signers, _participant_lists) =
-                Participant::reshare(&params, dealer1_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
-            let (dealer2_for_signers, dealer2_encrypted_shares_for_signers, _participant_lists) =
-                Participant::reshare(&params, dealer2_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
-            let (dealer3_for_signers, dealer3_encrypted_shares_for_signers, _participant_lists) =
-                Participant::reshare(&params, dealer3_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let p1_my_encrypted_secret_shares = vec!(
+            p1_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p2_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p3_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+        );
 
-            let dealers: Vec<Participant> = vec!(dealer1_for_signers, dealer2_for_signers, dealer3_for_signers);
-            let (signer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params,
-                                                                     &signer1_dh_sk,
-                                                                     &signer1.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+        let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap();
 
-            let (signer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params,
-                                                                     &signer2_dh_sk,
-                                                                     &signer2.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+        let group_key = p1_state.group_key().unwrap();
+        let (expected_group_key, _) = p1_state.finish().unwrap();
 
-            let (signer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params,
-                                                                     &signer3_dh_sk,
-                                                                     &signer3.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+        assert_eq!(group_key, expected_group_key);
+    }
 
-            let signer1_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[0].clone(),
-                                                          dealer2_encrypted_shares_for_signers[0].clone(),
-                                                          dealer3_encrypted_shares_for_signers[0].clone());
-            let signer2_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[1].clone(),
-                                                          dealer2_encrypted_shares_for_signers[1].clone(),
-                                                          dealer3_encrypted_shares_for_signers[1].clone());
-            let signer3_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[2].clone(),
-                                                          dealer2_encrypted_shares_for_signers[2].clone(),
-                                                          dealer3_encrypted_shares_for_signers[2].clone());
+    #[test]
+    fn rejected_share_is_wiped() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let signer1_state = signer1_state.to_round_two(signer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let signer2_state = signer2_state.to_round_two(signer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let signer3_state = signer3_state.to_round_two(signer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        let (p1, p1coeffs, _) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let commitment = p1.commitments.as_ref().unwrap();
 
-            let (signer1_group_key, _signer1_secret_key) = signer1_state.finish().or(Err(()))?;
-            let (signer2_group_key, _signer2_secret_key) = signer2_state.finish().or(Err(()))?;
-            let (signer3_group_key, _signer3_secret_key) = signer3_state.finish().or(Err(()))?;
+        let mut share = SecretShare::evaluate_polynomial(&1, &2, &p1coeffs);
+        let expected = share.clone();
 
-            assert!(signer1_group_key.0.compress() == signer2_group_key.0.compress());
-            assert!(signer2_group_key.0.compress() == signer3_group_key.0.compress());
+        assert!(share.verify_or_wipe(commitment).is_ok());
+        assert_eq!(share, expected);
 
-            assert!(signer1_group_key.0.compress() == dealer1_group_key.0.compress());
+        share.polynomial_evaluation += Scalar::one();
 
-            Ok(())
-        }
-        assert!(do_test().is_ok());
+        assert_eq!(share.verify_or_wipe(commitment), Err(Error::ShareVerificationError));
+        assert_eq!(share.polynomial_evaluation, Scalar::zero());
     }
 
     #[test]
-    fn keygen_static_2_out_of_3_into_3_out_of_5() {
-        fn do_test() -> Result<(), ()> {
-            let params_dealers = Parameters { n: 3, t: 2 };
-            let mut rng = OsRng;
+    fn share_fragmentation() {
+        let mut rng: OsRng = OsRng;
 
-            let (dealer1, dealer1coeffs, dealer1_dh_sk) = Participant::new_dealer(&params_dealers, 1, "Φ", &mut rng);
-            let (dealer2, dealer2coeffs, dealer2_dh_sk) = Participant::new_dealer(&params_dealers, 2, "Φ", &mut rng);
-            let (dealer3, dealer3coeffs, dealer3_dh_sk) = Participant::new_dealer(&params_dealers, 3, "Φ", &mut rng);
+        let share = SecretShare { sender_index: 1, receiver_index: 2, polynomial_evaluation: Scalar::random(&mut rng) };
+        let encrypted_share = encrypt_share(&share, &[7u8; 32], &mut rng).unwrap();
 
-            dealer1.proof_of_secret_key.as_ref().unwrap().verify(&dealer1.index, dealer1.public_key().unwrap(), "Φ").or(Err(()))?;
-            dealer2.proof_of_secret_key.as_ref().unwrap().verify(&dealer2.index, dealer2.public_key().unwrap(), "Φ").or(Err(()))?;
-            dealer3.proof_of_secret_key.as_ref().unwrap().verify(&dealer3.index, dealer3.public_key().unwrap(), "Φ").or(Err(()))?;
+        let mut fragments = ShareFragment::fragment(&encrypted_share, 42, 20).unwrap();
+        assert_eq!(fragments.len(), 3);
+        assert!(fragments.iter().all(|fragment| fragment.total == 3 && fragment.data.len() <= 20));
 
-            let dealers: Vec<Participant> = vec!(dealer1.clone(), dealer2.clone(), dealer3.clone());
-            let (dealer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params_dealers,
-                                                                     &dealer1_dh_sk,
-                                                                     &dealer1.index,
-                                                                     &dealer1coeffs,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let dealer1_their_encrypted_secret_shares = dealer1_state.their_encrypted_secret_shares().or(Err(()))?;
+        fragments.swap(0, 2);
+        assert_eq!(ShareFragment::reassemble(&fragments).unwrap(), encrypted_share);
 
-            let (dealer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params_dealers,
-                                                                     &dealer2_dh_sk,
-                                                                     &dealer2.index,
-                                                                     &dealer2coeffs,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let dealer2_their_encrypted_secret_shares = dealer2_state.their_encrypted_secret_shares().or(Err(()))?;
+        let decoded: Vec<ShareFragment> = fragments.iter()
+            .map(|fragment| ShareFragment::from_bytes(&fragment.to_bytes()).unwrap())
+            .collect();
+        assert_eq!(decoded, fragments);
 
-            let (dealer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params_dealers,
-                                                                     &dealer3_dh_sk,
-                                                                     &dealer3.index,
-                                                                     &dealer3coeffs,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let dealer3_their_encrypted_secret_shares = dealer3_state.their_encrypted_secret_shares().or(Err(()))?;
+        assert!(ShareFragment::reassemble(&fragments[..2]).is_err());
 
-            let dealer1_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[0].clone(),
-                                                          dealer2_their_encrypted_secret_shares[0].clone(),
-                                                          dealer3_their_encrypted_secret_shares[0].clone());
-            let dealer2_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[1].clone(),
-                                                          dealer2_their_encrypted_secret_shares[1].clone(),
-                                                          dealer3_their_encrypted_secret_shares[1].clone());
-            let dealer3_my_encrypted_secret_shares = vec!(dealer1_their_encrypted_secret_shares[2].clone(),
-                                                          dealer2_their_encrypted_secret_shares[2].clone(),
-                                                          dealer3_their_encrypted_secret_shares[2].clone());
+        let mut duplicated = fragments.clone();
+        duplicated[1] = duplicated[0].clone();
+        assert!(ShareFragment::reassemble(&duplicated).is_err());
 
-            let dealer1_state = dealer1_state.to_round_two(dealer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let dealer2_state = dealer2_state.to_round_two(dealer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let dealer3_state = dealer3_state.to_round_two(dealer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        let mut other_session = fragments.clone();
+        other_session[1].session_id = 43;
+        assert!(ShareFragment::reassemble(&other_session).is_err());
 
-            let (dealer1_group_key, dealer1_secret_key) = dealer1_state.finish().or(Err(()))?;
-            let (dealer2_group_key, dealer2_secret_key) = dealer2_state.finish().or(Err(()))?;
-            let (dealer3_group_key, dealer3_secret_key) = dealer3_state.finish().or(Err(()))?;
+        assert!(ShareFragment::fragment(&encrypted_share, 42, 0).is_err());
+        assert!(ShareFragment::reassemble(&[]).is_err());
+    }
 
-            assert!(dealer1_group_key.0.compress() == dealer2_group_key.0.compress());
-            assert!(dealer2_group_key.0.compress() == dealer3_group_key.0.compress());
+    #[test]
+    fn individual_public_key_diagnose() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let params_signers = Parameters { n: 5, t: 3 };
-            let (signer1, signer1_dh_sk) = Participant::new_signer(&params_signers, 1, "Φ", &mut rng);
-            let (signer2, signer2_dh_sk) = Participant::new_signer(&params_signers, 2, "Φ", &mut rng);
-            let (signer3, signer3_dh_sk) = Participant::new_signer(&params_signers, 3, "Φ", &mut rng);
-            let (signer4, signer4_dh_sk) = Participant::new_signer(&params_signers, 4, "Φ", &mut rng);
-            let (signer5, signer5_dh_sk) = Participant::new_signer(&params_signers, 5, "Φ", &mut rng);
+        let commitments: Vec<VerifiableSecretSharingCommitment> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0.commitments.unwrap())
+            .collect();
 
-            let signers: Vec<Participant> = vec!(signer1.clone(), signer2.clone(), signer3.clone(), signer4.clone(), signer5.clone());
+        let public_key = IndividualPublicKey::generate_from_commitments(1, &commitments).unwrap();
+        assert!(public_key.diagnose(&commitments).is_empty());
 
-            let (dealer1_for_signers, dealer1_encrypted_shares_for_signers, _participant_lists) =
-                Participant::reshare(&params_signers, dealer1_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
-            let (dealer2_for_signers, dealer2_encrypted_shares_for_signers, _participant_lists) =
-                Participant::reshare(&params_signers, dealer2_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
-            let (dealer3_for_signers, dealer3_encrypted_shares_for_signers, _participant_lists) =
-                Participant::reshare(&params_signers, dealer3_secret_key, &signers, "Φ", &mut rng).map_err(|_| ())?;
+        // The key was derived without dealer 2, whose commitment is corrupted.
+        let honest: Vec<VerifiableSecretSharingCommitment> = vec![commitments[0].clone(), commitments[2].clone()];
+        let public_key = IndividualPublicKey::generate_from_commitments(1, &honest).unwrap();
 
-            let dealers: Vec<Participant> = vec!(dealer1_for_signers, dealer2_for_signers, dealer3_for_signers);
-            let (signer1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
-                                                                     &signer1_dh_sk,
-                                                                     &signer1.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+        let mut corrupted = commitments.clone();
+        corrupted[1].points[1] += &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
 
-            let (signer2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
-                                                                     &signer2_dh_sk,
-                                                                     &signer2.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+        assert!(public_key.verify(&corrupted).is_err());
+        assert_eq!(public_key.diagnose(&corrupted), vec![2]);
 
-            let (signer3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
-                                                                     &signer3_dh_sk,
-                                                                     &signer3.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+        // A key unrelated to the commitments cannot be attributed to any dealer.
+        let unrelated = IndividualPublicKey { index: 1, share: &RISTRETTO_BASEPOINT_TABLE * &Scalar::one() };
+        assert!(unrelated.diagnose(&corrupted).is_empty());
+    }
 
-            let (signer4_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
-                                                                     &signer4_dh_sk,
-                                                                     &signer4.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+    #[test]
+    fn keygen_state_moves_across_threads() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let (signer5_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new(&params_dealers,
-                                                                     &signer5_dh_sk,
-                                                                     &signer5.index,
-                                                                     &dealers,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
+        let (_, _, mut states) = run_round_one(&params, "Φ", &mut rng);
+        let p1_my_encrypted_secret_shares = collect_shares_for(1, &all_encrypted_shares(&states));
+        let p1_state = states.remove(0);
 
-            let signer1_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[0].clone(),
-                                                          dealer2_encrypted_shares_for_signers[0].clone(),
-                                                          dealer3_encrypted_shares_for_signers[0].clone());
-            let signer2_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[1].clone(),
-                                                          dealer2_encrypted_shares_for_signers[1].clone(),
-                                                          dealer3_encrypted_shares_for_signers[1].clone());
-            let signer3_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[2].clone(),
-                                                          dealer2_encrypted_shares_for_signers[2].clone(),
-                                                          dealer3_encrypted_shares_for_signers[2].clone());
-            let signer4_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[3].clone(),
-                                                          dealer2_encrypted_shares_for_signers[3].clone(),
-                                                          dealer3_encrypted_shares_for_signers[3].clone());
-            let signer5_my_encrypted_secret_shares = vec!(dealer1_encrypted_shares_for_signers[4].clone(),
-                                                          dealer2_encrypted_shares_for_signers[4].clone(),
-                                                          dealer3_encrypted_shares_for_signers[4].clone());
+        let handle = std::thread::spawn(move || {
+            p1_state.to_round_two(p1_my_encrypted_secret_shares, OsRng).unwrap()
+        });
+        let p1_state = handle.join().unwrap();
 
-            let signer1_state = signer1_state.to_round_two(signer1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let signer2_state = signer2_state.to_round_two(signer2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let signer3_state = signer3_state.to_round_two(signer3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let signer4_state = signer4_state.to_round_two(signer4_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let signer5_state = signer5_state.to_round_two(signer5_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        assert!(p1_state.finish().is_ok());
+    }
 
-            let (signer1_group_key, _signer1_secret_key) = signer1_state.finish().or(Err(()))?;
-            let (signer2_group_key, _signer2_secret_key) = signer2_state.finish().or(Err(()))?;
-            let (signer3_group_key, _signer3_secret_key) = signer3_state.finish().or(Err(()))?;
-            let (signer4_group_key, _signer4_secret_key) = signer4_state.finish().or(Err(()))?;
-            let (signer5_group_key, _signer5_secret_key) = signer5_state.finish().or(Err(()))?;
+    #[test]
+    fn keygen_round_two_report() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            assert!(signer1_group_key.0.compress() == signer2_group_key.0.compress());
-            assert!(signer2_group_key.0.compress() == signer3_group_key.0.compress());
-            assert!(signer3_group_key.0.compress() == signer4_group_key.0.compress());
-            assert!(signer4_group_key.0.compress() == signer5_group_key.0.compress());
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
+        let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+
+        let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p2_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p2_dh_sk, &p2.index, &p2coeffs, &participants, "Φ", &mut rng).unwrap();
+        let (p3_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p3_dh_sk, &p3.index, &p3coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let mut p1_my_encrypted_secret_shares = vec!(
+            p1_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p2_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+            p3_state.their_encrypted_secret_shares().unwrap()[0].clone(),
+        );
+
+        let (result, report) = replayable(&p1_state).to_round_two_with_report(p1_my_encrypted_secret_shares.clone(), &mut rng);
+        assert!(result.is_ok());
+        assert_eq!(report, RoundTwoReport { received: 3, decrypted: 3, verified: 3, complaints: 0 });
+
+        // Participant 3 sends a share which decrypts, but does not verify.
+        let dh_key = (p3.dh_public_key.0 * p1_dh_sk.0).compress().to_bytes();
+        let wrong_share = SecretShare { sender_index: 3, receiver_index: 1, polynomial_evaluation: Scalar::from(42u32) };
+        p1_my_encrypted_secret_shares[2] = encrypt_share(&wrong_share, &dh_key, &mut rng).unwrap();
+
+        let (result, report) = p1_state.to_round_two_with_report(p1_my_encrypted_secret_shares, &mut rng);
+        assert!(matches!(result, Err(Error::Complaint(_))));
+        assert_eq!(report, RoundTwoReport { received: 3, decrypted: 3, verified: 2, complaints: 1 });
+    }
+
+    #[test]
+    fn verify_participant_set_matches_new_initial() {
+        let params = Parameters { n: 4, t: 2 };
+        let mut rng: OsRng = OsRng;
+
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let (p2, _, _) = Participant::new_dealer(&params, 2, "Ψ", &mut rng);
+        let (p3, _, _) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+        let (mut p4, _, _) = Participant::new_dealer(&params, 4, "Φ", &mut rng);
+        p4.proof_of_secret_key = p3.proof_of_secret_key.clone();
+
+        let participants: Vec<Participant> = vec!(p1.clone(), p2, p3, p4);
 
-            assert!(signer1_group_key.0.compress() == dealer1_group_key.0.compress());
+        let expected = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap().1;
+        let list = verify_participant_set(&participants, "Φ", &params);
 
-            Ok(())
-        }
-        assert!(do_test().is_ok());
+        assert_eq!(list.misbehaving_participants, Some(vec![2, 4]));
+        assert_eq!(list.misbehaving_participants, expected.misbehaving_participants);
+        assert_eq!(list.valid_participants, expected.valid_participants);
+
+        let (p5, _, _) = Participant::new_dealer(&Parameters { n: 5, t: 2 }, 5, "Φ", &mut rng);
+        let list = verify_participant_set(&[p1, p5], "Φ", &params);
+
+        assert_eq!(list.valid_participants.len(), 1);
+        assert_eq!(list.misbehaving_participants, Some(vec![5]));
     }
 
     #[test]
-    fn encrypt_and_decrypt() {
+    fn keygen_dh_public_keys_sorted() {
+        let params = Parameters { n: 4, t: 2 };
         let mut rng: OsRng = OsRng;
 
-        let original_share = SecretShare { sender_index: 1,
-                                           receiver_index: 2,
-                                           polynomial_evaluation: Scalar::random(&mut rng)};
+        let dealers = new_dealers(&params, "Φ", &mut rng);
+        let participants: Vec<Participant> = vec!(
+            dealers[2].0.clone(),
+            dealers[0].0.clone(),
+            dealers[3].0.clone(),
+            dealers[1].0.clone(),
+        );
 
-        let mut key = [0u8; 32];
-        rng.fill(&mut key);
+        let (p1, p1coeffs, p1_dh_sk) = &dealers[0];
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, p1_dh_sk, &p1.index, p1coeffs, &participants, "Φ", &mut rng).unwrap();
 
-        let encrypted_share = encrypt_share(&original_share, &key, &mut rng);
-        let decrypted_share = decrypt_share(&encrypted_share, &key);
+        let indices: Vec<u32> = p1_state.state.their_dh_public_keys.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![1, 2, 3, 4]);
 
-        assert!(decrypted_share.is_ok());
-        assert!(original_share.polynomial_evaluation == decrypted_share.unwrap().polynomial_evaluation);
+        let deserialised = DistributedKeyGeneration::<RoundOne>::from_bytes(&p1_state.to_bytes()).unwrap();
+        assert_eq!(deserialised.state.their_dh_public_keys, p1_state.state.their_dh_public_keys);
+        assert_eq!(deserialised.to_bytes(), p1_state.to_bytes());
+
+        assert_eq!(p1_state.state.their_dh_public_key(3), Some(&dealers[2].0.dh_public_key));
+        assert_eq!(p1_state.state.their_dh_public_key(5), None);
     }
 
     #[test]
-    fn keygen_2_out_of_3_with_random_keys() {
-        fn do_test() -> Result<(), ()> {
-            let params = Parameters { n: 3, t: 2 };
-            let mut rng: OsRng = OsRng;
+    fn keygen_controlled_randomness() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
-            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
-            let (p3, p3coeffs, dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+        let dealers = new_dealers(&params, "Φ", &mut rng);
+        let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+        let (p1, _, p1_dh_sk) = &dealers[0];
+        let (p2, p2coeffs, p2_dh_sk) = &dealers[1];
+
+        let mut states = new_initial_states(&params, &dealers, "Φ", &mut rng);
+        let mut p1_my_encrypted_secret_shares = collect_shares_for(1, &all_encrypted_shares(&states));
+        let p1_state = states.remove(0);
+
+        // Honest shares do not require any randomness to be processed.
+        assert!(replayable(&p1_state).to_round_two(p1_my_encrypted_secret_shares.clone(), FailingRng).is_ok());
+
+        // Participant 2 sends a share which decrypts to a non-canonical scalar:
+        // since AES-CTR is malleable, flipping the ciphertext bits turns the
+        // plaintext into all ones.
+        let dh_key = (p2.dh_public_key.0 * p1_dh_sk.0).compress().to_bytes();
+        let honest_share = decrypt_share(&p1_my_encrypted_secret_shares[1], &dh_key).unwrap();
+        let plaintext = honest_share.polynomial_evaluation.to_bytes();
+        for (byte, plain) in p1_my_encrypted_secret_shares[1].encrypted_polynomial_evaluation.iter_mut().zip(plaintext.iter()) {
+            *byte ^= plain ^ 0xff;
+        }
+        assert_eq!(decrypt_share(&p1_my_encrypted_secret_shares[1], &dh_key), Err(Error::DecryptionError));
 
-            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
-            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
-            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+        // The complaint built with replayed randomness is fully reproducible.
+        let complain = |state: DistributedKeyGeneration<RoundOne>, rng| {
+            match state.to_round_two(p1_my_encrypted_secret_shares.clone(), rng) {
+                Err(Error::Complaint(complaints)) => complaints,
+                _ => panic!("a complaint should have been issued"),
+            }
+        };
+        let complaints = complain(replayable(&p1_state), ControlledRng(7));
+        assert_eq!(complaints, complain(replayable(&p1_state), ControlledRng(7)));
+        assert_ne!(complaints[0].proof, complain(replayable(&p1_state), ControlledRng(8))[0].proof);
+
+        assert_eq!(complaints.len(), 1);
+        assert_eq!(complaints[0].accused_index, 2);
+        assert!(complaints[0].verify(&p1.dh_public_key.0, &p2.dh_public_key.0).is_ok());
+
+        // Issuing a complaint consumes randomness, and a failing RNG is
+        // reported instead of a complaint.
+        let result = p1_state.to_round_two(p1_my_encrypted_secret_shares.clone(), FailingRng);
+        assert_eq!(result.err(), Some(Error::RandomnessError));
+
+        // Encrypting shares draws their nonces, and fails alike.
+        let result = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, p2_dh_sk, &p2.index, p2coeffs, &participants, "Φ", FailingRng);
+        assert_eq!(result.err(), Some(Error::RandomnessError));
+    }
 
-            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
-            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &dh_sk1,
-                                                                     &p1.index,
-                                                                     &p1coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+    #[test]
+    fn keygen_cloned_state_advanced_twice() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &dh_sk2,
-                                                                     &p2.index,
-                                                                     &p2coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+        let (participants, dh_private_keys, mut states) = run_round_one(&params, "Φ", &mut rng);
+        let shares = collect_shares_for(1, &all_encrypted_shares(&states));
+        let p1_state = states.remove(0);
+
+        let dh_key = (participants[1].dh_public_key.0 * dh_private_keys[0].0).compress().to_bytes();
+        let wrong_share = SecretShare { sender_index: 2, receiver_index: 1, polynomial_evaluation: Scalar::from(42u32) };
+        let mut bad_shares = shares.clone();
+        bad_shares[1] = encrypt_share(&wrong_share, &dh_key, &mut rng).unwrap();
+
+        // A successfully advanced state prevents its clones from advancing.
+        let copy = replayable(&p1_state);
+        let copy_clone = copy.clone();
+        assert!(copy.to_round_two(shares.clone(), &mut rng).is_ok());
+        assert_eq!(copy_clone.to_round_two(shares.clone(), &mut rng).err(), Some(Error::StateAlreadyAdvanced));
+
+        // So does a state which issued complaints, whichever way the clones
+        // are then advanced.
+        let p1_clone = p1_state.clone();
+        assert!(matches!(p1_clone.to_round_two(bad_shares, &mut rng), Err(Error::Complaint(_))));
+        assert_eq!(p1_state.clone().to_round_two_strict(shares.clone()).err(), Some(Error::StateAlreadyAdvanced));
+        assert_eq!(p1_state.to_round_two(shares, &mut rng).err(), Some(Error::StateAlreadyAdvanced));
+    }
 
-            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                      &dh_sk3,
-                                                                      &p3.index,
-                                                                      &p3coeffs,
-                                                                      &participants,
-                                                                      "Φ",
-                                                                      &mut rng).or(Err(()))?;
-            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+    #[test]
+    fn keygen_group_key_continuity() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                           p2_their_encrypted_secret_shares[0].clone(),
-                                           p3_their_encrypted_secret_shares[0].clone());
-            let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
-                                           p2_their_encrypted_secret_shares[1].clone(),
-                                           p3_their_encrypted_secret_shares[1].clone());
-            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                           p2_their_encrypted_secret_shares[2].clone(),
-                                           p3_their_encrypted_secret_shares[2].clone());
+        let (_, states) = run_dkg(&params, "Φ", &mut rng);
+        let outputs: Vec<(GroupKey, SecretKey)> = states.into_iter().map(|state| state.finish().unwrap()).collect();
+        let group_key = outputs[0].0;
 
-            let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        let signers: Vec<Participant> = (1..=params.n)
+            .map(|i| Participant::new_signer(&params, i, "Φ", &mut rng).0)
+            .collect();
 
-            let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
-            let (p2_group_key, _p2_secret_key) = p2_state.finish().or(Err(()))?;
-            let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+        let (dealer1, _, _) = Participant::reshare(&params, outputs[0].1.clone(), &signers, "Φ", &mut rng).unwrap();
+        let (dealer2, _, _) = Participant::reshare(&params, outputs[1].1.clone(), &signers, "Φ", &mut rng).unwrap();
 
-            assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
-            assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+        let commitments = vec![dealer1.commitments.clone().unwrap(), dealer2.commitments.clone().unwrap()];
+        assert!(verify_group_key_continuity(&group_key, &commitments).is_ok());
 
-            Ok(())
+        // A dealer resharing a random secret is caught before any signer finishes.
+        let random_secret_key = SecretKey { index: 2, key: Scalar::random(&mut rng) };
+        let (cheating_dealer2, _, _) = Participant::reshare(&params, random_secret_key, &signers, "Φ", &mut rng).unwrap();
+
+        let sabotaged = vec![dealer1.commitments.unwrap(), cheating_dealer2.commitments.unwrap()];
+        assert_eq!(verify_group_key_continuity(&group_key, &sabotaged), Err(Error::InvalidGroupKey));
+    }
+
+    #[test]
+    fn keygen_share_replayed_across_sessions() {
+        let mut rng: OsRng = OsRng;
+
+        // A 2-out-of-3 session, from which a share of participant 2 is replayed.
+        let old_params = Parameters { n: 3, t: 2 };
+        let old_dealers: Vec<_> = (1..=old_params.n)
+            .map(|i| Participant::new_dealer(&old_params, i, "Φ", &mut rng))
+            .collect();
+        let old_participants: Vec<Participant> = old_dealers.iter().map(|(p, _, _)| p.clone()).collect();
+        let (old_p2, old_p2coeffs, old_p2_dh_sk) = &old_dealers[1];
+        let (old_p2_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &old_params, old_p2_dh_sk, &old_p2.index, old_p2coeffs, &old_participants, "Φ", &mut rng).unwrap();
+        let replayed_share = old_p2_state.their_encrypted_secret_shares().unwrap()[0].clone();
+
+        // A 3-out-of-5 session between the same parties, with fresh DH keys.
+        let params = Parameters { n: 5, t: 3 };
+        let dealers: Vec<_> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng))
+            .collect();
+        let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+        let all_dealers_shares: Vec<Vec<EncryptedSecretShare>> = dealers
+            .iter()
+            .map(|(p, coefficients, dh_sk)| {
+                DistributedKeyGeneration::<RoundOne>::new_initial(
+                    &params, dh_sk, &p.index, coefficients, &participants, "Φ", &mut rng).unwrap().0
+                    .their_encrypted_secret_shares().unwrap().clone()
+            })
+            .collect();
+
+        let (p1, p1coeffs, p1_dh_sk) = &dealers[0];
+        let (p1_state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, p1_dh_sk, &p1.index, p1coeffs, &participants, "Φ", &mut rng).unwrap();
+
+        let mut p1_my_encrypted_secret_shares = collect_shares_for(1, &all_dealers_shares);
+        p1_my_encrypted_secret_shares[1] = replayed_share;
+
+        match p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng) {
+            Err(Error::Complaint(complaints)) => {
+                assert_eq!(complaints.len(), 1);
+                assert_eq!(complaints[0].accused_index, 2);
+            },
+            _ => panic!("the replayed share should have been rejected"),
         }
-        assert!(do_test().is_ok());
     }
 
     #[test]
-    fn keygen_verify_complaint() {
-        fn do_test() -> Result<(), ()> {
-            let params = Parameters { n: 3, t: 2 };
-            let mut rng: OsRng = OsRng;
+    fn keygen_rekey_threshold() {
+        use crate::signature::calculate_lagrange_coefficients;
 
-            let (p1, p1coeffs, dh_sk1) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
-            let (p2, p2coeffs, dh_sk2) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
-            let (p3, p3coeffs, dh_sk3) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
-            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
-            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+        let (_, states) = run_dkg(&params, "Φ", &mut rng);
+        let outputs: Vec<(GroupKey, SecretKey)> = states.into_iter().map(|state| state.finish().unwrap()).collect();
+        let group_key = outputs[0].0;
 
-            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
-            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &dh_sk1,
-                                                                     &p1.index,
-                                                                     &p1coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+        // Tighten the 2-out-of-3 group to a 3-out-of-3 one.
+        let new_params = Parameters { n: 3, t: 3 };
+        let new_signers: Vec<_> = (1..=params.n)
+            .map(|i| Participant::new_signer(&new_params, i, "Φ", &mut rng))
+            .collect();
+        let signers: Vec<Participant> = new_signers.iter().map(|(p, _)| p.clone()).collect();
 
-            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &dh_sk2,
-                                                                     &p2.index,
-                                                                     &p2coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+        assert_eq!(
+            Participant::rekey(&params, 4, outputs[0].1.clone(), &signers, "Φ", &mut rng).map(|_| ()),
+            Err(Error::InvalidParameters)
+        );
+        assert_eq!(
+            Participant::rekey(&params, 3, outputs[0].1.clone(), &signers[..2], "Φ", &mut rng).map(|_| ()),
+            Err(Error::InvalidNumberOfParticipants(2, 3))
+        );
 
-            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                      &dh_sk3,
-                                                                      &p3.index,
-                                                                      &p3coeffs,
-                                                                      &participants,
-                                                                      "Φ",
-                                                                      &mut rng).or(Err(()))?;
-            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+        let mut rekeying_dealers = Vec::new();
+        let mut all_rekeyed_shares = Vec::new();
+        for (_, secret_key) in outputs.iter() {
+            let (dealer, shares, _) = Participant::rekey(&params, 3, secret_key.clone(), &signers, "Φ", &mut rng).unwrap();
+            rekeying_dealers.push(dealer);
+            all_rekeyed_shares.push(shares);
+        }
 
-            let mut complaint: Complaint;
+        let mut new_secret_keys = Vec::new();
+        for (signer, dh_sk) in new_signers.iter() {
+            let (state, _) = DistributedKeyGeneration::<RoundOne>::new(
+                &params, dh_sk, &signer.index, &rekeying_dealers, "Φ", &mut rng).unwrap();
+            let shares = collect_shares_for(signer.index, &all_rekeyed_shares);
+            let (new_group_key, secret_key) = state.to_round_two(shares, &mut rng).unwrap().finish().unwrap();
 
-            // Wrong decryption from nonce
-            {
-                let mut wrong_encrypted_secret_share = p1_their_encrypted_secret_shares[1].clone();
-                wrong_encrypted_secret_share.nonce = [42; 16];
-                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                               p2_their_encrypted_secret_shares[0].clone(),
-                                               p3_their_encrypted_secret_shares[0].clone());
-                // Wrong share inserted here!
-                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
-                                               p2_their_encrypted_secret_shares[1].clone(),
-                                               p3_their_encrypted_secret_shares[1].clone());
-                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                               p2_their_encrypted_secret_shares[2].clone(),
-                                               p3_their_encrypted_secret_shares[2].clone());
+            assert_eq!(new_group_key, group_key);
+            new_secret_keys.push(secret_key);
+        }
 
-                let p1_state = p1_state.clone().to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-                let p3_state = p3_state.clone().to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        // All three new shares are needed to interpolate the group key.
+        let interpolate = |indices: &[u32]| -> RistrettoPoint {
+            indices
+                .iter()
+                .map(|i| {
+                    let public_key: IndividualPublicKey = (&new_secret_keys[*i as usize - 1]).into();
+                    public_key.share * calculate_lagrange_coefficients(i, indices).unwrap()
+                })
+                .sum()
+        };
 
-                let complaints = p2_state.clone().to_round_two(p2_my_encrypted_secret_shares, &mut rng);
-                assert!(complaints.is_err());
-                let complaints = complaints.unwrap_err();
-                if let Error::Complaint(complaints) = complaints {
-                    assert!(complaints.len() == 1);
+        assert_eq!(interpolate(&[1, 2, 3]), group_key.0);
+        assert_ne!(interpolate(&[1, 2]), group_key.0);
+        assert_ne!(interpolate(&[1, 3]), group_key.0);
+        assert_ne!(interpolate(&[2, 3]), group_key.0);
+    }
 
-                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]);
-                    assert!(bad_index == 1);
+    #[test]
+    fn participant_set_fingerprint() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
-                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+        let participants: Vec<Participant> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0)
+            .collect();
+        let reordered = vec![participants[2].clone(), participants[0].clone(), participants[1].clone()];
 
-                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
+        assert_eq!(fingerprint(&participants), fingerprint(&reordered));
 
-                    // Copy for next test and change dh_key
-                    complaint = complaints[0].clone();
-                    complaint.dh_key[0] += 1;
-                } else {
-                    return Err(())
-                }
-            }
+        let mut changed = participants.clone();
+        changed[1].commitments.as_mut().unwrap().points[1] += &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
+        assert_ne!(fingerprint(&participants), fingerprint(&changed));
 
-            // Wrong decryption of polynomial evaluation
-            {
-                let mut wrong_encrypted_secret_share = p1_their_encrypted_secret_shares[1].clone();
-                wrong_encrypted_secret_share.encrypted_polynomial_evaluation = [42; 32];
-                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                               p2_their_encrypted_secret_shares[0].clone(),
-                                               p3_their_encrypted_secret_shares[0].clone());
-                // Wrong share inserted here!
-                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
-                                               p2_their_encrypted_secret_shares[1].clone(),
-                                               p3_their_encrypted_secret_shares[1].clone());
-                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                               p2_their_encrypted_secret_shares[2].clone(),
-                                               p3_their_encrypted_secret_shares[2].clone());
+        assert_ne!(fingerprint(&participants), fingerprint(&participants[..2]));
+    }
 
-                let p1_state = p1_state.clone().to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-                let p3_state = p3_state.clone().to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+    #[test]
+    fn keygen_round_one_aborts_early() {
+        let params = Parameters { n: 5, t: 3 };
+        let mut rng: OsRng = OsRng;
 
-                let complaints = p2_state.clone().to_round_two(p2_my_encrypted_secret_shares, &mut rng);
-                assert!(complaints.is_err());
-                let complaints = complaints.unwrap_err();
-                if let Error::Complaint(complaints) = complaints {
-                    assert!(complaints.len() == 1);
+        let dealers = new_dealers(&params, "Φ", &mut rng);
+        let mut participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
 
-                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]);
-                    assert!(bad_index == 1);
+        // The first n - t + 1 participants present someone else's DH proof.
+        let bad = (params.n - params.t + 1) as usize;
+        for participant in participants.iter_mut().take(bad) {
+            participant.proof_of_dh_private_key = dealers[4].0.proof_of_dh_private_key.clone();
+        }
 
-                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
-                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+        // The number of participants placed in either list is the number of
+        // participants which have been checked.
+        let full = partition_participants::<Sha512>(&params, &participants, "Φ", PartitionChecks::ALL);
+        assert_eq!(full.valid_participants.len() + full.misbehaving_participants.len(), params.n as usize);
+
+        let early = partition_participants::<Sha512>(
+            &params, &participants, "Φ", PartitionChecks { abort_early: true, ..PartitionChecks::ALL });
+        assert_eq!(early.valid_participants.len(), 0);
+        assert_eq!(early.misbehaving_participants, vec![1, 2, 3]);
+
+        let (_, coefficients, dh_sk) = &dealers[4];
+        let result = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, dh_sk, &5, coefficients, &participants, "Φ", &mut rng);
+        assert_eq!(result.map(|_| ()), Err(Error::TooManyInvalidParticipants(vec![1, 2, 3])));
+
+        // With n - t bad participants, the key generation goes on.
+        participants[0] = dealers[0].0.clone();
+        let (_, lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, dh_sk, &5, coefficients, &participants, "Φ", &mut rng).unwrap();
+        assert_eq!(lists.misbehaving_participants, Some(vec![2, 3]));
+    }
 
-                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
-                } else {
-                    return Err(())
-                }
-            }
+    #[test]
+    fn keygen_reshare_with_overlapping_members() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            // Wrong encrypted share
-            {
-                let dh_key = (p1.dh_public_key.0 * dh_sk1.0).compress().to_bytes();
-                let wrong_encrypted_secret_share = encrypt_share(
-                    &SecretShare {
-                        sender_index: 1,
-                        receiver_index: 2,
-                        polynomial_evaluation: Scalar::from(42u32)
-                    },
-                    &dh_key,
-                    &mut rng,
-                );
-                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                               p2_their_encrypted_secret_shares[0].clone(),
-                                               p3_their_encrypted_secret_shares[0].clone());
-                // Wrong share inserted here!
-                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
-                                               p2_their_encrypted_secret_shares[1].clone(),
-                                               p3_their_encrypted_secret_shares[1].clone());
-                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                               p2_their_encrypted_secret_shares[2].clone(),
-                                               p3_their_encrypted_secret_shares[2].clone());
+        let (_, states) = run_dkg(&params, "Φ", &mut rng);
+        let outputs: Vec<(GroupKey, SecretKey)> = states.into_iter().map(|state| state.finish().unwrap()).collect();
+        let group_key = outputs[0].0;
+
+        // The holders of the first two shares stay in a 2-out-of-2 group, with
+        // the same indices, while the third one only deals. Each staying party
+        // holds a dealer and a signer, with distinct DH keys.
+        let new_params = Parameters { n: 2, t: 2 };
+        let new_signers: Vec<_> = (1..=new_params.n)
+            .map(|i| Participant::new_signer(&new_params, i, "Φ", &mut rng))
+            .collect();
+        let signers: Vec<Participant> = new_signers.iter().map(|(p, _)| p.clone()).collect();
+
+        // A signer listed twice is rejected.
+        let duplicated_signers = vec![signers[0].clone(), signers[0].clone()];
+        assert_eq!(
+            Participant::reshare(&new_params, outputs[0].1.clone(), &duplicated_signers, "Φ", &mut rng).map(|_| ()),
+            Err(Error::InvalidParticipantIndex(1))
+        );
 
-                let p1_state = p1_state.clone().to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-                let p3_state = p3_state.clone().to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        let mut resharing_dealers = Vec::new();
+        let mut all_reshared_shares = Vec::new();
+        for (_, secret_key) in outputs.iter() {
+            let (dealer, shares, _) = Participant::reshare(&new_params, secret_key.clone(), &signers, "Φ", &mut rng).unwrap();
+            resharing_dealers.push(dealer);
+            all_reshared_shares.push(shares);
+        }
 
-                let complaints = p2_state.clone().to_round_two(p2_my_encrypted_secret_shares, &mut rng);
-                assert!(complaints.is_err());
-                let complaints = complaints.unwrap_err();
-                if let Error::Complaint(complaints) = complaints {
-                    assert!(complaints.len() == 1);
+        // A dealer listed twice, in place of another one, is rejected.
+        let duplicated_dealers = vec![resharing_dealers[0].clone(), resharing_dealers[1].clone(), resharing_dealers[1].clone()];
+        let (signer, dh_sk) = &new_signers[0];
+        assert_eq!(
+            DistributedKeyGeneration::<RoundOne>::new(&params, dh_sk, &signer.index, &duplicated_dealers, "Φ", &mut rng)
+                .map(|_| ()),
+            Err(Error::InvalidParticipantIndex(2))
+        );
 
-                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]);
-                    assert!(bad_index == 1);
+        let mut new_secret_keys = Vec::new();
+        for (signer, dh_sk) in new_signers.iter() {
+            let (state, _) = DistributedKeyGeneration::<RoundOne>::new(
+                &params, dh_sk, &signer.index, &resharing_dealers, "Φ", &mut rng).unwrap();
+            let shares = collect_shares_for(signer.index, &all_reshared_shares);
+            let (new_group_key, secret_key) = state.to_round_two(shares, &mut rng).unwrap().finish().unwrap();
 
-                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
-                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+            assert_eq!(new_group_key, group_key);
+            new_secret_keys.push(secret_key);
+        }
 
-                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
-                } else {
-                    return Err(())
-                }
-            }
+        let group_secret_key = calculate_lagrange_coefficients(&1, &[1, 2]).unwrap() * new_secret_keys[0].key
+            + calculate_lagrange_coefficients(&2, &[1, 2]).unwrap() * new_secret_keys[1].key;
+        assert_eq!(&RISTRETTO_BASEPOINT_TABLE * &group_secret_key, group_key.0);
+    }
 
-            // Wrong complaint leads to blaming the complaint maker
-            {
-                let _p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                               p2_their_encrypted_secret_shares[0].clone(),
-                                               p3_their_encrypted_secret_shares[0].clone());
-                let _p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                               p2_their_encrypted_secret_shares[1].clone(),
-                                               p3_their_encrypted_secret_shares[1].clone());
-                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                               p2_their_encrypted_secret_shares[2].clone(),
-                                               p3_their_encrypted_secret_shares[2].clone());
+    #[test]
+    fn keygen_group_key_proof() {
+        let params = Parameters { n: 5, t: 3 };
+        let mut rng: OsRng = OsRng;
 
-                let p3_state = p3_state.clone().to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        let (participants, states) = run_dkg(&params, "Φ", &mut rng);
+        let group_key = states[0].group_key().unwrap();
+
+        let commitments: Vec<VerifiableSecretSharingCommitment> =
+            participants.iter().rev().map(|p| p.commitments.clone().unwrap()).collect();
+        let digest = commitments_digest(&commitments).unwrap();
+
+        let proof = GroupKeyProof::new(&commitments).unwrap();
+        assert!(proof.verify(&digest, &group_key).is_ok());
+        assert_eq!(GroupKeyProof::from_bytes(&proof.to_bytes()).unwrap(), proof);
+
+        let other_group_key = GroupKey::new(&RISTRETTO_BASEPOINT_TABLE * &Scalar::random(&mut rng));
+        assert_eq!(proof.verify(&digest, &other_group_key), Err(Error::InvalidGroupKey));
+
+        // Tampering with any term of a commitment breaks the proof.
+        let mut tampered = commitments.clone();
+        tampered[2].points[2] += &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
+        let tampered_proof = GroupKeyProof::new(&tampered).unwrap();
+        assert_eq!(tampered_proof.verify(&digest, &group_key), Err(Error::InvalidCommitment));
+
+        let mut tampered = commitments.clone();
+        tampered[2].points[0] += &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
+        let tampered_proof = GroupKeyProof::new(&tampered).unwrap();
+        assert_eq!(tampered_proof.verify(&digest, &group_key), Err(Error::InvalidCommitment));
+        assert_eq!(
+            tampered_proof.verify(&commitments_digest(&tampered).unwrap(), &group_key),
+            Err(Error::InvalidGroupKey)
+        );
 
-                let bad_index = p3_state.blame(&p1_their_encrypted_secret_shares[0], &complaint);
-                assert!(bad_index == 2);
+        // Commitments to the identity are rejected, as everywhere else.
+        let mut tampered = commitments.clone();
+        tampered[2].points[0] = RistrettoPoint::identity();
+        assert_eq!(commitments_digest(&tampered), Err(Error::InvalidCommitment));
+        assert_eq!(GroupKeyProof::new(&tampered), Err(Error::InvalidCommitment));
+
+        let mut tampered_proof = proof.clone();
+        tampered_proof.leaves[2].1 = RistrettoPoint::identity();
+        assert_eq!(tampered_proof.verify(&digest, &group_key), Err(Error::InvalidCommitment));
+
+        // Commitments and leaves indexed by zero, or twice by the same index,
+        // are rejected.
+        let mut tampered = commitments.clone();
+        tampered[2].index = 0;
+        assert_eq!(commitments_digest(&tampered), Err(Error::InvalidParticipantIndex(0)));
+        assert_eq!(GroupKeyProof::new(&tampered), Err(Error::InvalidParticipantIndex(0)));
+
+        let mut tampered = commitments.clone();
+        tampered[2].index = tampered[3].index;
+        assert_eq!(commitments_digest(&tampered), Err(Error::InvalidParticipantIndex(tampered[3].index)));
+
+        let mut tampered_proof = proof.clone();
+        tampered_proof.leaves[0].0 = 0;
+        assert_eq!(tampered_proof.verify(&digest, &group_key), Err(Error::InvalidParticipantIndex(0)));
+
+        let mut tampered_proof = proof.clone();
+        tampered_proof.leaves[1].0 = 1;
+        assert_eq!(tampered_proof.verify(&digest, &group_key), Err(Error::InvalidParticipantIndex(1)));
+
+        assert_eq!(commitments_digest(&[]), Err(Error::InvalidCommitment));
+        assert_eq!(GroupKeyProof::from_bytes(&u32::MAX.to_le_bytes()), Err(Error::SerialisationError));
+    }
+
+    #[test]
+    fn keygen_commitment_inclusion_proof() {
+        let mut rng: OsRng = OsRng;
+
+        // Trees with a single leaf, a power of two of leaves, or an odd
+        // number of leaves at some level.
+        for n in [1u32, 2, 4, 5, 7] {
+            let params = Parameters { n, t: 1 };
+            let commitments: Vec<VerifiableSecretSharingCommitment> = (1..=n)
+                .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0.commitments.unwrap())
+                .collect();
+            let root = commitments_digest(&commitments).unwrap();
+            let proof = GroupKeyProof::new(&commitments).unwrap();
+
+            for commitment in commitments.iter() {
+                let inclusion = proof.inclusion_proof(commitment.index).unwrap();
+                assert!(inclusion.verify(&root, commitment).is_ok());
+                assert_eq!(CommitmentInclusionProof::from_bytes(&inclusion.to_bytes()).unwrap(), inclusion);
+
+                // The path only holds for this very commitment.
+                let mut tampered = commitment.clone();
+                tampered.points[0] += &RISTRETTO_BASEPOINT_TABLE * &Scalar::one();
+                assert_eq!(inclusion.verify(&root, &tampered), Err(Error::InvalidCommitment));
+
+                if n > 1 {
+                    let other = &commitments[(commitment.index % n) as usize];
+                    assert_eq!(inclusion.verify(&root, other), Err(Error::InvalidCommitment));
+
+                    let mut shifted = inclusion.clone();
+                    shifted.position = (shifted.position + 1) % n;
+                    assert_eq!(shifted.verify(&root, commitment), Err(Error::InvalidCommitment));
+                }
+
+                let mut extended = inclusion.clone();
+                extended.siblings.push([0u8; 32]);
+                assert_eq!(extended.verify(&root, commitment), Err(Error::InvalidCommitment));
             }
 
-            Ok(())
+            assert_eq!(proof.inclusion_proof(n + 1), Err(Error::InvalidParticipantIndex(n + 1)));
         }
-        assert!(do_test().is_ok());
     }
 
     #[test]
-    fn serialisation() {
-        fn do_test() -> Result<(), ()> {
-            let params = Parameters { n: 3, t: 2 };
-            let mut rng: OsRng = OsRng;
-
-            let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
-            let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
-            let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+    fn keygen_blame_without_commitments() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
-            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
-            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+        let (_, _, mut states) = run_round_one(&params, "Φ", &mut rng);
+        let all_dealers_shares = all_encrypted_shares(&states);
 
-            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
-            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &p1_dh_sk,
-                                                                     &p1.index,
-                                                                     &p1coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+        let shares = collect_shares_for(3, &all_dealers_shares);
+        let mut p3_state = states.remove(2).to_round_two(shares, &mut rng).unwrap();
 
-            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &p2_dh_sk,
-                                                                     &p2.index,
-                                                                     &p2coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+        let complaint = Complaint {
+            maker_index: 2,
+            accused_index: 1,
+            dh_key: [0u8; 32],
+            proof: ComplaintProof::from_bytes(&[0u8; 96]).unwrap(),
+        };
+        let disputed_share = all_dealers_shares[0][1].clone();
+        assert!(p3_state.blame(&disputed_share, &complaint).is_ok());
 
-            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                      &p3_dh_sk,
-                                                                      &p3.index,
-                                                                      &p3coeffs,
-                                                                      &participants,
-                                                                      "Φ",
-                                                                      &mut rng).or(Err(()))?;
-            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+        // A state restored from bytes without the commitments cannot assign blame.
+        p3_state.state.their_commitments = None;
+        let p3_state = DistributedKeyGeneration::<RoundTwo>::from_bytes(&p3_state.to_bytes()).unwrap();
 
-            {
-                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                           p2_their_encrypted_secret_shares[0].clone(),
-                                           p3_their_encrypted_secret_shares[0].clone());
-                let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
-                                           p2_their_encrypted_secret_shares[1].clone(),
-                                           p3_their_encrypted_secret_shares[1].clone());
-                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                           p2_their_encrypted_secret_shares[2].clone(),
-                                           p3_their_encrypted_secret_shares[2].clone());
+        assert!(p3_state.blame(&disputed_share, &complaint).is_err());
+        assert!(p3_state.resolve_complaints(&[(disputed_share, complaint)]).is_err());
+    }
 
-                // Check serialisation
+    #[test]
+    fn authenticated_share_tampering_is_detected() {
+        let dealer_dh_sk = Scalar::random(&mut OsRng);
+        let receiver_dh_sk = Scalar::random(&mut OsRng);
+        let dh_key = ((&RISTRETTO_BASEPOINT_TABLE * &receiver_dh_sk) * dealer_dh_sk).compress().to_bytes();
 
-                let bytes = p1.to_bytes();
-                assert_eq!(p1, Participant::from_bytes(&bytes).unwrap());
+        let params = Parameters { n: 3, t: 2 };
 
-                let bytes = p1coeffs.to_bytes();
-                let p1coeffs_deserialised = Coefficients::from_bytes(&bytes).unwrap();
-                assert_eq!(p1coeffs.0.len(), p1coeffs_deserialised.0.len());
-                for i in 0..p1coeffs.0.len() {
-                    assert_eq!(p1coeffs.0[i], p1coeffs_deserialised.0[i]);
-                }
+        let share = SecretShare { sender_index: 1, receiver_index: 2, polynomial_evaluation: Scalar::random(&mut OsRng) };
+        let posted = encrypt_share(&share, &dh_key, &mut OsRng).unwrap().authenticate(&dh_key, &params, "Φ").unwrap();
 
-                let bytes = p1_dh_sk.to_bytes();
-                assert_eq!(p1_dh_sk, DHPrivateKey::from_bytes(&bytes).unwrap());
+        assert!(posted.verify_integrity(&dh_key, &params, "Φ").is_ok());
+        assert_eq!(posted.decrypt(&dh_key, &params, "Φ").unwrap(), share);
+        assert_eq!(AuthenticatedEncryptedSecretShare::from_bytes(&posted.to_bytes()).unwrap(), posted);
 
-                let bytes = p1.proof_of_secret_key.as_ref().unwrap().to_bytes();
-                assert_eq!(p1.proof_of_secret_key.unwrap(), NizkOfSecretKey::from_bytes(&bytes).unwrap());
+        // A tag only verifies under the DH key of its sender and receiver.
+        let other_dh_key = (&RISTRETTO_BASEPOINT_TABLE * &Scalar::random(&mut OsRng)).compress().to_bytes();
+        assert_eq!(posted.verify_integrity(&other_dh_key, &params, "Φ"), Err(Error::IntegrityError));
 
-                let bytes = p1_state.their_encrypted_secret_shares().unwrap()[0].to_bytes();
-                assert_eq!(p1_state.their_encrypted_secret_shares().unwrap()[0], EncryptedSecretShare::from_bytes(&bytes).unwrap());
+        // Tampering with the ciphertext, the nonce, the indices or the tag is
+        // caught by the MAC, before any decryption is attempted.
+        for position in [0, 4, 8, 24, 55, 56, 87] {
+            let mut bytes = posted.to_bytes();
+            bytes[position] ^= 1;
+            let tampered = AuthenticatedEncryptedSecretShare::from_bytes(&bytes).unwrap();
 
-                let bytes = p1_state.to_bytes();
-                assert_eq!(*p1_state.state, *DistributedKeyGeneration::<RoundOne>::from_bytes(&bytes).unwrap().state);
+            assert_eq!(tampered.verify_integrity(&dh_key, &params, "Φ"), Err(Error::IntegrityError));
+            assert_eq!(tampered.decrypt(&dh_key, &params, "Φ"), Err(Error::IntegrityError));
+        }
+    }
 
-                // Continue KeyGen
+    #[test]
+    fn authenticated_share_replayed_across_sessions() {
+        let dealer_dh_sk = Scalar::random(&mut OsRng);
+        let receiver_dh_sk = Scalar::random(&mut OsRng);
+        let dh_key = ((&RISTRETTO_BASEPOINT_TABLE * &receiver_dh_sk) * dealer_dh_sk).compress().to_bytes();
+
+        // A share posted during a 2-out-of-3 session.
+        let old_params = Parameters { n: 3, t: 2 };
+        let share = SecretShare { sender_index: 1, receiver_index: 2, polynomial_evaluation: Scalar::random(&mut OsRng) };
+        let posted = encrypt_share(&share, &dh_key, &mut OsRng).unwrap().authenticate(&dh_key, &old_params, "Φ").unwrap();
+
+        // The same parties reuse their DH keys for a 3-out-of-5 session, or
+        // for another session with the same parameters.
+        let params = Parameters { n: 5, t: 3 };
+        assert_eq!(posted.verify_integrity(&dh_key, &params, "Φ"), Err(Error::IntegrityError));
+        assert_eq!(posted.decrypt(&dh_key, &params, "Φ"), Err(Error::IntegrityError));
+        assert_eq!(posted.verify_integrity(&dh_key, &old_params, "Ψ"), Err(Error::IntegrityError));
 
-                let p1_state = p1_state.clone().to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-                let p2_state = p2_state.clone().to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-                let p3_state = p3_state.clone().to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        assert_eq!(posted.decrypt(&dh_key, &old_params, "Φ").unwrap(), share);
+    }
 
-                let (p1_group_key, _p1_secret_key) = p1_state.clone().finish().or(Err(()))?;
-                let (p2_group_key, _p2_secret_key) = p2_state.finish().or(Err(()))?;
-                let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+    #[test]
+    fn generate_public_key_with_parameters() {
+        let params = Parameters { n: 5, t: 3 };
+        let commitments: Vec<VerifiableSecretSharingCommitment> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut OsRng).0.commitments.unwrap())
+            .collect();
+
+        for index in 1..=params.n {
+            assert_eq!(
+                IndividualPublicKey::generate_from_commitments_with_parameters(index, &commitments, &params).unwrap(),
+                IndividualPublicKey::generate_from_commitments(index, &commitments).unwrap(),
+            );
+        }
 
-                assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
-                assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+        // A subset of the dealers interpolates to the same shares as well.
+        assert_eq!(
+            IndividualPublicKey::generate_from_commitments_with_parameters(2, &commitments[1..4], &params).unwrap(),
+            IndividualPublicKey::generate_from_commitments(2, &commitments[1..4]).unwrap(),
+        );
+    }
 
-                // Check serialisation
-                let bytes = p1_group_key.to_bytes();
-                assert_eq!(p1_group_key, GroupKey::from_bytes(&bytes).unwrap());
+    #[test]
+    fn keygen_round_one_deferred_shares() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng = ControlledRng(0xdef3_44ed);
+
+        let dealers = new_dealers(&params, "Φ", &mut rng);
+        let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+        let (p1, p1_coefficients, p1_dh_sk) = &dealers[0];
+
+        let (combined, combined_list) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, p1_dh_sk, &p1.index, p1_coefficients, &participants, "Φ", ControlledRng(7)).unwrap();
+
+        // The participant set is checked first, without computing any share.
+        let (mut deferred, deferred_list) = DistributedKeyGeneration::<RoundOne>::new_initial_deferred(
+            &params, p1_dh_sk, &p1.index, &participants, "Φ").unwrap();
+        assert_eq!(deferred.their_encrypted_secret_shares(), Err(Error::NoEncryptedShares));
+        assert_eq!(deferred_list.to_bytes(), combined_list.to_bytes());
+        assert!(deferred_list.misbehaving_participants.is_none());
+
+        // Once approved, the shares are the same as with the combined call.
+        let shares = deferred.compute_encrypted_shares(p1_coefficients, &participants, ControlledRng(7)).unwrap().clone();
+        assert_eq!(&shares, combined.their_encrypted_secret_shares().unwrap());
+        assert_eq!(deferred.state.to_bytes(), combined.state.to_bytes());
+
+        // The shares can only be computed once.
+        assert_eq!(
+            deferred.compute_encrypted_shares(p1_coefficients, &participants, ControlledRng(8)).map(|_| ()),
+            Err(Error::SharesAlreadyComputed)
+        );
+        assert_eq!(deferred.their_encrypted_secret_shares().unwrap(), &shares);
+    }
 
-                let bytes = p1_state.to_bytes();
-                assert_eq!(*p1_state.state, *DistributedKeyGeneration::<RoundTwo>::from_bytes(&bytes).unwrap().state);
-            }
+    #[test]
+    fn keygen_deferred_shares_for_unapproved_participants() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            {
-                let wrong_encrypted_secret_share = EncryptedSecretShare {sender_index: 1,
-                                                                         receiver_index: 2,
-                                                                         nonce: [0; 16],
-                                                                         encrypted_polynomial_evaluation: [0; 32]};
+        let dealers = new_dealers(&params, "Φ", &mut rng);
+        let mut participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+        let (p1, p1_coefficients, p1_dh_sk) = &dealers[0];
 
-                let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                           p2_their_encrypted_secret_shares[0].clone(),
-                                           p3_their_encrypted_secret_shares[0].clone());
-                let p2_my_encrypted_secret_shares = vec!(wrong_encrypted_secret_share.clone(),
-                                           p2_their_encrypted_secret_shares[1].clone(),
-                                           p3_their_encrypted_secret_shares[1].clone());
-                let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                           p2_their_encrypted_secret_shares[2].clone(),
-                                           p3_their_encrypted_secret_shares[2].clone());
+        // Participant 3 misbehaves, and is left out of the validated ones.
+        participants[2].proof_of_dh_private_key = participants[1].proof_of_dh_private_key.clone();
+        let (mut deferred, list) = DistributedKeyGeneration::<RoundOne>::new_initial_deferred(
+            &params, p1_dh_sk, &p1.index, &participants, "Φ").unwrap();
+        assert_eq!(list.misbehaving_participants, Some(vec![3]));
 
-                let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-                let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+        assert_eq!(
+            deferred.compute_encrypted_shares(p1_coefficients, &participants, &mut rng).map(|_| ()),
+            Err(Error::UnapprovedParticipant(3))
+        );
 
-                let complaints = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng);
-                assert!(complaints.is_err());
-                let complaints = complaints.unwrap_err();
-                if let Error::Complaint(complaints) = complaints {
-                    assert!(complaints.len() == 1);
+        // Neither is a participant unknown to the key generation, nor one
+        // whose DH key was swapped after the validation.
+        let (outsider, _, _) = Participant::new_dealer(&Parameters { n: 4, t: 2 }, 4, "Φ", &mut rng);
+        assert_eq!(
+            deferred.compute_encrypted_shares(p1_coefficients, &[outsider], &mut rng).map(|_| ()),
+            Err(Error::UnapprovedParticipant(4))
+        );
+        let mut swapped = participants[1].clone();
+        swapped.dh_public_key = participants[0].dh_public_key.clone();
+        assert_eq!(
+            deferred.compute_encrypted_shares(p1_coefficients, &[swapped], &mut rng).map(|_| ()),
+            Err(Error::UnapprovedParticipant(2))
+        );
 
-                    let bad_index = p3_state.blame(&wrong_encrypted_secret_share, &complaints[0]);
+        let duplicated = vec![participants[1].clone(), participants[1].clone()];
+        assert_eq!(
+            deferred.compute_encrypted_shares(p1_coefficients, &duplicated, &mut rng).map(|_| ()),
+            Err(Error::InvalidParticipantIndex(2))
+        );
+        assert_eq!(deferred.their_encrypted_secret_shares(), Err(Error::NoEncryptedShares));
 
-                    assert!(bad_index == 1);
+        // The approved participants get their shares.
+        let shares = deferred.compute_encrypted_shares(p1_coefficients, &list.valid_participants, &mut rng).unwrap();
+        assert_eq!(shares.iter().map(|share| share.receiver_index).collect::<Vec<u32>>(), vec![1, 2]);
+    }
 
-                    let (p1_group_key, _p1_secret_key) = p1_state.finish().or(Err(()))?;
-                    let (p3_group_key, _p3_secret_key) = p3_state.finish().or(Err(()))?;
+    #[test]
+    fn coefficients_from_bytes_bounds() {
+        let coefficients = Coefficients((0..3).map(|_| Scalar::random(&mut OsRng)).collect());
+        let bytes = coefficients.to_bytes();
+        assert_eq!(Coefficients::from_bytes(&bytes).unwrap().0, coefficients.0);
+
+        // A short buffer, or one without any length field, is rejected.
+        assert_eq!(Coefficients::from_bytes(&bytes[..bytes.len() - 1]).err(), Some(Error::SerialisationError));
+        assert_eq!(Coefficients::from_bytes(&bytes[..2]).err(), Some(Error::SerialisationError));
+        assert_eq!(Coefficients::from_bytes(&[]).err(), Some(Error::SerialisationError));
+
+        // An oversized length field is rejected without reading past the buffer.
+        let mut oversized = bytes.clone();
+        oversized[0..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(Coefficients::from_bytes(&oversized).err(), Some(Error::SerialisationError));
+
+        // A non-canonical coefficient after valid ones is rejected as well.
+        let mut non_canonical = bytes;
+        non_canonical[4 + 2 * 32..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(Coefficients::from_bytes(&non_canonical).err(), Some(Error::SerialisationError));
+    }
 
-                    assert!(p1_group_key.0.compress() == p3_group_key.0.compress());
+    #[test]
+    fn dealer_collects_share_receipts() {
+        let params = Parameters { n: 4, t: 3 };
+
+        let dealers: Vec<_> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut OsRng))
+            .collect();
+        let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+        let all_dealers_shares: Vec<Vec<EncryptedSecretShare>> = dealers
+            .iter()
+            .take(2)
+            .map(|(p, coefficients, dh_sk)| {
+                let (state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+                    &params, dh_sk, &p.index, coefficients, &participants, "Φ", &mut OsRng).unwrap();
+                state.their_encrypted_secret_shares().unwrap().clone()
+            })
+            .collect();
+        let shares = &all_dealers_shares[0];
+
+        // Every recipient acknowledges the share of dealer 1, except the
+        // fourth one, whose share failed to verify.
+        let mut receipts: Vec<ShareReceipt> = dealers
+            .iter()
+            .zip(shares.iter())
+            .map(|((p, _, dh_sk), share)| ShareReceipt::new(share, p.index != 4, dh_sk, "Φ", &mut OsRng))
+            .collect();
 
-                    // Check serialisation
+        for ((receipt, p), share) in receipts.iter().zip(participants.iter()).zip(shares.iter()) {
+            assert!(receipt.verify(share, &p.dh_public_key, "Φ").is_ok());
+            assert_eq!(ShareReceipt::from_bytes(&receipt.to_bytes()).unwrap(), *receipt);
+        }
+        assert_eq!(
+            receipts[0].verify(&shares[0], &participants[1].dh_public_key, "Φ"),
+            Err(Error::InvalidShareReceipt(1))
+        );
+        assert_eq!(unacknowledged_recipients(shares, &participants, &receipts, "Φ"), vec![4]);
+
+        // A receipt does not acknowledge another share, nor the same share
+        // in another session.
+        let mut other_share = shares[0].clone();
+        other_share.encrypted_polynomial_evaluation[0] ^= 1;
+        assert_eq!(
+            receipts[0].verify(&other_share, &participants[0].dh_public_key, "Φ"),
+            Err(Error::InvalidShareReceipt(1))
+        );
+        assert_eq!(
+            receipts[0].verify(&shares[0], &participants[0].dh_public_key, "Ψ"),
+            Err(Error::InvalidShareReceipt(1))
+        );
+        assert_eq!(unacknowledged_recipients(shares, &participants, &receipts, "Ψ"), vec![1, 2, 3, 4]);
 
-                    let bytes = complaints[0].proof.to_bytes();
-                    assert_eq!(complaints[0].proof, ComplaintProof::from_bytes(&bytes).unwrap());
+        // A missing receipt is detected as well.
+        receipts.remove(1);
+        assert_eq!(unacknowledged_recipients(shares, &participants, &receipts, "Φ"), vec![2, 4]);
 
-                    let bytes = complaints[0].to_bytes();
-                    assert_eq!(complaints[0], Complaint::from_bytes(&bytes).unwrap());
+        // A receipt whose flag was flipped no longer verifies.
+        let mut bytes = receipts[2].to_bytes();
+        bytes[8] = 1;
+        let forged = ShareReceipt::from_bytes(&bytes).unwrap();
+        assert!(forged.verify(&shares[3], &participants[3].dh_public_key, "Φ").is_err());
+        receipts[2] = forged;
+        assert_eq!(unacknowledged_recipients(shares, &participants, &receipts, "Φ"), vec![2, 4]);
 
-                    Ok(())
-                } else {
-                    Err(())
-                }
-            }
-        }
+        bytes[8] = 2;
+        assert_eq!(ShareReceipt::from_bytes(&bytes), Err(Error::SerialisationError));
 
-        assert!(do_test().is_ok());
+        // Receipts for another dealer do not count.
+        assert_eq!(unacknowledged_recipients(&all_dealers_shares[1], &participants, &receipts, "Φ"), vec![1, 2, 3, 4]);
     }
 
     #[test]
-    fn individual_public_key_share() {
-        fn do_test() -> Result<(), ()> {
-            let params = Parameters { n: 3, t: 2 };
-            let mut rng: OsRng = OsRng;
+    fn group_key_from_commitments() {
+        let params = Parameters { n: 3, t: 2 };
 
-            let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
-            let (p2, p2coeffs, p2_dh_sk) = Participant::new_dealer(&params, 2, "Φ", &mut rng);
-            let (p3, p3coeffs, p3_dh_sk) = Participant::new_dealer(&params, 3, "Φ", &mut rng);
+        let (participants, states) = run_dkg(&params, "Φ", &mut OsRng);
+        let group_key = states[0].calculate_group_key().unwrap();
 
-            p1.proof_of_secret_key.as_ref().unwrap().verify(&p1.index, p1.public_key().unwrap(), "Φ").or(Err(()))?;
-            p2.proof_of_secret_key.as_ref().unwrap().verify(&p2.index, p2.public_key().unwrap(), "Φ").or(Err(()))?;
-            p3.proof_of_secret_key.as_ref().unwrap().verify(&p3.index, p3.public_key().unwrap(), "Φ").or(Err(()))?;
+        // The commitments may arrive in any order.
+        let mut commitments: Vec<VerifiableSecretSharingCommitment> =
+            participants.iter().rev().map(|p| p.commitments.clone().unwrap()).collect();
+        assert_eq!(calculate_group_key_from(&commitments, &params).unwrap(), group_key);
+        assert_eq!(calculate_group_key_from(&commitments, &params).unwrap(), interpolate_group_key(&commitments).unwrap());
 
-            let participants: Vec<Participant> = vec!(p1.clone(), p2.clone(), p3.clone());
-            let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &p1_dh_sk,
-                                                                     &p1.index,
-                                                                     &p1coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p1_their_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().or(Err(()))?;
+        assert_eq!(
+            calculate_group_key_from(&commitments[..1], &params),
+            Err(Error::InvalidNumberOfParticipants(1, 2))
+        );
+        assert_eq!(
+            calculate_group_key_from(&commitments, &Parameters { n: 3, t: 3 }),
+            Err(Error::InvalidCommitment)
+        );
 
-            let (p2_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                     &p2_dh_sk,
-                                                                     &p2.index,
-                                                                     &p2coeffs,
-                                                                     &participants,
-                                                                     "Φ",
-                                                                     &mut rng).or(Err(()))?;
-            let p2_their_encrypted_secret_shares = p2_state.their_encrypted_secret_shares().or(Err(()))?;
+        commitments[1].index = commitments[0].index;
+        assert_eq!(
+            calculate_group_key_from(&commitments, &params),
+            Err(Error::InvalidParticipantIndex(3))
+        );
+    }
 
-            let (p3_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(&params,
-                                                                      &p3_dh_sk,
-                                                                      &p3.index,
-                                                                      &p3coeffs,
-                                                                      &participants,
-                                                                      "Φ",
-                                                                      &mut rng).or(Err(()))?;
-            let p3_their_encrypted_secret_shares = p3_state.their_encrypted_secret_shares().or(Err(()))?;
+    #[test]
+    fn keygen_reshare_draws_randomness_from_rng_only() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let p1_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[0].clone(),
-                                           p2_their_encrypted_secret_shares[0].clone(),
-                                           p3_their_encrypted_secret_shares[0].clone());
-            let p2_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[1].clone(),
-                                           p2_their_encrypted_secret_shares[1].clone(),
-                                           p3_their_encrypted_secret_shares[1].clone());
-            let p3_my_encrypted_secret_shares = vec!(p1_their_encrypted_secret_shares[2].clone(),
-                                           p2_their_encrypted_secret_shares[2].clone(),
-                                           p3_their_encrypted_secret_shares[2].clone());
+        let (signer1, _signer1_dh_sk) = Participant::new_signer(&params, 1, "Φ", &mut rng);
+        let (signer2, _signer2_dh_sk) = Participant::new_signer(&params, 2, "Φ", &mut rng);
+        let (signer3, _signer3_dh_sk) = Participant::new_signer(&params, 3, "Φ", &mut rng);
+        let signers: Vec<Participant> = vec!(signer1, signer2, signer3);
+
+        let secret_key = SecretKey { index: 1, key: Scalar::random(&mut rng) };
+
+        let mut recording_rng = RecordingRng { inner: ControlledRng(42), drawn: Vec::new() };
+        let (dealer, encrypted_shares, _participant_lists) =
+            Participant::reshare(&params, secret_key.clone(), &signers, "Φ", &mut recording_rng).unwrap();
+        assert!(!recording_rng.drawn.is_empty());
+
+        // Replaying the recorded stream yields the very same outputs, and
+        // consumes the stream entirely.
+        let mut stream_rng = StreamRng(&recording_rng.drawn);
+        let (replayed_dealer, replayed_encrypted_shares, _participant_lists) =
+            Participant::reshare(&params, secret_key, &signers, "Φ", &mut stream_rng).unwrap();
+        assert!(stream_rng.0.is_empty());
+
+        assert_eq!(dealer.to_bytes(), replayed_dealer.to_bytes());
+        assert_eq!(encrypted_shares.len(), 3);
+        for (share, replayed_share) in encrypted_shares.iter().zip(replayed_encrypted_shares.iter()) {
+            assert_eq!(share.to_bytes(), replayed_share.to_bytes());
+        }
+    }
 
-            let p1_state = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let p2_state = p2_state.to_round_two(p2_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
-            let p3_state = p3_state.to_round_two(p3_my_encrypted_secret_shares, &mut rng).or(Err(()))?;
+    #[test]
+    fn secret_key_from_trusted_dealer_share() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            let (p1_group_key, p1_secret_key) = p1_state.finish().or(Err(()))?;
-            let (p2_group_key, p2_secret_key) = p2_state.finish().or(Err(()))?;
-            let (p3_group_key, p3_secret_key) = p3_state.finish().or(Err(()))?;
+        let (dealer, dealer_coeffs, _dealer_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let commitments = [dealer.commitments.unwrap()];
 
-            assert!(p1_group_key.0.compress() == p2_group_key.0.compress());
-            assert!(p2_group_key.0.compress() == p3_group_key.0.compress());
+        for receiver_index in 1..=params.n {
+            let share = SecretShare::evaluate_polynomial(&dealer.index, &receiver_index, &dealer_coeffs);
+            assert!(share.verify(&commitments[0]).is_ok());
 
-            // Check the validity of each IndividualPublicKey
+            let secret_key = SecretKey::from_secret_share(&share);
+            assert_eq!(secret_key.index, receiver_index);
 
-            let p1_public_key = p1_secret_key.to_public();
-            let p2_public_key = p2_secret_key.to_public();
-            let p3_public_key = p3_secret_key.to_public();
+            let expected_public_key = IndividualPublicKey::generate_from_commitments(receiver_index, &commitments).unwrap();
+            assert!(secret_key.matches_public(&expected_public_key));
+        }
+    }
 
-            // The order does not matter
-            let commitments = [p2.commitments.unwrap(), p3.commitments.unwrap(), p1.commitments.unwrap()];
+    #[test]
+    fn generate_public_key_from_duplicate_commitments() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            assert!(p1_public_key.verify(&commitments).is_ok());
-            assert!(p2_public_key.verify(&commitments).is_ok());
-            assert!(p3_public_key.verify(&commitments).is_ok());
+        let commitments: Vec<VerifiableSecretSharingCommitment> = (1..=params.n)
+            .map(|i| Participant::new_dealer(&params, i, "Φ", &mut rng).0.commitments.unwrap())
+            .collect();
+        assert!(IndividualPublicKey::generate_from_commitments(1, &commitments).is_ok());
 
-            assert!(p1_public_key.verify(&commitments[1..]).is_err());
+        let duplicated = vec![commitments[0].clone(), commitments[1].clone(), commitments[1].clone()];
+        assert_eq!(
+            IndividualPublicKey::generate_from_commitments(1, &duplicated),
+            Err(Error::InvalidParticipantIndex(2))
+        );
+        assert_eq!(
+            IndividualPublicKey::generate_from_commitments_with_parameters(1, &duplicated, &params),
+            Err(Error::InvalidParticipantIndex(2))
+        );
 
-            // Check that the generated IndividualPublicKey from other participants match
-            let p1_recovered_public_key = IndividualPublicKey::generate_from_commitments(1, &commitments);
-            let p2_recovered_public_key = IndividualPublicKey::generate_from_commitments(2, &commitments);
-            let p3_recovered_public_key = IndividualPublicKey::generate_from_commitments(3, &commitments);
+        let mut zero_indexed = commitments.clone();
+        zero_indexed[2].index = 0;
+        assert_eq!(
+            IndividualPublicKey::generate_from_commitments(1, &zero_indexed),
+            Err(Error::InvalidParticipantIndex(0))
+        );
+    }
 
-            assert_eq!(p1_public_key, p1_recovered_public_key);
-            assert_eq!(p2_public_key, p2_recovered_public_key);
-            assert_eq!(p3_public_key, p3_recovered_public_key);
+    #[test]
+    fn keygen_rejects_commitments_from_another_session() {
+        let params = Parameters { n: 3, t: 2 };
+        let mut rng: OsRng = OsRng;
 
-            Ok(())
+        // Two key generation sessions between participants with the same
+        // indices, each with its own context string, from the point of view
+        // of participant 1.
+        let mut sessions = Vec::new();
+        for context_string in ["Φ-a", "Φ-b"].iter() {
+            let (_, states) = run_dkg(&params, context_string, &mut rng);
+            sessions.push(states.into_iter().next().unwrap());
         }
-        assert!(do_test().is_ok());
+
+        let session_b = sessions.pop().unwrap();
+        let session_a = sessions.pop().unwrap();
+
+        // Participant 1 mixes the commitment of participant 2 from the second
+        // session into its state of the first one, first alone, then along
+        // with the proof of knowledge it came with.
+        let mut mixed = DistributedKeyGeneration::<RoundTwo>::from_bytes(&session_a.to_bytes()).unwrap();
+        mixed.state.their_commitments.as_mut().unwrap()[1] = session_b.state.their_commitments.as_ref().unwrap()[1].clone();
+        assert_eq!(mixed.group_key(), Err(Error::MismatchedSession(2)));
+
+        mixed.state.their_commitment_proofs[1] = session_b.state.their_commitment_proofs[1].clone();
+        assert_eq!(mixed.group_key(), Err(Error::MismatchedSession(2)));
+
+        let mixed = DistributedKeyGeneration::<RoundTwo>::from_bytes(&mixed.to_bytes()).unwrap();
+        assert_eq!(mixed.finish().map(|_| ()), Err(Error::MismatchedSession(2)));
+
+        // Dropping the proofs does not skip the check either.
+        let mut truncated = DistributedKeyGeneration::<RoundTwo>::from_bytes(&session_a.to_bytes()).unwrap();
+        truncated.state.their_commitment_proofs.truncate(1);
+        assert_eq!(truncated.group_key(), Err(Error::MismatchedSession(2)));
+
+        // A state serialised before the context string was recorded is
+        // restored without it, and still finishes.
+        let mut legacy = DistributedKeyGeneration::<RoundTwo>::from_bytes(&session_a.to_bytes()).unwrap();
+        legacy.state.context_string = None;
+        legacy.state.their_commitment_proofs.clear();
+        let legacy_bytes = legacy.to_bytes();
+        assert_eq!(legacy_bytes.len(), session_a.to_bytes().len() - (4 + "Φ-a".len()) - (4 + 3 * 64));
+
+        let legacy = DistributedKeyGeneration::<RoundTwo>::from_bytes(&legacy_bytes).unwrap();
+        assert_eq!(legacy.state.context_string, None);
+        assert!(legacy.state.their_commitment_proofs.is_empty());
+        assert_eq!(legacy.to_bytes(), legacy_bytes);
+        assert_eq!(legacy.finish().unwrap(), DistributedKeyGeneration::<RoundTwo>::from_bytes(&session_a.to_bytes()).unwrap().finish().unwrap());
+
+        // Anything between the legacy and the current encodings is rejected.
+        let mut partial = legacy_bytes.clone();
+        partial.insert(partial.len() - 1, 0u8);
+        assert!(DistributedKeyGeneration::<RoundTwo>::from_bytes(&partial).is_err());
+
+        assert!(session_a.finish().is_ok());
+        assert!(session_b.finish().is_ok());
     }
 }
\ No newline at end of file
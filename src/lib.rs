@@ -1044,8 +1044,8 @@
 //! #
 //! # let mut aggregator = SignatureAggregator::new(params, bob_group_key.clone(), &context[..], &message[..]);
 //! #
-//! aggregator.include_signer(1, alice_public_comshares.commitments[0], alice_public_key);
-//! aggregator.include_signer(3, carol_public_comshares.commitments[0], carol_public_key);
+//! aggregator.include_signer(1, alice_public_comshares.commitments[0], alice_public_key).or(Err(()))?;
+//! aggregator.include_signer(3, carol_public_comshares.commitments[0], carol_public_key).or(Err(()))?;
 //! # Ok(()) }
 //! # fn main() { assert!(do_test().is_ok()); }
 //! ```
@@ -1124,8 +1124,8 @@
 //! #
 //! # let mut aggregator = SignatureAggregator::new(params, bob_group_key.clone(), &context[..], &message[..]);
 //! #
-//! # aggregator.include_signer(1, alice_public_comshares.commitments[0], (&alice_secret_key).into());
-//! # aggregator.include_signer(3, carol_public_comshares.commitments[0], (&carol_secret_key).into());
+//! # aggregator.include_signer(1, alice_public_comshares.commitments[0], (&alice_secret_key).into()).or(Err(""))?;
+//! # aggregator.include_signer(3, carol_public_comshares.commitments[0], (&carol_secret_key).into()).or(Err(""))?;
 //! #
 //! # let signers = aggregator.get_signers();
 //!
@@ -1189,21 +1189,37 @@ extern crate std;
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod cfrg;
 pub mod keygen;
 pub mod parameters;
 pub mod precomputation;
 pub mod nizk;
 pub mod signature;
 
+#[cfg(feature = "bench")]
+pub mod bench;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+#[cfg(test)]
+mod test_vectors;
+
+#[cfg(test)]
+mod test_utils;
+
 pub use keygen::Error;
+pub use nizk::DkgHash;
 
 pub use keygen::DistributedKeyGeneration;
 pub use keygen::GroupKey;
 pub use keygen::IndividualPublicKey;
 pub use keygen::Participant;
+pub use keygen::PublicParticipant;
 pub use keygen::SecretKey as IndividualSecretKey;
 pub use parameters::Parameters;
 pub use precomputation::generate_commitment_share_lists;
 
+pub use signature::compute_group_commitment;
 pub use signature::compute_message_hash;
 pub use signature::SignatureAggregator;
@@ -22,6 +22,16 @@ use rand::Rng;
 
 use sha2::Digest;
 use sha2::Sha512;
+use sha2::digest::consts::U64;
+
+/// A hash function usable for the zero-knowledge proofs and the challenges
+/// of the distributed key generation.
+///
+/// This is implemented for any [`Digest`] with a 512-bit output, such as
+/// [`Sha512`], which is used by default, or SHA3-512.
+pub trait DkgHash: Digest<OutputSize = U64> + Default + Clone {}
+
+impl<H: Digest<OutputSize = U64> + Default + Clone> DkgHash for H {}
 
 /// A proof of knowledge of a secret key, created by making a Schnorr signature
 /// with the secret key.
@@ -48,6 +58,18 @@ pub struct NizkOfSecretKey {
 impl NizkOfSecretKey {
     /// Prove knowledge of a secret key.
     pub fn prove(
+        index: &u32,
+        secret_key: &Scalar,
+        public_key: &RistrettoPoint,
+        context_string: &str,
+        csprng: impl Rng + CryptoRng,
+    ) -> Self
+    {
+        Self::prove_with_hash::<Sha512>(index, secret_key, public_key, context_string, csprng)
+    }
+
+    /// Prove knowledge of a secret key, using the hash function `H`.
+    pub fn prove_with_hash<H: DkgHash>(
         index: &u32,
         secret_key: &Scalar,
         public_key: &RistrettoPoint,
@@ -58,7 +80,7 @@ impl NizkOfSecretKey {
         let k: Scalar = Scalar::random(&mut csprng);
         let M: RistrettoPoint = &k * &RISTRETTO_BASEPOINT_TABLE;
 
-        let mut hram = Sha512::new();
+        let mut hram = H::new();
 
         hram.update(index.to_be_bytes());
         hram.update(context_string);
@@ -73,9 +95,20 @@ impl NizkOfSecretKey {
 
     /// Verify that the prover does indeed know the secret key.
     pub fn verify(&self, index: &u32, public_key: &RistrettoPoint, context_string: &str) -> Result<(), Error> {
+        self.verify_with_hash::<Sha512>(index, public_key, context_string)
+    }
+
+    /// Verify that the prover does indeed know the secret key, using the hash
+    /// function `H` with which the proof was created.
+    pub fn verify_with_hash<H: DkgHash>(
+        &self,
+        index: &u32,
+        public_key: &RistrettoPoint,
+        context_string: &str,
+    ) -> Result<(), Error> {
         let M_prime: RistrettoPoint = (&RISTRETTO_BASEPOINT_TABLE * &self.r) + (public_key * -&self.s);
 
-        let mut hram = Sha512::new();
+        let mut hram = H::new();
 
         hram.update(index.to_be_bytes());
         hram.update(context_string);
@@ -118,6 +151,117 @@ impl NizkOfSecretKey {
     }
 }
 
+/// A proof of knowledge of the two secret nonces \\((d\_i, e\_i)\\) behind a
+/// published commitment share \\((D\_i, E\_i)\\), attached by a signer to its
+/// partial signature so that an aggregator can check that the commitment
+/// share it published is the one it used.
+///
+/// Both nonces are proven with a single challenge
+/// \\( s = \mathcal{H}(i, D\_i, E\_i, M\_D, M\_E, m) \\), where
+/// \\(M\_D = g^{k\_D}\\), \\(M\_E = g^{k\_E}\\) and \\(m\\) is the hash of the
+/// signed message, and \\(r\_D = k\_D + d\_i \cdot s\\),
+/// \\(r\_E = k\_E + e\_i \cdot s\\).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NizkOfNonces {
+    /// The challenge.
+    s: Scalar,
+    /// The response for the hiding nonce.
+    r_hiding: Scalar,
+    /// The response for the binding nonce.
+    r_binding: Scalar,
+}
+
+impl NizkOfNonces {
+    fn challenge(
+        index: &u32,
+        commitment: &(RistrettoPoint, RistrettoPoint),
+        M_hiding: &RistrettoPoint,
+        M_binding: &RistrettoPoint,
+        message_hash: &[u8; 64],
+    ) -> Scalar {
+        let mut h = Sha512::new();
+
+        h.update(b"ICE-FROST-nonces");
+        h.update(index.to_be_bytes());
+        h.update(commitment.0.compress().as_bytes());
+        h.update(commitment.1.compress().as_bytes());
+        h.update(M_hiding.compress().as_bytes());
+        h.update(M_binding.compress().as_bytes());
+        h.update(&message_hash[..]);
+
+        Scalar::from_hash(h)
+    }
+
+    /// Prove knowledge of the `hiding_nonce` and the `binding_nonce` behind the
+    /// published `commitment` of the signer with the given `index`, for the
+    /// message with the given `message_hash`.
+    pub fn prove(
+        index: &u32,
+        hiding_nonce: &Scalar,
+        binding_nonce: &Scalar,
+        commitment: &(RistrettoPoint, RistrettoPoint),
+        message_hash: &[u8; 64],
+        mut csprng: impl Rng + CryptoRng,
+    ) -> Self
+    {
+        let k_hiding: Scalar = Scalar::random(&mut csprng);
+        let k_binding: Scalar = Scalar::random(&mut csprng);
+        let M_hiding: RistrettoPoint = &k_hiding * &RISTRETTO_BASEPOINT_TABLE;
+        let M_binding: RistrettoPoint = &k_binding * &RISTRETTO_BASEPOINT_TABLE;
+
+        let s = Self::challenge(index, commitment, &M_hiding, &M_binding, message_hash);
+
+        NizkOfNonces {
+            s,
+            r_hiding: k_hiding + (hiding_nonce * s),
+            r_binding: k_binding + (binding_nonce * s),
+        }
+    }
+
+    /// Verify that the prover does indeed know the nonces behind the
+    /// `commitment`.
+    pub fn verify(
+        &self,
+        index: &u32,
+        commitment: &(RistrettoPoint, RistrettoPoint),
+        message_hash: &[u8; 64],
+    ) -> Result<(), Error> {
+        let M_hiding: RistrettoPoint = (&RISTRETTO_BASEPOINT_TABLE * &self.r_hiding) + (commitment.0 * -&self.s);
+        let M_binding: RistrettoPoint = (&RISTRETTO_BASEPOINT_TABLE * &self.r_binding) + (commitment.1 * -&self.s);
+
+        if self.s == Self::challenge(index, commitment, &M_hiding, &M_binding, message_hash) {
+            return Ok(());
+        }
+
+        Err(Error::InvalidProofOfKnowledge)
+    }
+
+    /// Serialise this proof to an array of bytes
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut res = [0u8; 96];
+        res[0..32].copy_from_slice(&self.s.to_bytes());
+        res[32..64].copy_from_slice(&self.r_hiding.to_bytes());
+        res[64..96].copy_from_slice(&self.r_binding.to_bytes());
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a NiZK proof
+    pub fn from_bytes(bytes: &[u8; 96]) -> Result<NizkOfNonces, Error> {
+        let mut scalars = [Scalar::zero(); 3];
+
+        for (scalar, chunk) in scalars.iter_mut().zip(bytes.chunks(32)) {
+            *scalar = Scalar::from_canonical_bytes(
+                chunk
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?
+            ).ok_or(Error::SerialisationError)?;
+        }
+
+        Ok(NizkOfNonces { s: scalars[0], r_hiding: scalars[1], r_binding: scalars[2] })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -25,6 +25,47 @@ pub struct Parameters {
 }
 
 impl Parameters {
+    /// The largest participant index allowed for these parameters, which is
+    /// currently equal to `n`.
+    ///
+    /// Participant indices are used as evaluation points of the secret
+    /// polynomials, and must lie in \\([1, max\\_index]\\).
+    pub fn max_index(&self) -> u32 {
+        self.n
+    }
+
+    /// Whether `index` is a valid participant index for these parameters.
+    pub fn is_valid_index(&self, index: u32) -> bool {
+        index != 0 && index <= self.max_index()
+    }
+
+    /// The ratio `t / n` of participants required to sign.
+    pub fn threshold_ratio(&self) -> f64 {
+        self.t as f64 / self.n as f64
+    }
+
+    /// The number of participants, `n - t`, which may be unavailable while
+    /// the remaining ones can still produce a signature.
+    pub fn fault_tolerance(&self) -> u32 {
+        self.n.saturating_sub(self.t)
+    }
+
+    /// Whether signing requires a strict majority of the participants,
+    /// i.e. whether `t > n / 2`.
+    pub fn is_honest_majority(&self) -> bool {
+        2 * self.t as u64 > self.n as u64
+    }
+
+    /// Check that these parameters are consistent, i.e. that the threshold
+    /// lies in \\([1, n]\\).
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.t == 0 || self.t > self.n {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(())
+    }
+
     /// Serialise these parameters as an array of bytes
     pub fn to_bytes(&self) -> [u8; 8] {
         let mut res = [0u8; 8];
@@ -47,7 +88,23 @@ impl Parameters {
                 .map_err(|_| Error::SerialisationError)?,
         );
 
-        Ok(Parameters { n, t })
+        // Reject inconsistent parameters, which could only come from corrupted bytes.
+        let parameters = Parameters { n, t };
+        parameters.validate().map_err(|_| Error::SerialisationError)?;
+
+        Ok(parameters)
+    }
+}
+
+/// Parameters for a 2-out-of-3 threshold scheme.
+///
+/// This is only meant for prototyping, examples and tests, and is gated
+/// behind the `prototype` feature: production deployments should always
+/// choose their parameters explicitly.
+#[cfg(feature = "prototype")]
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters { n: 3, t: 2 }
     }
 }
 
@@ -61,11 +118,73 @@ mod test {
         let mut rng = OsRng;
 
         for _ in 0..100 {
-            let params = Parameters { n: rng.next_u32(), t: rng.next_u32() };
+            let n = rng.next_u32().max(1);
+            let params = Parameters { n, t: rng.next_u32() % n + 1 };
             let bytes = params.to_bytes();
             assert!(Parameters::from_bytes(&bytes).is_ok());
             assert_eq!(params, Parameters::from_bytes(&bytes).unwrap());
 
         }
     }
+
+    #[test]
+    fn test_inconsistent_parameters_serialisation() {
+        let bytes = Parameters { n: 2, t: 3 }.to_bytes();
+        assert_eq!(Parameters::from_bytes(&bytes), Err(Error::SerialisationError));
+
+        let bytes = Parameters { n: 2, t: 0 }.to_bytes();
+        assert_eq!(Parameters::from_bytes(&bytes), Err(Error::SerialisationError));
+
+        let bytes = Parameters { n: 2, t: 2 }.to_bytes();
+        assert!(Parameters::from_bytes(&bytes).is_ok());
+    }
+
+    #[test]
+    fn validate() {
+        assert!(Parameters { n: 3, t: 2 }.validate().is_ok());
+        assert!(Parameters { n: 1, t: 1 }.validate().is_ok());
+        assert_eq!(Parameters { n: 3, t: 0 }.validate(), Err(Error::InvalidParameters));
+        assert_eq!(Parameters { n: 3, t: 4 }.validate(), Err(Error::InvalidParameters));
+    }
+
+    #[cfg(feature = "prototype")]
+    #[test]
+    fn default_parameters() {
+        let params = Parameters::default();
+
+        assert_eq!(params, Parameters { n: 3, t: 2 });
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn max_index() {
+        let params = Parameters { n: 3, t: 2 };
+
+        assert_eq!(params.max_index(), 3);
+        assert!(!params.is_valid_index(0));
+        assert!(params.is_valid_index(1));
+        assert!(params.is_valid_index(3));
+        assert!(!params.is_valid_index(4));
+        assert!(!params.is_valid_index(u32::MAX));
+    }
+
+    #[test]
+    fn threshold_predicates() {
+        let params = Parameters { n: 5, t: 3 };
+        assert_eq!(params.fault_tolerance(), 2);
+        assert!(params.is_honest_majority());
+        assert_eq!(params.threshold_ratio(), 0.6);
+
+        let params = Parameters { n: 4, t: 2 };
+        assert_eq!(params.fault_tolerance(), 2);
+        assert!(!params.is_honest_majority());
+
+        let params = Parameters { n: 1, t: 1 };
+        assert_eq!(params.fault_tolerance(), 0);
+        assert!(params.is_honest_majority());
+
+        let params = Parameters { n: u32::MAX, t: u32::MAX / 2 + 1 };
+        assert_eq!(params.fault_tolerance(), u32::MAX / 2);
+        assert!(params.is_honest_majority());
+    }
 }
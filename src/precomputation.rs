@@ -45,12 +45,13 @@ impl NoncePair {
     }
 }
 
-impl From<NoncePair> for CommitmentShare {
-    fn from(other: NoncePair) -> CommitmentShare {
+impl From<(u32, NoncePair)> for CommitmentShare {
+    fn from((participant_index, other): (u32, NoncePair)) -> CommitmentShare {
         let x = &RISTRETTO_BASEPOINT_TABLE * &other.0;
         let y = &RISTRETTO_BASEPOINT_TABLE * &other.1;
 
         CommitmentShare {
+            participant_index,
             hiding: Commitment {
                 nonce: other.0,
                 sealed: x,
@@ -59,6 +60,7 @@ impl From<NoncePair> for CommitmentShare {
                 nonce: other.1,
                 sealed: y,
             },
+            creation_epoch: None,
         }
     }
 }
@@ -123,6 +125,8 @@ impl Commitment {
 #[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
 #[zeroize(drop)]
 pub struct CommitmentShare {
+    /// The index of the participant owning this commitment share.
+    pub(crate) participant_index: u32,
     /// The hiding commitment.
     ///
     /// This is \\((d\_{ij}, D\_{ij})\\) in the paper.
@@ -131,12 +135,16 @@ pub struct CommitmentShare {
     ///
     /// This is \\((e\_{ij}, E\_{ij})\\) in the paper.
     pub(crate) binding: Commitment,
+    /// The epoch at which this commitment share was created, if any.
+    pub(crate) creation_epoch: Option<u64>,
 }
 
 /// Test equality in constant-time.
 impl ConstantTimeEq for CommitmentShare {
     fn ct_eq(&self, other: &CommitmentShare) -> Choice {
-        self.hiding.ct_eq(&other.hiding) & self.binding.ct_eq(&other.binding)
+        self.participant_index.ct_eq(&other.participant_index) &
+            self.hiding.ct_eq(&other.hiding) &
+            self.binding.ct_eq(&other.binding)
     }
 }
 
@@ -146,34 +154,87 @@ impl CommitmentShare {
         (self.hiding.sealed, self.binding.sealed)
     }
 
+    /// Get the index of the participant owning this [`CommitmentShare`].
+    pub fn participant_index(&self) -> u32 {
+        self.participant_index
+    }
+
+    /// Get the epoch at which this [`CommitmentShare`] was created, if it
+    /// has been set.
+    pub fn creation_epoch(&self) -> Option<u64> {
+        self.creation_epoch
+    }
+
+    /// Set the epoch at which this [`CommitmentShare`] was created.
+    ///
+    /// The epoch is an application-defined counter, such as a UNIX timestamp,
+    /// which is only compared against the cutoff given to
+    /// [`SecretCommitmentShareList::expire_older_than`].
+    pub fn set_creation_epoch(&mut self, epoch: u64) {
+        self.creation_epoch = Some(epoch);
+    }
+
     /// Serialise this commitment share to an array of bytes
-    pub fn to_bytes(&self) -> [u8; 128] {
-        let mut res = [0u8; 128];
-        res[0..64].copy_from_slice(&self.hiding.to_bytes());
-        res[64..128].copy_from_slice(&self.binding.to_bytes());
+    ///
+    /// The encoding starts with the owning participant index, which earlier
+    /// versions did not store: their 128-byte encodings are read with
+    /// [`CommitmentShare::from_legacy_bytes`].
+    ///
+    /// The creation epoch is not part of this encoding, but is kept by
+    /// [`SecretCommitmentShareList::to_bytes`].
+    pub fn to_bytes(&self) -> [u8; 132] {
+        let mut res = [0u8; 132];
+        res[0..4].copy_from_slice(&self.participant_index.to_le_bytes());
+        res[4..68].copy_from_slice(&self.hiding.to_bytes());
+        res[68..132].copy_from_slice(&self.binding.to_bytes());
 
         res
     }
 
     /// Deserialise this array of bytes to a `CommitmentShare`
-    pub fn from_bytes(bytes: &[u8; 128]) -> Result<CommitmentShare, Error> {
-        let hiding = Commitment::from_bytes(&bytes[0..64]
+    pub fn from_bytes(bytes: &[u8; 132]) -> Result<CommitmentShare, Error> {
+        let participant_index = u32::from_le_bytes(
+            bytes[0..4]
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        );
+
+        let hiding = Commitment::from_bytes(&bytes[4..68]
             .try_into()
             .map_err(|_| Error::SerialisationError)?
         )?;
 
-        let binding = Commitment::from_bytes(&bytes[64..128]
+        let binding = Commitment::from_bytes(&bytes[68..132]
             .try_into()
             .map_err(|_| Error::SerialisationError)?
         )?;
 
-        Ok(CommitmentShare { hiding, binding })
+        Ok(CommitmentShare { participant_index, hiding, binding, creation_epoch: None })
+    }
+
+    /// Deserialise the 128-byte encoding of a `CommitmentShare` written by
+    /// versions which did not store the owning participant index, assigning
+    /// it to the participant with index `participant_index`.
+    pub fn from_legacy_bytes(bytes: &[u8; 128], participant_index: u32) -> Result<CommitmentShare, Error> {
+        let mut array = [0u8; 132];
+        array[0..4].copy_from_slice(&participant_index.to_le_bytes());
+        array[4..132].copy_from_slice(bytes);
+
+        let share = CommitmentShare::from_bytes(&array);
+        array.zeroize();
+
+        share
     }
 }
 
 /// A secret commitment share list, containing the revealed nonces for the
 /// hiding and binding commitments.
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// The whole list is zeroized when dropped: the `Zeroize` implementation of
+/// `Vec` also wipes the spare capacity of its backing vector, and not only its
+/// commitment shares.
+#[derive(Clone, Debug, Eq, PartialEq, Zeroize)]
+#[zeroize(drop)]
 pub struct SecretCommitmentShareList {
     /// The secret commitment shares.
     pub commitments: Vec<CommitmentShare>,
@@ -181,8 +242,12 @@ pub struct SecretCommitmentShareList {
 
 impl SecretCommitmentShareList {
     /// Serialise this secret commitment share list to a Vec of bytes
+    ///
+    /// If any of the commitment shares has a creation epoch, the encoding is
+    /// followed by the optional epoch of each commitment share, as a flag byte
+    /// and an 8-byte little-endian integer.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut res = Vec::with_capacity(8 + 128 * self.commitments.len());
+        let mut res = Vec::with_capacity(8 + 141 * self.commitments.len());
 
         let len = self.commitments.len();
         res.extend_from_slice(&TryInto::<u32>::try_into(len).unwrap().to_le_bytes());
@@ -190,6 +255,13 @@ impl SecretCommitmentShareList {
             res.extend_from_slice(&self.commitments[i].to_bytes());
         }
 
+        if self.commitments.iter().any(|share| share.creation_epoch.is_some()) {
+            for share in self.commitments.iter() {
+                res.push(share.creation_epoch.is_some() as u8);
+                res.extend_from_slice(&share.creation_epoch.unwrap_or(0).to_le_bytes());
+            }
+        }
+
         res
     }
 
@@ -200,16 +272,77 @@ impl SecretCommitmentShareList {
                 .try_into()
                 .map_err(|_| Error::SerialisationError)?,
         );
-        let mut commitments: Vec<CommitmentShare> = Vec::with_capacity(len as usize);
+        // Dropping the list on the error path zeroizes the shares read so far.
+        let mut list = SecretCommitmentShareList { commitments: Vec::with_capacity(len as usize) };
         let mut index_slice = 4;
-        let mut array = [0u8; 128];
+        let mut array = [0u8; 132];
 
         for _ in 0..len {
-            array.copy_from_slice(&bytes[index_slice..index_slice + 128]);
-            commitments.push(CommitmentShare::from_bytes(&array)?);
-            index_slice += 128;
+            array.copy_from_slice(&bytes[index_slice..index_slice + 132]);
+            let share = CommitmentShare::from_bytes(&array);
+            array.zeroize();
+
+            list.commitments.push(share?);
+            index_slice += 132;
+        }
+
+        if bytes.len() > index_slice {
+            if bytes.len() - index_slice != 9 * len as usize {
+                return Err(Error::SerialisationError);
+            }
+
+            for share in list.commitments.iter_mut() {
+                let epoch = u64::from_le_bytes(
+                    bytes[index_slice + 1..index_slice + 9]
+                        .try_into()
+                        .map_err(|_| Error::SerialisationError)?,
+                );
+                share.creation_epoch = match bytes[index_slice] {
+                    0 => None,
+                    1 => Some(epoch),
+                    _ => return Err(Error::SerialisationError),
+                };
+                index_slice += 9;
+            }
+        }
+
+        Ok(list)
+    }
+
+    /// Deserialise the encoding of a `SecretCommitmentShareList` written by
+    /// versions which did not store the owning participant index in each
+    /// commitment share, assigning them to the participant with index
+    /// `participant_index`.
+    ///
+    /// This encoding is the number of commitment shares, as a 4-byte
+    /// little-endian integer, followed by their 128-byte legacy encodings.
+    pub fn from_legacy_bytes(bytes: &[u8], participant_index: u32) -> Result<SecretCommitmentShareList, Error> {
+        let len = u32::from_le_bytes(
+            bytes
+                .get(0..4)
+                .ok_or(Error::SerialisationError)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?,
+        ) as usize;
+
+        let expected_len = len.checked_mul(128).and_then(|l| l.checked_add(4)).ok_or(Error::SerialisationError)?;
+        if bytes.len() != expected_len {
+            return Err(Error::SerialisationError);
+        }
+
+        // Dropping the list on the error path zeroizes the shares read so far.
+        let mut list = SecretCommitmentShareList { commitments: Vec::with_capacity(len) };
+        let mut array = [0u8; 128];
+
+        for chunk in bytes[4..].chunks_exact(128) {
+            array.copy_from_slice(chunk);
+            let share = CommitmentShare::from_legacy_bytes(&array, participant_index);
+            array.zeroize();
+
+            list.commitments.push(share?);
         }
-        Ok(SecretCommitmentShareList { commitments })
+
+        Ok(list)
     }
 }
 
@@ -218,6 +351,9 @@ impl SecretCommitmentShareList {
 ///
 /// This should be published somewhere before the signing protocol takes place
 /// for the other signing participants to obtain.
+///
+/// Although it only holds public points, it is zeroized when dropped, as the
+/// secret list is, for applications wiping all their session-scoped data.
 #[derive(Debug, Eq, PartialEq)]
 pub struct PublicCommitmentShareList {
     /// The participant's index.
@@ -226,6 +362,23 @@ pub struct PublicCommitmentShareList {
     pub commitments: Vec<(RistrettoPoint, RistrettoPoint)>,
 }
 
+impl Zeroize for PublicCommitmentShareList {
+    fn zeroize(&mut self) {
+        self.participant_index.zeroize();
+        for (hiding, binding) in self.commitments.iter_mut() {
+            hiding.zeroize();
+            binding.zeroize();
+        }
+        self.commitments.clear();
+    }
+}
+
+impl Drop for PublicCommitmentShareList {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 impl PublicCommitmentShareList {
     /// Serialise this commitment share list to a Vec of bytes
     pub fn to_bytes(&self) -> Vec<u8> {
@@ -293,7 +446,7 @@ pub fn generate_commitment_share_lists(
     let mut commitments: Vec<CommitmentShare> = Vec::with_capacity(number_of_shares);
 
     for _ in 0..number_of_shares {
-        commitments.push(CommitmentShare::from(NoncePair::new(&mut csprng)));
+        commitments.push(CommitmentShare::from((participant_index, NoncePair::new(&mut csprng))));
     }
 
     let mut published: Vec<(RistrettoPoint, RistrettoPoint)> = Vec::with_capacity(number_of_shares);
@@ -329,6 +482,44 @@ impl SecretCommitmentShareList {
         }
         drop(share);
     }
+
+    /// Drop all the [`CommitmentShare`]s from our secret commitment share list,
+    /// e.g. when a signing session is cancelled, and ensure that they are
+    /// wiped from memory.
+    pub fn clear(&mut self) {
+        self.wipe();
+        self.commitments.clear();
+    }
+
+    /// Zeroize the nonces and commitments of every [`CommitmentShare`] in
+    /// place, leaving the list of the same length.
+    pub(crate) fn wipe(&mut self) {
+        for share in self.commitments.iter_mut() {
+            share.zeroize();
+        }
+    }
+
+    /// Drop the [`CommitmentShare`]s created before the given `epoch` from
+    /// our secret commitment share list, and ensure that they are wiped from
+    /// memory.
+    ///
+    /// Commitment shares without a creation epoch are kept, since their age
+    /// is unknown.
+    ///
+    /// # Returns
+    ///
+    /// The number of commitment shares which were dropped.
+    pub fn expire_older_than(&mut self, epoch: u64) -> usize {
+        let len = self.commitments.len();
+
+        // Dropping a commitment share zeroizes its nonces.
+        self.commitments.retain(|share| match share.creation_epoch {
+            Some(creation_epoch) => creation_epoch >= epoch,
+            None => true,
+        });
+
+        len - self.commitments.len()
+    }
 }
 
 #[cfg(test)]
@@ -343,7 +534,7 @@ mod test {
 
     #[test]
     fn nonce_pair_into_commitment_share() {
-        let _commitment_share: CommitmentShare = NoncePair::new(&mut OsRng).into();
+        let _commitment_share: CommitmentShare = (1, NoncePair::new(&mut OsRng)).into();
     }
 
     #[test]
@@ -365,7 +556,7 @@ mod test {
             let sealed = &nonce * &curve25519_dalek::constants::RISTRETTO_BASEPOINT_TABLE;
             let binding = Commitment { nonce, sealed };
             let hiding = binding.clone();
-            let commitment_share = CommitmentShare { binding, hiding };
+            let commitment_share = CommitmentShare { participant_index: 1, binding, hiding, creation_epoch: None };
 
             let bytes = commitment_share.to_bytes();
             assert!(CommitmentShare::from_bytes(&bytes).is_ok());
@@ -376,7 +567,7 @@ mod test {
         let bytes = [255u8; 64];
         assert!(Commitment::from_bytes(&bytes).is_err());
 
-        let bytes = [255u8; 128];
+        let bytes = [255u8; 132];
         assert!(CommitmentShare::from_bytes(&bytes).is_err());
     }
 
@@ -400,4 +591,99 @@ mod test {
 
         assert!(secret_share_list.commitments.len() == 7);
     }
+
+    #[test]
+    fn expire_stale_commitment_shares() {
+        let (_public_share_list, mut secret_share_list) = generate_commitment_share_lists(&mut OsRng, 3, 5);
+
+        for (share, epoch) in secret_share_list.commitments.iter_mut().zip([10u64, 20, 30, 40].iter()) {
+            share.set_creation_epoch(*epoch);
+        }
+        let kept: Vec<CommitmentShare> = secret_share_list.commitments[2..].to_vec();
+
+        // The epochs survive serialisation.
+        let bytes = secret_share_list.to_bytes();
+        assert_eq!(SecretCommitmentShareList::from_bytes(&bytes).unwrap(), secret_share_list);
+        assert!(SecretCommitmentShareList::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        assert_eq!(secret_share_list.expire_older_than(5), 0);
+        assert_eq!(secret_share_list.expire_older_than(30), 2);
+
+        // The share created at the cutoff and the one without an epoch are kept.
+        assert_eq!(secret_share_list.commitments, kept);
+        assert_eq!(secret_share_list.commitments[2].creation_epoch(), None);
+    }
+
+    #[test]
+    fn clear_commitment_shares() {
+        let (_public_share_list, mut secret_share_list) = generate_commitment_share_lists(&mut OsRng, 3, 4);
+
+        // Wiping in place lets us observe the zeroized nonces before they are dropped.
+        let mut wiped = secret_share_list.clone();
+        wiped.wipe();
+        assert_eq!(wiped.commitments.len(), 4);
+        for share in wiped.commitments.iter() {
+            assert_eq!(share.hiding.nonce, Scalar::zero());
+            assert_eq!(share.binding.nonce, Scalar::zero());
+            assert_eq!(share.publish(), (RistrettoPoint::identity(), RistrettoPoint::identity()));
+        }
+
+        secret_share_list.clear();
+        assert!(secret_share_list.commitments.is_empty());
+    }
+
+    #[test]
+    fn commitment_share_lists_zeroize() {
+        let (public_share_list, secret_share_list) = generate_commitment_share_lists(&mut OsRng, 3, 4);
+
+        // Each commitment share wipes its nonces.
+        let mut share = secret_share_list.commitments[0].clone();
+        share.zeroize();
+        assert_eq!(share.hiding.nonce, Scalar::zero());
+        assert_eq!(share.binding.nonce, Scalar::zero());
+        assert_eq!(share.hiding.sealed, RistrettoPoint::identity());
+        assert_eq!(share.binding.sealed, RistrettoPoint::identity());
+
+        // The lists wipe and drop their shares, the spare capacity of their
+        // backing vector being wiped by the `Zeroize` implementation of `Vec`.
+        let mut wiped = secret_share_list.clone();
+        wiped.zeroize();
+        assert!(wiped.commitments.is_empty());
+
+        let mut public_share_list = public_share_list;
+        public_share_list.zeroize();
+        assert_eq!(public_share_list.participant_index, 0);
+        assert!(public_share_list.commitments.is_empty());
+
+        // An invalid encoding is rejected after some shares were read.
+        let mut bytes = secret_share_list.to_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] = 0xff;
+        assert!(SecretCommitmentShareList::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn legacy_serialisation() {
+        let (_, secret_comshares) = generate_commitment_share_lists(&mut OsRng, 2, 3);
+
+        // The encoding written before commitment shares stored their owner.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        for share in secret_comshares.commitments.iter() {
+            bytes.extend_from_slice(&share.to_bytes()[4..]);
+        }
+
+        let legacy_array: [u8; 128] = bytes[4..132].try_into().unwrap();
+        assert_eq!(CommitmentShare::from_legacy_bytes(&legacy_array, 2).unwrap(), secret_comshares.commitments[0]);
+
+        let deserialised = SecretCommitmentShareList::from_legacy_bytes(&bytes, 2).unwrap();
+        assert_eq!(deserialised.commitments, secret_comshares.commitments);
+
+        // The shares are assigned to the given participant.
+        let deserialised = SecretCommitmentShareList::from_legacy_bytes(&bytes, 3).unwrap();
+        assert!(deserialised.commitments.iter().all(|share| share.participant_index() == 3));
+
+        assert!(SecretCommitmentShareList::from_legacy_bytes(&bytes[..bytes.len() - 1], 2).is_err());
+        assert!(SecretCommitmentShareList::from_legacy_bytes(&secret_comshares.to_bytes(), 2).is_err());
+    }
 }
@@ -43,14 +43,23 @@ use curve25519_dalek::constants::RISTRETTO_BASEPOINT_TABLE;
 use curve25519_dalek::ristretto::CompressedRistretto;
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+
+use rand::CryptoRng;
+use rand::Rng;
 
 use sha2::Digest;
 use sha2::Sha512;
 
+use subtle::ConditionallySelectable;
+use subtle::ConstantTimeEq;
+
 use crate::keygen::Error;
 use crate::keygen::GroupKey;
 use crate::keygen::IndividualPublicKey;
+use crate::nizk::NizkOfNonces;
 use crate::parameters::Parameters;
+use crate::precomputation::PublicCommitmentShareList;
 use crate::precomputation::SecretCommitmentShareList;
 
 pub use crate::keygen::SecretKey;
@@ -64,6 +73,18 @@ pub enum SignatureError {
     InvalidBindingFactor,
     /// Invalid signature
     InvalidSignature,
+    /// The commitment share belongs to another participant
+    MismatchedCommitmentShare,
+    /// The partial signature of the participant with this index is invalid
+    InvalidPartialSignature(u32),
+    /// The participant with this index has already been included
+    DuplicateSigner(u32),
+    /// The participant index is zero or above the parameters' `max_index`
+    InvalidParticipantIndex(u32),
+    /// The participating signers are not the members of the signing quorum
+    MismatchedSigningQuorum,
+    /// The number of distinct available signers is below the threshold
+    NotEnoughSigners(usize, u32),
     /// Custom error
     Custom(String),
 }
@@ -80,6 +101,24 @@ impl fmt::Display for SignatureError {
             SignatureError::InvalidSignature => {
                 write!(f, "The threshold signature is not correct.")
             }
+            SignatureError::MismatchedCommitmentShare => {
+                write!(f, "The commitment share belongs to another participant.")
+            }
+            SignatureError::InvalidPartialSignature(index) => {
+                write!(f, "The partial signature of participant {} is invalid.", index)
+            }
+            SignatureError::DuplicateSigner(index) => {
+                write!(f, "The participant {} has already been included.", index)
+            }
+            SignatureError::InvalidParticipantIndex(index) => {
+                write!(f, "The participant index {} is out of range.", index)
+            }
+            SignatureError::MismatchedSigningQuorum => {
+                write!(f, "The signers do not match the signing quorum.")
+            }
+            SignatureError::NotEnoughSigners(available, threshold) => {
+                write!(f, "Only {} signers are available for a threshold of {}.", available, threshold)
+            }
             SignatureError::Custom(string) => {
                 write!(f, "{:?}", string)
             },
@@ -129,37 +168,148 @@ impl PartialEq for Signer {
 pub struct PartialThresholdSignature {
     pub(crate) index: u32,
     pub(crate) z: Scalar,
+    /// An optional proof that the signer knows the nonces behind the
+    /// commitment share it published, see
+    /// [`SecretKey::sign_partial_with_nonce_proof`].
+    pub(crate) nonce_proof: Option<NizkOfNonces>,
 }
 
 impl PartialThresholdSignature {
-    /// Serialize this partial threshold signature to an array of 36 bytes.
-    pub fn to_bytes(&self) -> [u8; 36] {
-        let mut bytes = [0u8; 36];
-
-        bytes[..4].copy_from_slice(&self.index.to_le_bytes());
-        bytes[4..].copy_from_slice(self.z.as_bytes());
+    /// Serialize this partial threshold signature to a Vec of bytes.
+    ///
+    /// The 36 bytes of the index and the signature share are followed by a
+    /// flag byte and, if the flag is set, the 96 bytes of the [`NizkOfNonces`]
+    /// carried by this partial signature.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(36 + 1 + 96);
+
+        bytes.extend_from_slice(&self.index.to_le_bytes());
+        bytes.extend_from_slice(self.z.as_bytes());
+
+        match &self.nonce_proof {
+            Some(nonce_proof) => {
+                bytes.push(1u8);
+                bytes.extend_from_slice(&nonce_proof.to_bytes());
+            },
+            None => bytes.push(0u8),
+        }
 
         bytes
     }
 
-    /// Attempt to deserialize a partial threshold signature from an array of 36 bytes.
-    pub fn from_bytes(bytes: &[u8; 36]) -> Result<PartialThresholdSignature, Error> {
+    /// Attempt to deserialize a partial threshold signature from a slice of bytes.
+    ///
+    /// The flag byte of the [`NizkOfNonces`] is optional, so that the 36-byte
+    /// encoding of earlier versions can still be read.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PartialThresholdSignature, Error> {
         let index = u32::from_le_bytes(
-            bytes[0..4]
+            bytes
+                .get(0..4)
+                .ok_or(Error::SerialisationError)?
                 .try_into()
                 .map_err(|_| Error::SerialisationError)?
         );
 
-        let z = Scalar::from_canonical_bytes(bytes[4..36]
+        let z = Scalar::from_canonical_bytes(bytes
+            .get(4..36)
+            .ok_or(Error::SerialisationError)?
             .try_into()
             .map_err(|_| Error::SerialisationError)?
         ).ok_or(Error::SerialisationError)?;
 
-        Ok(PartialThresholdSignature { index, z })
+        let nonce_proof = match bytes.get(36) {
+            None if bytes.len() == 36 => None,
+            Some(0u8) if bytes.len() == 37 => None,
+            Some(1u8) if bytes.len() == 37 + 96 => Some(NizkOfNonces::from_bytes(
+                &bytes[37..]
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?
+            )?),
+            _ => return Err(Error::SerialisationError),
+        };
+
+        Ok(PartialThresholdSignature { index, z, nonce_proof })
+    }
+
+    /// Get the proof of correct nonce usage carried by this partial signature, if any.
+    pub fn nonce_proof(&self) -> Option<&NizkOfNonces> {
+        self.nonce_proof.as_ref()
+    }
+
+    /// Check this partial signature against its signer's public key, with the
+    /// verification equation \\(z\_i G = R\_i + \lambda\_i c Y\_i\\),
+    /// independently of any [`SignatureAggregator`].
+    ///
+    /// # Inputs
+    ///
+    /// * The list of all the currently participating [`Signer`]s, which holds
+    ///   the commitment share published by this partial signature's signer and
+    ///   must be the members of the `signing_quorum`,
+    /// * The [`IndividualPublicKey`] of the signer,
+    /// * The `signing_quorum`, holding the Lagrange coefficient of the signer,
+    /// * The `message_hash` which was signed, as computed by [`compute_message_hash`], and
+    /// * The public [`GroupKey`] for this group of signing participants.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Ok` if the partial signature is valid, otherwise a
+    /// [`SignatureError::InvalidPartialSignature`] naming the signer, or a
+    /// [`SignatureError::MismatchedSigningQuorum`] if the signers do not match
+    /// the quorum.
+    pub fn verify(
+        &self,
+        signers: &[Signer],
+        individual_public_key: &IndividualPublicKey,
+        signing_quorum: &SigningQuorum,
+        message_hash: &[u8; 64],
+        group_key: &GroupKey,
+    ) -> Result<(), SignatureError>
+    {
+        let invalid = SignatureError::InvalidPartialSignature(self.index);
+
+        let mut signer_indices: Vec<u32> = signers.iter().map(|signer| signer.participant_index).collect();
+        signer_indices.sort_unstable();
+        if signer_indices != signing_quorum.signers() {
+            return Err(SignatureError::MismatchedSigningQuorum);
+        }
+
+        if individual_public_key.index != self.index {
+            return Err(invalid);
+        }
+
+        let lambda = match signing_quorum.lagrange_coefficient(self.index) {
+            Some(lambda) => lambda,
+            None => return Err(invalid),
+        };
+
+        let (_, Rs) = compute_binding_factors_and_group_commitment(message_hash, signers);
+        let R: RistrettoPoint = Rs.values().sum();
+        let c = compute_challenge(message_hash, group_key, &R);
+
+        let R_i = match Rs.get(&self.index) {
+            Some(R_i) => R_i,
+            None => return Err(invalid),
+        };
+
+        if &RISTRETTO_BASEPOINT_TABLE * &self.z != R_i + (individual_public_key.share * (c * lambda)) {
+            return Err(invalid);
+        }
+
+        Ok(())
     }
 }
 
 /// A complete, aggregated threshold signature.
+///
+/// # Compatibility with ed25519
+///
+/// A threshold signature cannot be checked by a classic ed25519 verifier.
+/// The nonce commitment and the group key are encoded as Ristretto points,
+/// which a verifier decompresses differently from Edwards points, and the
+/// challenge is computed with a domain separator over the hash of the message.
+/// There is no torsion check to relax either, since the Ristretto group has
+/// prime order, so a legacy mode would require running the whole protocol
+/// over Edwards points instead.
 #[derive(Debug, Eq, PartialEq)]
 pub struct ThresholdSignature {
     pub(crate) R: RistrettoPoint,
@@ -267,6 +417,27 @@ pub fn compute_message_hash(context_string: &[u8], message: &[u8]) -> [u8; 64] {
     output
 }
 
+/// Compute the message hash to be signed, in the Ed25519ph style, for a
+/// message which was already hashed by the caller into a `prehash`, with
+/// any hash function of its choice.
+///
+/// The prehash is tagged, along with the `context_string`, so that a
+/// prehashed signature never verifies as a signature on the prehash itself
+/// with [`compute_message_hash`], nor the other way around.
+pub fn compute_prehashed_message_hash(context_string: &[u8], prehash: &[u8]) -> [u8; 64] {
+    let mut h = Sha512::new();
+
+    h.update(b"ICE-FROST-prehashed");
+    h.update((context_string.len() as u64).to_le_bytes());
+    h.update(context_string);
+    h.update(prehash);
+
+    let mut output = [0u8; 64];
+
+    output.copy_from_slice(&h.finalize());
+    output
+}
+
 fn compute_binding_factors_and_group_commitment(
     message_hash: &[u8; 64],
     signers: &[Signer],
@@ -318,6 +489,153 @@ fn compute_binding_factors_and_group_commitment(
     (binding_factors, Rs)
 }
 
+/// The context string of the ristretto255-SHA512 ciphersuite of the CFRG
+/// FROST draft, prepended to every hash computed by this ciphersuite.
+const CFRG_CONTEXT_STRING: &[u8] = b"FROST-RISTRETTO255-SHA512-v1";
+
+/// Compute the binding factors \\(\\rho_i\\) of a signing session, as
+/// specified by the ristretto255-SHA512 ciphersuite of the CFRG FROST draft.
+///
+/// The `commitments` are given as `(participant_index, hiding, binding)`
+/// triples, and are encoded in increasing order of participant index as
+/// required by the draft, regardless of the order in which they are given.
+///
+/// # Note
+///
+/// The signing protocol of this crate derives its own binding factors, and
+/// this function is provided for interoperability with implementations of
+/// the draft, for instance alongside [`compute_group_commitment`].
+pub fn compute_binding_factors(
+    message: &[u8],
+    commitments: &[(u32, RistrettoPoint, RistrettoPoint)],
+    group_public_key: &GroupKey,
+) -> BTreeMap<u32, Scalar>
+{
+    let mut commitments = commitments.to_vec();
+    commitments.sort_by_key(|(index, _, _)| *index);
+
+    // H4(msg)
+    let mut h = Sha512::new();
+    h.update(CFRG_CONTEXT_STRING);
+    h.update(b"msg");
+    h.update(message);
+    let message_hash = h.finalize();
+
+    // H5(encode_group_commitment_list(commitment_list))
+    let mut h = Sha512::new();
+    h.update(CFRG_CONTEXT_STRING);
+    h.update(b"com");
+    for (index, hiding, binding) in commitments.iter() {
+        h.update(Scalar::from(*index).as_bytes());
+        h.update(hiding.compress().as_bytes());
+        h.update(binding.compress().as_bytes());
+    }
+    let commitments_hash = h.finalize();
+
+    // H1(group_public_key || msg_hash || commitments_hash || identifier)
+    let mut prefix = Sha512::new();
+    prefix.update(CFRG_CONTEXT_STRING);
+    prefix.update(b"rho");
+    prefix.update(group_public_key.to_bytes());
+    prefix.update(message_hash);
+    prefix.update(commitments_hash);
+
+    let mut binding_factors: BTreeMap<u32, Scalar> = BTreeMap::new();
+
+    for (index, _, _) in commitments.iter() {
+        let mut h1 = prefix.clone();
+        h1.update(Scalar::from(*index).as_bytes());
+
+        binding_factors.insert(*index, Scalar::from_hash(h1));
+    }
+
+    binding_factors
+}
+
+/// Compute the group commitment \\(R\\) of a signing session, from each
+/// signer's `(participant_index, hiding, binding)` commitments and their
+/// respective binding factors \\(\\rho_i\\), as
+/// \\(R = \\sum_i D_i + \\rho_i \\cdot E_i\\).
+///
+/// # Note
+///
+/// The binding factors are used as given, and are expected to be the ones
+/// of the CFRG FROST draft, as computed by [`compute_binding_factors`]. The
+/// signing protocol of this crate derives other binding factors, hence the
+/// group commitment of its signing sessions is computed by
+/// [`compute_group_commitment_from_lists`] instead.
+///
+/// # Returns
+///
+/// The group commitment, or a `SignatureError::InvalidBindingFactor` if
+/// one of the signers has no corresponding binding factor.
+pub fn compute_group_commitment(
+    commitments: &[(u32, RistrettoPoint, RistrettoPoint)],
+    binding_factors: &BTreeMap<u32, Scalar>,
+) -> Result<RistrettoPoint, SignatureError>
+{
+    let mut R = RistrettoPoint::identity();
+
+    for (index, hiding, binding) in commitments.iter() {
+        let binding_factor = binding_factors.get(index).ok_or(SignatureError::InvalidBindingFactor)?;
+
+        R += hiding + (binding_factor * binding);
+    }
+
+    Ok(R)
+}
+
+/// Compute the group commitment \\(R\\) of a signing session of this crate,
+/// from the published commitment share lists of its signers only.
+///
+/// This lets an external coordinator, or a stateless relay, which holds no
+/// secret nonces, compute and broadcast the \\(R\\) the signers will derive
+/// when signing the `message_hash` with their commitments at position
+/// `commitment_share_index` in their lists. The lists may be given in any
+/// order.
+///
+/// # Note
+///
+/// The binding factors are derived as in [`SecretKey::sign`], and not as in
+/// the CFRG FROST draft: the group commitment of a session of the draft is
+/// computed by [`compute_group_commitment`], with the binding factors of
+/// [`compute_binding_factors`].
+///
+/// # Returns
+///
+/// The group commitment, a `SignatureError::MissingCommitmentShares` if one
+/// of the lists has no commitment at `commitment_share_index`, or a
+/// `SignatureError::DuplicateSigner` if two lists have the same participant
+/// index.
+pub fn compute_group_commitment_from_lists(
+    message_hash: &[u8; 64],
+    commitment_lists: &[PublicCommitmentShareList],
+    commitment_share_index: usize,
+) -> Result<RistrettoPoint, SignatureError>
+{
+    let mut signers: Vec<Signer> = Vec::with_capacity(commitment_lists.len());
+
+    for list in commitment_lists.iter() {
+        let published_commitment_share = *list
+            .commitments
+            .get(commitment_share_index)
+            .ok_or(SignatureError::MissingCommitmentShares)?;
+
+        signers.push(Signer { participant_index: list.participant_index, published_commitment_share });
+    }
+
+    // The signers hash the commitments sorted by participant index, as
+    // returned by SignatureAggregator::get_signers().
+    signers.sort();
+    if let Some(pair) = signers.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(SignatureError::DuplicateSigner(pair[0].participant_index));
+    }
+
+    let (_, Rs) = compute_binding_factors_and_group_commitment(message_hash, &signers);
+
+    Ok(Rs.values().sum())
+}
+
 fn compute_challenge(message_hash: &[u8; 64], group_key: &GroupKey, R: &RistrettoPoint) -> Scalar {
     let mut h2 = Sha512::new();
 
@@ -333,6 +651,9 @@ fn compute_challenge(message_hash: &[u8; 64], group_key: &GroupKey, R: &Ristrett
 
 /// Calculate using Lagrange's method the interpolation of a polynomial.
 ///
+/// For a single participant, both products are empty and the coefficient is
+/// exactly one, so that a 1-of-1 group needs no special-casing.
+///
 /// # Note
 ///
 /// isis stole this from Chelsea and Ian but they stole it from Lagrange, so who
@@ -342,6 +663,13 @@ pub(crate) fn calculate_lagrange_coefficients(
     all_participant_indices: &[u32],
 ) -> Result<Scalar, &'static str>
 {
+    debug_assert!(
+        all_participant_indices.contains(participant_index),
+        "The participant index {} is not among the indices {:?}",
+        participant_index,
+        all_participant_indices,
+    );
+
     let mut num = Scalar::one();
     let mut den = Scalar::one();
 
@@ -363,6 +691,113 @@ pub(crate) fn calculate_lagrange_coefficients(
     Ok(num * den.invert())
 }
 
+/// Calculate the Lagrange coefficients of every participant of
+/// `all_participant_indices` at once, for inspecting a whole interpolation.
+///
+/// # Returns
+///
+/// The pairs of participant index and Lagrange coefficient, in the order of
+/// `all_participant_indices`, or the error of the first coefficient which
+/// could not be computed.
+pub fn lagrange_coefficients_for(all_participant_indices: &[u32]) -> Result<Vec<(u32, Scalar)>, &'static str> {
+    all_participant_indices
+        .iter()
+        .map(|index| Ok((*index, calculate_lagrange_coefficients(index, all_participant_indices)?)))
+        .collect()
+}
+
+/// Calculate the same Lagrange coefficient as `calculate_lagrange_coefficients`,
+/// without branching on the values of the participant indices.
+///
+/// Every index of `all_participant_indices` contributes a factor to both the
+/// numerator and the denominator, the factor being conditionally replaced by
+/// one for the participant's own index. This should be preferred when the
+/// signing quorum itself is meant to remain private.
+pub fn calculate_lagrange_coefficients_constant_time(
+    participant_index: &u32,
+    all_participant_indices: &[u32],
+) -> Result<Scalar, &'static str>
+{
+    let mut num = Scalar::one();
+    let mut den = Scalar::one();
+
+    let mine = Scalar::from(*participant_index);
+
+    for j in all_participant_indices.iter() {
+        let is_mine = j.ct_eq(participant_index);
+        let s = Scalar::from(*j);
+
+        num *= Scalar::conditional_select(&s, &Scalar::one(), is_mine);
+        den *= Scalar::conditional_select(&(s - mine), &Scalar::one(), is_mine);
+    }
+
+    if bool::from(den.ct_eq(&Scalar::zero())) {
+        return Err("Duplicate shares provided");
+    }
+    Ok(num * den.invert())
+}
+
+/// A quorum of exactly \\(t\\) signers, selected among the available ones,
+/// along with their precomputed Lagrange coefficients.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SigningQuorum {
+    /// The sorted participant indices of the signers in this quorum.
+    pub(crate) signers: Vec<u32>,
+    /// The Lagrange coefficient of each signer, in the same order.
+    pub(crate) lagrange_coefficients: Vec<Scalar>,
+}
+
+impl SigningQuorum {
+    /// Select a quorum of \\(t\\) signers from the `available_indices`.
+    ///
+    /// The available indices are sorted and deduplicated, and the \\(t\\)
+    /// lowest ones are selected.
+    ///
+    /// # Returns
+    ///
+    /// A `SigningQuorum` if at least \\(t\\) distinct, non-zero indices were
+    /// available, otherwise a [`SignatureError`].
+    pub fn new(parameters: &Parameters, available_indices: &[u32]) -> Result<SigningQuorum, SignatureError> {
+        let mut indices: Vec<u32> = available_indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        if indices.contains(&0) {
+            return Err(SignatureError::InvalidParticipantIndex(0));
+        }
+
+        if indices.len() < parameters.t as usize {
+            return Err(SignatureError::NotEnoughSigners(indices.len(), parameters.t));
+        }
+
+        indices.truncate(parameters.t as usize);
+
+        let mut lagrange_coefficients: Vec<Scalar> = Vec::with_capacity(indices.len());
+
+        for index in indices.iter() {
+            let coeff = calculate_lagrange_coefficients(index, &indices)
+                .map_err(|_| SignatureError::InvalidParticipantIndex(*index))?;
+            lagrange_coefficients.push(coeff);
+        }
+
+        Ok(SigningQuorum { signers: indices, lagrange_coefficients })
+    }
+
+    /// Get the sorted participant indices of the signers in this quorum.
+    pub fn signers(&self) -> &[u32] {
+        &self.signers
+    }
+
+    /// Get the precomputed Lagrange coefficient of the signer with the given
+    /// `participant_index`, if it belongs to this quorum.
+    pub fn lagrange_coefficient(&self, participant_index: u32) -> Option<Scalar> {
+        self.signers
+            .iter()
+            .position(|index| *index == participant_index)
+            .map(|position| self.lagrange_coefficients[position])
+    }
+}
+
 impl SecretKey {
     /// Compute an individual signer's [`PartialThresholdSignature`] contribution to
     /// a [`ThresholdSignature`] on a `message`.
@@ -404,6 +839,10 @@ impl SecretKey {
             return Err(SignatureError::MissingCommitmentShares);
         }
 
+        if my_secret_commitment_share_list.commitments[my_commitment_share_index].participant_index != self.index {
+            return Err(SignatureError::MismatchedCommitmentShare);
+        }
+
         let (binding_factors, Rs) = compute_binding_factors_and_group_commitment(message_hash, signers);
         let R: RistrettoPoint = Rs.values().sum();
         let challenge = compute_challenge(message_hash, group_key, &R);
@@ -429,7 +868,122 @@ impl SecretKey {
         // Zero out our secrets from memory to prevent nonce reuse.
         my_secret_commitment_share_list.drop_share(my_commitment_share);
 
-        Ok(PartialThresholdSignature { index: self.index, z })
+        Ok(PartialThresholdSignature { index: self.index, z, nonce_proof: None })
+    }
+
+    /// Compute this signer's [`PartialThresholdSignature`] as
+    /// \\(z\_i = d\_i + e\_i \rho\_i + \lambda\_i s\_i c\\), with a precomputed
+    /// [`SigningQuorum`].
+    ///
+    /// # Inputs
+    ///
+    /// * This signer's [`SecretCommitmentShareList`] and
+    /// * The index of the particular `CommitmentShare` being used, whose public
+    ///   part must be the one published for this signer among the `signers`.
+    ///   It is removed from the list, and wiped from memory, once the partial
+    ///   signature is computed, so that its nonces cannot be reused.
+    /// * The list of all the currently participating [`Signer`]s (including
+    ///   ourself), which must be the members of the `signing_quorum`,
+    /// * The `signing_quorum`, holding the Lagrange coefficient of this signer,
+    /// * The `message_hash` to be signed, as computed by [`compute_message_hash`], and
+    /// * The public [`GroupKey`] for this group of signing participants.
+    ///
+    /// # Returns
+    ///
+    /// A Result whose `Ok` value contains a [`PartialThresholdSignature`], to
+    /// be sent to the [`SignatureAggregator`], or a [`SignatureError`].
+    pub fn sign_partial(
+        &self,
+        my_secret_commitment_share_list: &mut SecretCommitmentShareList,
+        my_commitment_share_index: usize,
+        signers: &[Signer],
+        signing_quorum: &SigningQuorum,
+        message_hash: &[u8; 64],
+        group_key: &GroupKey,
+    ) -> Result<PartialThresholdSignature, SignatureError>
+    {
+        let commitment_share = my_secret_commitment_share_list.commitments
+            .get(my_commitment_share_index)
+            .ok_or(SignatureError::MissingCommitmentShares)?;
+
+        if commitment_share.participant_index != self.index {
+            return Err(SignatureError::MismatchedCommitmentShare);
+        }
+
+        let mut signer_indices: Vec<u32> = signers.iter().map(|signer| signer.participant_index).collect();
+        signer_indices.sort_unstable();
+        if signer_indices != signing_quorum.signers() {
+            return Err(SignatureError::MismatchedSigningQuorum);
+        }
+
+        let published = signers
+            .iter()
+            .find(|signer| signer.participant_index == self.index)
+            .map(|signer| signer.published_commitment_share)
+            .ok_or(SignatureError::MissingCommitmentShares)?;
+        if published != commitment_share.publish() {
+            return Err(SignatureError::MismatchedCommitmentShare);
+        }
+
+        let lambda = signing_quorum
+            .lagrange_coefficient(self.index)
+            .ok_or(SignatureError::MissingCommitmentShares)?;
+
+        let (binding_factors, Rs) = compute_binding_factors_and_group_commitment(message_hash, signers);
+        let R: RistrettoPoint = Rs.values().sum();
+        let challenge = compute_challenge(message_hash, group_key, &R);
+        let my_binding_factor = binding_factors.get(&self.index).ok_or(SignatureError::InvalidBindingFactor)?;
+
+        let z = commitment_share.hiding.nonce +
+            (commitment_share.binding.nonce * my_binding_factor) +
+            (lambda * self.key * challenge);
+
+        // Zero out our secrets from memory to prevent nonce reuse.
+        let commitment_share = commitment_share.clone();
+        my_secret_commitment_share_list.drop_share(commitment_share);
+
+        Ok(PartialThresholdSignature { index: self.index, z, nonce_proof: None })
+    }
+
+    /// Compute this signer's [`PartialThresholdSignature`] as
+    /// [`SecretKey::sign_partial`] does, along with a [`NizkOfNonces`] proving
+    /// that the published commitment share matches the nonces used.
+    ///
+    /// The proof is bound to this signer's index and to the `message_hash`,
+    /// and is checked by [`SignatureAggregator.add_partial_signature`].
+    ///
+    /// # Returns
+    ///
+    /// A Result whose `Ok` value contains a [`PartialThresholdSignature`]
+    /// carrying the proof, or a [`SignatureError`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_partial_with_nonce_proof(
+        &self,
+        my_secret_commitment_share_list: &mut SecretCommitmentShareList,
+        my_commitment_share_index: usize,
+        signers: &[Signer],
+        signing_quorum: &SigningQuorum,
+        message_hash: &[u8; 64],
+        group_key: &GroupKey,
+        csprng: impl Rng + CryptoRng,
+    ) -> Result<PartialThresholdSignature, SignatureError>
+    {
+        let commitment_share = my_secret_commitment_share_list.commitments
+            .get(my_commitment_share_index)
+            .ok_or(SignatureError::MissingCommitmentShares)?;
+        let nonce_proof = NizkOfNonces::prove(
+            &self.index,
+            &commitment_share.hiding.nonce,
+            &commitment_share.binding.nonce,
+            &commitment_share.publish(),
+            message_hash,
+            csprng,
+        );
+        let mut partial_signature = self.sign_partial(
+            my_secret_commitment_share_list, my_commitment_share_index, signers, signing_quorum, message_hash, group_key)?;
+        partial_signature.nonce_proof = Some(nonce_proof);
+
+        Ok(partial_signature)
     }
 }
 
@@ -450,6 +1004,55 @@ pub(crate) struct AggregatorState {
     pub(crate) partial_signatures: PartialThresholdSignatures,
     /// The group public key for all the participants.
     pub(crate) group_key: GroupKey,
+    /// The values which the partial signatures are checked against, computed
+    /// once the signers have been included and reset whenever one is added.
+    pub(crate) transcript: Option<SigningTranscript>,
+}
+
+/// The values which every partial signature of a signing operation is
+/// checked against, which only depend on the message and the set of signers.
+#[derive(Debug)]
+pub(crate) struct SigningTranscript {
+    /// The hashed context and message for signing.
+    message_hash: [u8; 64],
+    /// The commitment \\(R\_i\\) of each signer, bound by its binding factor.
+    Rs: SignerRs,
+    /// The group commitment \\(R\\).
+    R: RistrettoPoint,
+    /// The challenge \\(c\\).
+    c: Scalar,
+    /// The Lagrange coefficient of each signer, which is missing for every
+    /// signer if two of them share the same participant index.
+    lambdas: BTreeMap<u32, Scalar>,
+}
+
+impl SigningTranscript {
+    /// Compute the binding factors, the group commitment, the challenge and
+    /// the Lagrange coefficients for this sorted list of `signers`.
+    fn new(message_hash: [u8; 64], group_key: &GroupKey, signers: &[Signer]) -> SigningTranscript {
+        let (_, Rs) = compute_binding_factors_and_group_commitment(&message_hash, signers);
+        let R: RistrettoPoint = Rs.values().sum();
+        let c = compute_challenge(&message_hash, group_key, &R);
+        let all_participant_indices: Vec<u32> = signers.iter().map(|x| x.participant_index).collect();
+
+        let mut lambdas: BTreeMap<u32, Scalar> = BTreeMap::new();
+        for index in all_participant_indices.iter() {
+            if let Ok(lambda) = calculate_lagrange_coefficients(index, &all_participant_indices) {
+                lambdas.insert(*index, lambda);
+            }
+        }
+
+        SigningTranscript { message_hash, Rs, R, c, lambdas }
+    }
+
+    /// Check the partial signature `z` of the signer with the given `index`
+    /// against its individual public key `Y_i`.
+    fn verify(&self, index: &u32, z: &Scalar, Y_i: &RistrettoPoint) -> bool {
+        match (self.Rs.get(index), self.lambdas.get(index)) {
+            (Some(R_i), Some(lambda)) => &RISTRETTO_BASEPOINT_TABLE * z == R_i + (Y_i * (self.c * lambda)),
+            _ => false,
+        }
+    }
 }
 
 /// A signature aggregator is an untrusted party who coalesces all of the
@@ -457,6 +1060,16 @@ pub(crate) struct AggregatorState {
 /// [`PartialThresholdSignature`] and creates the final [`ThresholdSignature`].
 /// The signature aggregator may even be one of the \\(t\\) participants in this
 /// signing operation.
+///
+/// # Aggregator as a signer
+///
+/// An aggregator which is also a signer includes itself exactly like any other
+/// signer, then contributes its own partial signature once all signers have
+/// been included, with [`SignatureAggregator::include_own_partial_signature`].
+/// Its commitment share and partial signature must be counted only once, which
+/// is why [`SignatureAggregator::include_signer_from_list`] and
+/// [`SignatureAggregator::add_partial_signature`] reject a signer index which
+/// has already been included.
 #[derive(Debug)]
 pub struct SignatureAggregator<A: Aggregator> {
     /// The aggregator's actual state, shared across types.
@@ -471,8 +1084,10 @@ pub struct SignatureAggregator<A: Aggregator> {
 pub struct Initial<'sa> {
     /// An optional context string for computing the message hash.
     pub(crate) context: &'sa [u8],
-    /// The message to be signed.
+    /// The message to be signed, or its prehash.
     pub(crate) message: &'sa [u8],
+    /// Whether the message is a prehash, see [`compute_prehashed_message_hash`].
+    pub(crate) prehashed: bool,
 }
 
 impl Aggregator for Initial<'_> {}
@@ -493,8 +1108,9 @@ impl Aggregator for Initial<'_> {}
 /// * Any signer could have contributed a malformed partial signature.
 #[derive(Debug)]
 pub struct Finalized {
-    /// The hashed context and message for signing.
-    pub(crate) message_hash: [u8; 64],
+    /// The values which the partial signatures are checked against, for the
+    /// final set of signers.
+    pub(crate) transcript: SigningTranscript,
 }
 
 impl Aggregator for Finalized {}
@@ -528,9 +1144,50 @@ impl SignatureAggregator<Initial<'_>> {
         let signers: Vec<Signer> = Vec::with_capacity(parameters.t as usize);
         let public_keys = IndividualPublicKeys::new();
         let partial_signatures = PartialThresholdSignatures::new();
-        let state = AggregatorState { parameters, signers, public_keys, partial_signatures, group_key };
+        let state = AggregatorState { parameters, signers, public_keys, partial_signatures, group_key, transcript: None };
+
+        SignatureAggregator { state: Box::new(state), aggregator: Initial { context, message, prehashed: false } }
+    }
+
+    /// Construct a new signature aggregator, as [`SignatureAggregator::new`]
+    /// does, for a message which was already hashed by the caller into a
+    /// `prehash`.
+    ///
+    /// The signers must sign the message hash computed by
+    /// [`compute_prehashed_message_hash`], and the resulting signature is
+    /// checked with [`ThresholdSignature::verify_prehashed`].
+    pub fn new_prehashed<'sa>(
+        parameters: Parameters,
+        group_key: GroupKey,
+        context: &'sa [u8],
+        prehash: &'sa [u8],
+    ) -> SignatureAggregator<Initial<'sa>> {
+        let mut aggregator = Self::new(parameters, group_key, context, prehash);
+        aggregator.aggregator.prehashed = true;
+
+        aggregator
+    }
+
+    /// Compute the hash of the context and the message to be signed.
+    fn message_hash(&self) -> [u8; 64] {
+        match self.aggregator.prehashed {
+            true => compute_prehashed_message_hash(self.aggregator.context, self.aggregator.message),
+            false => compute_message_hash(self.aggregator.context, self.aggregator.message),
+        }
+    }
 
-        SignatureAggregator { state: Box::new(state), aggregator: Initial { context, message } }
+    /// Get the values which the partial signatures are checked against,
+    /// computing them if a signer was included since they were last used.
+    fn transcript(&mut self) -> &SigningTranscript {
+        if self.state.transcript.is_none() {
+            let message_hash = self.message_hash();
+            self.get_signers();
+
+            self.state.transcript = Some(SigningTranscript::new(message_hash, &self.state.group_key, &self.state.signers));
+        }
+
+        // This unwrap() cannot fail, since the transcript was computed above.
+        self.state.transcript.as_ref().unwrap()
     }
 
     /// Include a signer in the protocol.
@@ -542,21 +1199,70 @@ impl SignatureAggregator<Initial<'_>> {
     /// [`SignatureAggregator.include_partial_signature`], otherwise the signing
     /// procedure will fail.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// If the `signer.participant_index` doesn't match the `public_key.index`.
+    /// An empty `Ok` if the signer was included, otherwise a [`SignatureError`],
+    /// which is [`SignatureError::InvalidParticipantIndex`] if the
+    /// `participant_index` is out of range for the parameters,
+    /// [`SignatureError::MismatchedCommitmentShare`] if the `public_key`
+    /// belongs to another participant, and [`SignatureError::DuplicateSigner`]
+    /// if a signer with the same `participant_index` has already been included.
     pub fn include_signer(
         &mut self,
         participant_index: u32,
         published_commitment_share: (RistrettoPoint, RistrettoPoint),
-        public_key: IndividualPublicKey)
+        public_key: IndividualPublicKey,
+    ) -> Result<(), SignatureError>
     {
-        assert_eq!(participant_index, public_key.index,
-                   "Tried to add signer with participant index {}, but public key is for participant with index {}",
-                   participant_index, public_key.index);
+        if !self.state.parameters.is_valid_index(participant_index) {
+            return Err(SignatureError::InvalidParticipantIndex(participant_index));
+        }
+
+        if public_key.index != participant_index {
+            return Err(SignatureError::MismatchedCommitmentShare);
+        }
+
+        if self.state.signers.iter().any(|signer| signer.participant_index == participant_index) {
+            return Err(SignatureError::DuplicateSigner(participant_index));
+        }
 
         self.state.signers.push(Signer { participant_index, published_commitment_share });
         self.state.public_keys.insert(&public_key.index, public_key.share);
+        self.state.transcript = None;
+
+        Ok(())
+    }
+
+    /// Include a signer in the protocol, using one of the commitments from
+    /// their published [`PublicCommitmentShareList`].
+    ///
+    /// On top of the checks of [`SignatureAggregator.include_signer`], this
+    /// checks that the commitment share list was published by the signer with
+    /// the given `participant_index`, so that a commitment cannot be presented
+    /// under another signer's index.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Ok` if the signer was included, otherwise a [`SignatureError`],
+    /// which is [`SignatureError::DuplicateSigner`] if a signer with the same
+    /// `participant_index` has already been included.
+    pub fn include_signer_from_list(
+        &mut self,
+        participant_index: u32,
+        public_commitment_share_list: &PublicCommitmentShareList,
+        commitment_share_index: usize,
+        public_key: IndividualPublicKey,
+    ) -> Result<(), SignatureError>
+    {
+        if public_commitment_share_list.participant_index != participant_index {
+            return Err(SignatureError::MismatchedCommitmentShare);
+        }
+
+        let published_commitment_share = *public_commitment_share_list.commitments
+            .get(commitment_share_index)
+            .ok_or(SignatureError::MissingCommitmentShares)?;
+
+        self.include_signer(participant_index, published_commitment_share, public_key)
     }
 
     /// Get the list of partipating signers.
@@ -603,9 +1309,108 @@ impl SignatureAggregator<Initial<'_>> {
         self.state.partial_signatures.insert(&partial_signature.index, partial_signature.z);
     }
 
-    /// Ensure that this signature aggregator is in a proper state to run the aggregation protocol.
+    /// Verify a [`PartialThresholdSignature`] against its signer's published
+    /// commitment share and public key, and include it in the aggregation
+    /// only if it is valid.
     ///
-    /// # Returns
+    /// If the partial signature carries a [`NizkOfNonces`], it is checked
+    /// against the commitment share published by its signer as well.
+    ///
+    /// # Note
+    ///
+    /// Since the binding factors and the challenge depend on the whole set of
+    /// signers, all of them must have been included with
+    /// [`SignatureAggregator.include_signer`] prior to calling this method.
+    /// They are computed upon the first call, and shared by all the following
+    /// ones until another signer is included.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Ok` if the partial signature was valid, otherwise a
+    /// [`SignatureError::InvalidPartialSignature`] naming the signer, or a
+    /// [`SignatureError::DuplicateSigner`] if this signer's partial signature
+    /// has already been added.
+    pub fn add_partial_signature(&mut self, partial_signature: PartialThresholdSignature) -> Result<(), SignatureError> {
+        let index = partial_signature.index;
+        let invalid = SignatureError::InvalidPartialSignature(index);
+
+        if self.state.partial_signatures.get(&index).is_some() {
+            return Err(SignatureError::DuplicateSigner(index));
+        }
+
+        let Y_i = match self.state.public_keys.get(&index) {
+            Some(Y_i) => *Y_i,
+            None => return Err(invalid),
+        };
+        let published = self.state.signers
+            .iter()
+            .find(|signer| signer.participant_index == index)
+            .map(|signer| signer.published_commitment_share);
+
+        let transcript = self.transcript();
+
+        if let Some(nonce_proof) = &partial_signature.nonce_proof {
+            match published {
+                Some(commitment) if nonce_proof.verify(&index, &commitment, &transcript.message_hash).is_ok() => (),
+                _ => return Err(invalid),
+            }
+        }
+
+        if !transcript.verify(&index, &partial_signature.z, &Y_i) {
+            return Err(invalid);
+        }
+
+        self.include_partial_signature(partial_signature);
+
+        Ok(())
+    }
+
+    /// Contribute the partial signature of the aggregator itself, when it is
+    /// also one of the signers.
+    ///
+    /// The aggregator must have been included as a signer beforehand, along
+    /// with all the other signers, since the partial signature depends on the
+    /// whole set of signers.
+    ///
+    /// # Inputs
+    ///
+    /// * The aggregator's own [`SecretKey`],
+    /// * The aggregator's [`SecretCommitmentShareList`] and the index of the
+    ///   commitment share it was included with.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Ok` if the partial signature was computed and added, otherwise
+    /// a [`SignatureError`], which is [`SignatureError::DuplicateSigner`] if the
+    /// aggregator's partial signature has already been added.
+    pub fn include_own_partial_signature(
+        &mut self,
+        secret_key: &SecretKey,
+        my_secret_commitment_share_list: &mut SecretCommitmentShareList,
+        my_commitment_share_index: usize,
+    ) -> Result<(), SignatureError>
+    {
+        if self.state.partial_signatures.get(&secret_key.index).is_some() {
+            return Err(SignatureError::DuplicateSigner(secret_key.index));
+        }
+
+        let signers = self.get_signers().clone();
+        let message_hash = self.message_hash();
+
+        let partial_signature = secret_key.sign(
+            &message_hash,
+            &self.state.group_key,
+            my_secret_commitment_share_list,
+            my_commitment_share_index,
+            &signers,
+        )?;
+
+        self.add_partial_signature(partial_signature)
+    }
+
+    /// Ensure that this signature aggregator is in a proper state to run the aggregation protocol.
+    ///
+    /// # Returns
     ///
     /// A Result whose Ok() value is a finalized aggregator, otherwise a
     /// `BTreeMap<u32, &'static str>` containing the participant indices of the misbehaving
@@ -614,10 +1419,18 @@ impl SignatureAggregator<Initial<'_>> {
     /// If the `BTreeMap` contains a key for `0`, this indicates that
     /// the aggregator did not have \(( t' \)) partial signers
     /// s.t. \(( t \le t' \le n \)).
+    ///
+    /// The partial signatures must cover the included signers exactly: a
+    /// signer included twice under the same participant index, or a partial
+    /// signature from a participant who was not included, is reported as well.
     pub fn finalize(mut self) -> Result<SignatureAggregator<Finalized>, BTreeMap<u32, &'static str>> {
         let mut misbehaving_participants: BTreeMap<u32, &'static str> = BTreeMap::new();
         let remaining_signers = self.get_remaining_signers();
 
+        if self.get_signers().len() < self.state.parameters.t as usize {
+            misbehaving_participants.insert(0, "Not enough signers");
+        }
+
         // [DIFFERENT_TO_PAPER] We're reporting missing partial signatures which
         // could possibly be the fault of the aggregator, but here we explicitly
         // make it the aggregator's fault and problem.
@@ -631,7 +1444,21 @@ impl SignatureAggregator<Initial<'_>> {
         }
 
         // Ensure that our new state is ordered and deduplicated.
-        self.state.signers = self.get_signers().clone();
+        self.transcript();
+
+        for pair in self.state.signers.windows(2) {
+            if pair[0].participant_index == pair[1].participant_index {
+                misbehaving_participants.insert(pair[0].participant_index, "Duplicate signer");
+            }
+        }
+
+        for index in self.state.partial_signatures.0.keys() {
+            let index = u32::from_be_bytes(*index);
+
+            if !self.state.signers.iter().any(|signer| signer.participant_index == index) {
+                misbehaving_participants.insert(index, "Partial signature from a non-signer");
+            }
+        }
 
         for signer in self.state.signers.iter() {
             if self.state.public_keys.get(&signer.participant_index).is_none() {
@@ -644,10 +1471,145 @@ impl SignatureAggregator<Initial<'_>> {
             return Err(misbehaving_participants);
         }
 
-        let message_hash = compute_message_hash(self.aggregator.context, self.aggregator.message);
+        // This unwrap() cannot fail, since the transcript was computed above.
+        let transcript = self.state.transcript.take().unwrap();
 
-        Ok(SignatureAggregator { state: self.state, aggregator: Finalized { message_hash } })
+        Ok(SignatureAggregator { state: self.state, aggregator: Finalized { transcript } })
     }
+
+    /// Serialise this signature aggregator to a Vec of bytes, for persisting
+    /// it while partial signatures are being collected.
+    ///
+    /// This captures the parameters, the group key, the context and message,
+    /// the included signers with their commitment shares and public keys, and
+    /// the partial signatures collected thus far. Only the signature shares
+    /// of the partial signatures are kept by the aggregator, their nonce
+    /// proofs being checked upon reception.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let state = &self.state;
+        let mut res = Vec::with_capacity(
+            8 + 32 + 1
+                + 8 + self.aggregator.context.len() + self.aggregator.message.len()
+                + 12 + 68 * state.signers.len() + 36 * (state.public_keys.0.len() + state.partial_signatures.0.len())
+        );
+
+        res.extend_from_slice(&state.parameters.to_bytes());
+        res.extend_from_slice(&state.group_key.to_bytes());
+        res.push(self.aggregator.prehashed as u8);
+
+        for bytes in [self.aggregator.context, self.aggregator.message].iter() {
+            res.extend_from_slice(&TryInto::<u32>::try_into(bytes.len()).unwrap().to_le_bytes());
+            res.extend_from_slice(bytes);
+        }
+
+        res.extend_from_slice(&TryInto::<u32>::try_into(state.signers.len()).unwrap().to_le_bytes());
+        for signer in state.signers.iter() {
+            res.extend_from_slice(&signer.participant_index.to_le_bytes());
+            res.extend_from_slice(signer.published_commitment_share.0.compress().as_bytes());
+            res.extend_from_slice(signer.published_commitment_share.1.compress().as_bytes());
+        }
+
+        res.extend_from_slice(&TryInto::<u32>::try_into(state.public_keys.0.len()).unwrap().to_le_bytes());
+        for (index, public_key) in state.public_keys.0.iter() {
+            res.extend_from_slice(&u32::from_be_bytes(*index).to_le_bytes());
+            res.extend_from_slice(public_key.compress().as_bytes());
+        }
+
+        res.extend_from_slice(&TryInto::<u32>::try_into(state.partial_signatures.0.len()).unwrap().to_le_bytes());
+        for (index, z) in state.partial_signatures.0.iter() {
+            res.extend_from_slice(&u32::from_be_bytes(*index).to_le_bytes());
+            res.extend_from_slice(z.as_bytes());
+        }
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `SignatureAggregator`, to resume
+    /// the collection of partial signatures.
+    ///
+    /// The context and message of the returned aggregator borrow from `bytes`.
+    pub fn from_bytes<'sa>(bytes: &'sa [u8]) -> Result<SignatureAggregator<Initial<'sa>>, Error> {
+        let mut position = 0;
+
+        let parameters = Parameters::from_bytes(
+            &read_slice(bytes, &mut position, 8)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        )?;
+        let group_key = GroupKey::from_bytes(
+            &read_slice(bytes, &mut position, 32)?
+                .try_into()
+                .map_err(|_| Error::SerialisationError)?
+        )?;
+        let prehashed = match read_slice(bytes, &mut position, 1)?[0] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::SerialisationError),
+        };
+
+        let len = read_u32(bytes, &mut position)? as usize;
+        let context = read_slice(bytes, &mut position, len)?;
+        let len = read_u32(bytes, &mut position)? as usize;
+        let message = read_slice(bytes, &mut position, len)?;
+
+        let mut aggregator = SignatureAggregator::new(parameters, group_key, context, message);
+        aggregator.aggregator.prehashed = prehashed;
+
+        for _ in 0..read_u32(bytes, &mut position)? {
+            let participant_index = read_u32(bytes, &mut position)?;
+            let hiding = read_point(bytes, &mut position)?;
+            let binding = read_point(bytes, &mut position)?;
+
+            aggregator.state.signers.push(Signer { participant_index, published_commitment_share: (hiding, binding) });
+        }
+
+        for _ in 0..read_u32(bytes, &mut position)? {
+            let index = read_u32(bytes, &mut position)?;
+            let public_key = read_point(bytes, &mut position)?;
+
+            aggregator.state.public_keys.insert(&index, public_key);
+        }
+
+        for _ in 0..read_u32(bytes, &mut position)? {
+            let index = read_u32(bytes, &mut position)?;
+            let z = Scalar::from_canonical_bytes(
+                read_slice(bytes, &mut position, 32)?
+                    .try_into()
+                    .map_err(|_| Error::SerialisationError)?
+            ).ok_or(Error::SerialisationError)?;
+
+            aggregator.state.partial_signatures.insert(&index, z);
+        }
+
+        if position != bytes.len() {
+            return Err(Error::SerialisationError);
+        }
+
+        Ok(aggregator)
+    }
+}
+
+/// Read the `len` bytes at `position` in `bytes`, and move `position` past them.
+fn read_slice<'a>(bytes: &'a [u8], position: &mut usize, len: usize) -> Result<&'a [u8], Error> {
+    let end = position.checked_add(len).ok_or(Error::SerialisationError)?;
+    let slice = bytes.get(*position..end).ok_or(Error::SerialisationError)?;
+    *position = end;
+
+    Ok(slice)
+}
+
+fn read_u32(bytes: &[u8], position: &mut usize) -> Result<u32, Error> {
+    Ok(u32::from_le_bytes(
+        read_slice(bytes, position, 4)?
+            .try_into()
+            .map_err(|_| Error::SerialisationError)?
+    ))
+}
+
+fn read_point(bytes: &[u8], position: &mut usize) -> Result<RistrettoPoint, Error> {
+    CompressedRistretto::from_slice(read_slice(bytes, position, 32)?)
+        .decompress()
+        .ok_or(Error::SerialisationError)
 }
 
 impl SignatureAggregator<Finalized> {
@@ -660,28 +1622,21 @@ impl SignatureAggregator<Finalized> {
     /// signers and a description of their misbehaviour.
     pub fn aggregate(&self) -> Result<ThresholdSignature, BTreeMap<u32, &'static str>> {
         let mut misbehaving_participants: BTreeMap<u32, &'static str> = BTreeMap::new();
-        
-        let (_, Rs) = compute_binding_factors_and_group_commitment(&self.aggregator.message_hash, &self.state.signers);
-        let R: RistrettoPoint = Rs.values().sum();
-        let c = compute_challenge(&self.aggregator.message_hash, &self.state.group_key, &R);
-        let all_participant_indices: Vec<u32> = self.state.signers.iter().map(|x| x.participant_index).collect();
+        let transcript = &self.aggregator.transcript;
         let mut z = Scalar::zero();
 
         for signer in self.state.signers.iter() {
             // [DIFFERENT_TO_PAPER] We're not just pulling lambda out of our
             // ass, instead to get the correct algebraic properties to allow for
             // partial signature aggregation with t <= #participant <= n, we
-            // have to do Langrangian polynomial interpolation.
-            //
-            // This unwrap() cannot fail, since the attempted division by zero in
-            // the calculation of the Lagrange interpolation cannot happen,
+            // have to do Langrangian polynomial interpolation. The Lagrange
+            // coefficients are part of the transcript, and cannot be missing
             // because we use the typestate pattern,
             // i.e. SignatureAggregator<Initial>.finalize(), to ensure that
             // there are no duplicate signers, which is the only thing that
             // would cause a denominator of zero.
-            let lambda = calculate_lagrange_coefficients(&signer.participant_index, &all_participant_indices).unwrap();
 
-            // Similar to above, this unwrap() cannot fail, because
+            // This unwrap() cannot fail, because
             // SignatureAggregator<Initial>.finalize() checks that we have
             // partial signature for every expected signer.
             let partial_sig = self.state.partial_signatures.get(&signer.participant_index).unwrap();
@@ -689,13 +1644,7 @@ impl SignatureAggregator<Finalized> {
             // Again, this unwrap() cannot fail, because of the checks in finalize().
             let Y_i = self.state.public_keys.get(&signer.participant_index).unwrap();
 
-            let check = &RISTRETTO_BASEPOINT_TABLE * partial_sig;
-
-            // Again, this unwrap() cannot fail, because we check the
-            // participant indexes against the expected ones in finalize().
-            let R_i = Rs.get(&signer.participant_index).unwrap();
-
-            if check == R_i + (Y_i * (c * lambda)) {
+            if transcript.verify(&signer.participant_index, partial_sig, Y_i) {
                 z += partial_sig;
             } else {
                 // XXX We don't really need the error string anymore, since there's only one failure mode.
@@ -703,6 +1652,8 @@ impl SignatureAggregator<Finalized> {
             }
         }
 
+        let R = transcript.R;
+
         match ! misbehaving_participants.is_empty() {
             true => Err(misbehaving_participants),
             false => Ok(ThresholdSignature {z, R}),
@@ -727,6 +1678,23 @@ impl ThresholdSignature {
             false => Err(SignatureError::InvalidSignature),
         }
     }
+
+    /// Verify this [`ThresholdSignature`] on a message which was hashed by
+    /// the caller into a `prehash`, under the given `context_string`.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Ok` if the signature was made with
+    /// [`SignatureAggregator::new_prehashed`] over this prehash, otherwise a
+    /// [`SignatureError::InvalidSignature`].
+    pub fn verify_prehashed(
+        &self,
+        group_key: &GroupKey,
+        context_string: &[u8],
+        prehash: &[u8],
+    ) -> Result<(), SignatureError> {
+        self.verify(group_key, &compute_prehashed_message_hash(context_string, prehash))
+    }
 }
 
 #[cfg(test)]
@@ -735,9 +1703,8 @@ mod test {
 
     use crate::keygen::Participant;
     use crate::keygen::{DistributedKeyGeneration, RoundOne};
-    use crate::precomputation::{generate_commitment_share_lists, PublicCommitmentShareList};
-
-    use curve25519_dalek::traits::Identity;
+    use crate::precomputation::generate_commitment_share_lists;
+    use crate::test_utils::trusted_dealer_keys;
 
     use rand::rngs::OsRng;
 
@@ -772,7 +1739,7 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -820,7 +1787,7 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -880,7 +1847,7 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1003,9 +1970,9 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
-        aggregator.include_signer(3, p3_public_comshares.commitments[0], (&p3_sk).into());
-        aggregator.include_signer(4, p4_public_comshares.commitments[0], (&p4_sk).into());
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(3, p3_public_comshares.commitments[0], (&p3_sk).into()).unwrap();
+        aggregator.include_signer(4, p4_public_comshares.commitments[0], (&p4_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1102,8 +2069,8 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
-        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into());
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1275,8 +2242,8 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, d1_public_comshares.commitments[0], (&d1_sk).into());
-        aggregator.include_signer(2, d2_public_comshares.commitments[0], (&d2_sk).into());
+        aggregator.include_signer(1, d1_public_comshares.commitments[0], (&d1_sk).into()).unwrap();
+        aggregator.include_signer(2, d2_public_comshares.commitments[0], (&d2_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1304,8 +2271,8 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, s1_public_comshares.commitments[0], (&s1_sk).into());
-        aggregator.include_signer(2, s2_public_comshares.commitments[0], (&s2_sk).into());
+        aggregator.include_signer(1, s1_public_comshares.commitments[0], (&s1_sk).into()).unwrap();
+        aggregator.include_signer(2, s2_public_comshares.commitments[0], (&s2_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1511,8 +2478,8 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(d_params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, d1_public_comshares.commitments[0], (&d1_sk).into());
-        aggregator.include_signer(2, d2_public_comshares.commitments[0], (&d2_sk).into());
+        aggregator.include_signer(1, d1_public_comshares.commitments[0], (&d1_sk).into()).unwrap();
+        aggregator.include_signer(2, d2_public_comshares.commitments[0], (&d2_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1541,9 +2508,9 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(s_params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, s1_public_comshares.commitments[0], (&s1_sk).into());
-        aggregator.include_signer(2, s2_public_comshares.commitments[0], (&s2_sk).into());
-        aggregator.include_signer(3, s3_public_comshares.commitments[0], (&s3_sk).into());
+        aggregator.include_signer(1, s1_public_comshares.commitments[0], (&s1_sk).into()).unwrap();
+        aggregator.include_signer(2, s2_public_comshares.commitments[0], (&s2_sk).into()).unwrap();
+        aggregator.include_signer(3, s3_public_comshares.commitments[0], (&s3_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1578,14 +2545,32 @@ mod test {
         let (p1_public_comshares, _) = generate_commitment_share_lists(&mut OsRng, 1, 1);
         let (p2_public_comshares, _) = generate_commitment_share_lists(&mut OsRng, 2, 1);
 
-        let mut aggregator = SignatureAggregator::new(params, GroupKey(RistrettoPoint::identity()), &context[..], &message[..]);
+        let mut aggregator = SignatureAggregator::new(params, GroupKey::new(RistrettoPoint::identity()), &context[..], &message[..]);
 
         let p1_sk = SecretKey{ index: 1, key: Scalar::random(&mut OsRng) };
         let p2_sk = SecretKey{ index: 2, key: Scalar::random(&mut OsRng) };
 
-        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into());
-        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
-        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into());
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        assert_eq!(
+            aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()),
+            Err(SignatureError::DuplicateSigner(2))
+        );
+        assert_eq!(
+            aggregator.include_signer(3, p2_public_comshares.commitments[0], (&p2_sk).into()),
+            Err(SignatureError::MismatchedCommitmentShare)
+        );
+
+        let p0_sk = SecretKey{ index: 0, key: Scalar::random(&mut OsRng) };
+        let p4_sk = SecretKey{ index: 4, key: Scalar::random(&mut OsRng) };
+        assert_eq!(
+            aggregator.include_signer(0, p1_public_comshares.commitments[0], (&p0_sk).into()),
+            Err(SignatureError::InvalidParticipantIndex(0))
+        );
+        assert_eq!(
+            aggregator.include_signer(4, p1_public_comshares.commitments[0], (&p4_sk).into()),
+            Err(SignatureError::InvalidParticipantIndex(4))
+        );
 
         let signers = aggregator.get_signers();
 
@@ -1682,8 +2667,8 @@ mod test {
 
         let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
-        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into());
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
 
         let signers = aggregator.get_signers();
         let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -1701,6 +2686,8 @@ mod test {
 
         let bytes = p1_partial.to_bytes();
         assert_eq!(p1_partial, PartialThresholdSignature::from_bytes(&bytes).unwrap());
+        // The encoding of earlier versions, without the nonce proof flag, is still read.
+        assert_eq!(p1_partial, PartialThresholdSignature::from_bytes(&bytes[..36]).unwrap());
 
         // Continue signature
 
@@ -1725,4 +2712,732 @@ mod test {
         assert_eq!(threshold_signature, ThresholdSignature::from_bytes(&bytes).unwrap());
 
     }
+
+    #[test]
+    fn commitment_share_bound_to_signer_index() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let (p2_public_comshares, mut p2_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 2, 1);
+        let (p3_public_comshares, _) = generate_commitment_share_lists(&mut OsRng, 3, 1);
+
+        let group_key = GroupKey::new(RistrettoPoint::identity());
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        let p2_sk = SecretKey{ index: 2, key: Scalar::random(&mut OsRng) };
+        let p3_sk = SecretKey{ index: 3, key: Scalar::random(&mut OsRng) };
+
+        // Signer 2's commitment cannot be passed off as signer 3's.
+        assert_eq!(
+            aggregator.include_signer_from_list(3, &p2_public_comshares, 0, (&p3_sk).into()),
+            Err(SignatureError::MismatchedCommitmentShare)
+        );
+        assert!(aggregator.get_signers().is_empty());
+
+        assert!(aggregator.include_signer_from_list(3, &p3_public_comshares, 0, (&p3_sk).into()).is_ok());
+        assert_eq!(
+            aggregator.include_signer_from_list(2, &p2_public_comshares, 1, (&p2_sk).into()),
+            Err(SignatureError::MissingCommitmentShares)
+        );
+        assert!(aggregator.include_signer_from_list(2, &p2_public_comshares, 0, (&p2_sk).into()).is_ok());
+        assert_eq!(aggregator.get_signers().len(), 2);
+
+        // Signer 3 cannot sign with signer 2's secret commitment shares either.
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+
+        assert_eq!(
+            p3_sk.sign(&message_hash, &group_key, &mut p2_secret_comshares, 0, &signers),
+            Err(SignatureError::MismatchedCommitmentShare)
+        );
+        assert_eq!(p2_secret_comshares.commitments.len(), 1);
+    }
+
+    #[test]
+    fn signing_quorum() {
+        let params = Parameters { n: 5, t: 3 };
+
+        let quorum = SigningQuorum::new(&params, &[5, 2, 4, 2, 1]).unwrap();
+
+        assert_eq!(quorum.signers(), &[1, 2, 4]);
+
+        for index in quorum.signers().iter() {
+            assert_eq!(
+                quorum.lagrange_coefficient(*index),
+                Some(calculate_lagrange_coefficients(index, &[1, 2, 4]).unwrap())
+            );
+        }
+        assert_eq!(quorum.lagrange_coefficient(5), None);
+
+        // The coefficients interpolate the constant term of a polynomial.
+        let secret = Scalar::from(42u32);
+        let slope = Scalar::from(7u32);
+        let interpolated: Scalar = quorum.signers()
+            .iter()
+            .map(|index| (secret + slope * Scalar::from(*index)) * quorum.lagrange_coefficient(*index).unwrap())
+            .sum();
+        assert_eq!(interpolated, secret);
+
+        assert_eq!(SigningQuorum::new(&params, &[1, 2, 2]), Err(SignatureError::NotEnoughSigners(2, 3)));
+        assert_eq!(SigningQuorum::new(&params, &[0, 1, 2]), Err(SignatureError::InvalidParticipantIndex(0)));
+    }
+
+    #[test]
+    fn incremental_partial_signatures() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p2_sk = secret_keys[1].clone();
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+        let (p2_public_comshares, mut p2_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 2, 1);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+
+        let p1_partial = p1_sk.sign(&message_hash, &group_key, &mut p1_secret_comshares, 0, &signers).unwrap();
+        let mut p2_partial = p2_sk.sign(&message_hash, &group_key, &mut p2_secret_comshares, 0, &signers).unwrap();
+
+        assert!(aggregator.add_partial_signature(p1_partial).is_ok());
+
+        // An invalid partial signature is rejected immediately, naming the bad signer.
+        p2_partial.z += Scalar::one();
+        assert_eq!(
+            aggregator.add_partial_signature(PartialThresholdSignature { index: 2, z: p2_partial.z, nonce_proof: None }),
+            Err(SignatureError::InvalidPartialSignature(2))
+        );
+        assert_eq!(aggregator.get_remaining_signers().len(), 1);
+
+        // As is a partial signature from an unknown signer.
+        assert_eq!(
+            aggregator.add_partial_signature(PartialThresholdSignature { index: 3, z: p2_partial.z, nonce_proof: None }),
+            Err(SignatureError::InvalidPartialSignature(3))
+        );
+
+        p2_partial.z -= Scalar::one();
+        assert!(aggregator.add_partial_signature(p2_partial).is_ok());
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+
+        assert!(threshold_signature.verify(&group_key, &message_hash).is_ok());
+    }
+
+    #[test]
+    fn finalize_requires_threshold_signers() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let p1_sk = SecretKey{ index: 1, key: Scalar::random(&mut OsRng) };
+        let group_key = GroupKey::new(RistrettoPoint::identity());
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+        let p1_partial = p1_sk.sign(&message_hash, &group_key, &mut p1_secret_comshares, 0, &signers).unwrap();
+
+        aggregator.add_partial_signature(p1_partial).unwrap();
+
+        let misbehaving_participants = aggregator.finalize().unwrap_err();
+        assert_eq!(misbehaving_participants.get(&0), Some(&"Not enough signers"));
+    }
+
+    #[test]
+    fn lagrange_coefficients_constant_time() {
+        let quorums: [&[u32]; 6] = [
+            &[1, 2],
+            &[1, 2, 3],
+            &[2, 5, 7],
+            &[3, 1, 4, 10],
+            &[6, 9, 12, 15, 42],
+            &[1, 2, 2],
+        ];
+
+        for quorum in quorums.iter() {
+            for index in quorum.iter() {
+                assert_eq!(
+                    calculate_lagrange_coefficients(index, quorum),
+                    calculate_lagrange_coefficients_constant_time(index, quorum),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn group_commitment_two_signers() {
+        let mut rng = OsRng;
+
+        let commitments: Vec<(u32, RistrettoPoint, RistrettoPoint)> = vec![
+            (1, RistrettoPoint::random(&mut rng), RistrettoPoint::random(&mut rng)),
+            (3, RistrettoPoint::random(&mut rng), RistrettoPoint::random(&mut rng)),
+        ];
+        let mut binding_factors: BTreeMap<u32, Scalar> = BTreeMap::new();
+        binding_factors.insert(1, Scalar::random(&mut rng));
+        binding_factors.insert(3, Scalar::random(&mut rng));
+
+        let expected = commitments[0].1 + (binding_factors[&1] * commitments[0].2) +
+            commitments[1].1 + (binding_factors[&3] * commitments[1].2);
+
+        assert_eq!(compute_group_commitment(&commitments, &binding_factors).unwrap(), expected);
+
+        binding_factors.remove(&3);
+        assert_eq!(
+            compute_group_commitment(&commitments, &binding_factors),
+            Err(SignatureError::InvalidBindingFactor)
+        );
+    }
+
+    #[test]
+    fn cfrg_binding_factors() {
+        let group_key = GroupKey::new(&Scalar::from(42u64) * &RISTRETTO_BASEPOINT_TABLE);
+        let message = b"test";
+
+        let point = |x: u64| &Scalar::from(x) * &RISTRETTO_BASEPOINT_TABLE;
+        let commitments: Vec<(u32, RistrettoPoint, RistrettoPoint)> = vec![
+            (3, point(5), point(6)),
+            (1, point(3), point(4)),
+        ];
+
+        let binding_factors = compute_binding_factors(message, &commitments, &group_key);
+        assert_eq!(binding_factors.len(), 2);
+
+        // The values of the binding factors are checked against the test
+        // vectors of RFC 9591 in the cfrg module.
+
+        // The binding factors do not depend on the order of the commitments.
+        let reversed: Vec<(u32, RistrettoPoint, RistrettoPoint)> = commitments.iter().rev().cloned().collect();
+        assert_eq!(compute_binding_factors(message, &reversed, &group_key), binding_factors);
+
+        // But they do depend on the message.
+        assert_ne!(compute_binding_factors(b"other", &commitments, &group_key), binding_factors);
+    }
+
+    #[test]
+    fn aggregator_is_also_signer() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p3_sk = secret_keys[2].clone();
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+        let (p3_public_comshares, mut p3_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 3, 1);
+
+        // The aggregator is signer 1.
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        aggregator.include_signer_from_list(1, &p1_public_comshares, 0, (&p1_sk).into()).unwrap();
+        aggregator.include_signer_from_list(3, &p3_public_comshares, 0, (&p3_sk).into()).unwrap();
+        assert_eq!(
+            aggregator.include_signer_from_list(1, &p1_public_comshares, 0, (&p1_sk).into()),
+            Err(SignatureError::DuplicateSigner(1))
+        );
+        assert_eq!(aggregator.get_signers().len(), 2);
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+        let p3_partial = p3_sk.sign(&message_hash, &group_key, &mut p3_secret_comshares, 0, &signers).unwrap();
+
+        aggregator.include_own_partial_signature(&p1_sk, &mut p1_secret_comshares, 0).unwrap();
+        let p3_partial_copy = PartialThresholdSignature { index: p3_partial.index, z: p3_partial.z, nonce_proof: None };
+        assert!(aggregator.add_partial_signature(p3_partial).is_ok());
+
+        // Neither partial signature can be counted twice.
+        assert_eq!(
+            aggregator.include_own_partial_signature(&p1_sk, &mut p1_secret_comshares, 0),
+            Err(SignatureError::DuplicateSigner(1))
+        );
+        assert_eq!(
+            aggregator.add_partial_signature(p3_partial_copy),
+            Err(SignatureError::DuplicateSigner(3))
+        );
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+
+        assert!(threshold_signature.verify(&group_key, &message_hash).is_ok());
+    }
+
+    #[test]
+    fn group_key_negation_convention() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p2_sk = secret_keys[1].clone();
+
+        assert_eq!(group_key.negate().negate(), group_key);
+        assert_ne!(group_key.negate(), group_key);
+        assert_eq!(group_key.negate().0, -group_key.0);
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+        let (p2_public_comshares, mut p2_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 2, 1);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+
+        let p1_partial = p1_sk.sign(&message_hash, &group_key, &mut p1_secret_comshares, 0, &signers).unwrap();
+        let p2_partial = p2_sk.sign(&message_hash, &group_key, &mut p2_secret_comshares, 0, &signers).unwrap();
+
+        aggregator.include_partial_signature(p1_partial);
+        aggregator.include_partial_signature(p2_partial);
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+
+        // Signatures verify under the group key as computed, never its negation.
+        assert!(threshold_signature.verify(&group_key, &message_hash).is_ok());
+        assert!(threshold_signature.verify(&group_key.negate(), &message_hash).is_err());
+    }
+
+    #[test]
+    fn sign_partial_with_quorum() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p3_sk = secret_keys[2].clone();
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+        let (p3_public_comshares, mut p3_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 3, 1);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(3, p3_public_comshares.commitments[0], (&p3_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+        let quorum = SigningQuorum::new(&params, &[3, 1]).unwrap();
+
+        // The quorum must be made of the participating signers.
+        let other_quorum = SigningQuorum::new(&params, &[1, 2]).unwrap();
+        assert_eq!(
+            p1_sk.sign_partial(&mut p1_secret_comshares, 0, &signers, &other_quorum, &message_hash, &group_key),
+            Err(SignatureError::MismatchedSigningQuorum)
+        );
+        // The commitment share must belong to the signer.
+        assert_eq!(
+            p1_sk.sign_partial(&mut p3_secret_comshares, 0, &signers, &quorum, &message_hash, &group_key),
+            Err(SignatureError::MismatchedCommitmentShare)
+        );
+        assert_eq!(
+            p1_sk.sign_partial(&mut p1_secret_comshares, 1, &signers, &quorum, &message_hash, &group_key),
+            Err(SignatureError::MissingCommitmentShares)
+        );
+
+        let p1_partial = p1_sk.sign_partial(&mut p1_secret_comshares, 0, &signers, &quorum, &message_hash, &group_key).unwrap();
+        let p3_partial = p3_sk.sign_partial(&mut p3_secret_comshares, 0, &signers, &quorum, &message_hash, &group_key).unwrap();
+
+        // The commitment shares are consumed, and cannot be used twice.
+        assert!(p1_secret_comshares.commitments.is_empty());
+        assert_eq!(
+            p1_sk.sign_partial(&mut p1_secret_comshares, 0, &signers, &quorum, &message_hash, &group_key),
+            Err(SignatureError::MissingCommitmentShares)
+        );
+
+        aggregator.add_partial_signature(p1_partial).unwrap();
+        aggregator.add_partial_signature(p3_partial).unwrap();
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+
+        assert!(threshold_signature.verify(&group_key, &message_hash).is_ok());
+    }
+
+    #[test]
+    fn partial_signature_verify_pinpoints_signer() {
+        let params = Parameters { n: 3, t: 3 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+        let mut secret_comshares = Vec::new();
+        for secret_key in secret_keys.iter() {
+            let (public_comshares, secret) = generate_commitment_share_lists(&mut OsRng, secret_key.index, 1);
+            aggregator.include_signer(secret_key.index, public_comshares.commitments[0], secret_key.into()).unwrap();
+            secret_comshares.push(secret);
+        }
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+        let quorum = SigningQuorum::new(&params, &[1, 2, 3]).unwrap();
+
+        let mut partials: Vec<PartialThresholdSignature> = secret_keys
+            .iter()
+            .zip(secret_comshares.iter_mut())
+            .map(|(secret_key, secret)| {
+                secret_key.sign_partial(secret, 0, &signers, &quorum, &message_hash, &group_key).unwrap()
+            })
+            .collect();
+
+        // Corrupt the partial signature of signer 3.
+        partials[2].z += Scalar::one();
+
+        for (partial, secret_key) in partials.iter().zip(secret_keys.iter()) {
+            let public_key: IndividualPublicKey = secret_key.into();
+            let result = partial.verify(&signers, &public_key, &quorum, &message_hash, &group_key);
+
+            if partial.index == 3 {
+                assert_eq!(result, Err(SignatureError::InvalidPartialSignature(3)));
+            } else {
+                assert!(result.is_ok());
+            }
+        }
+
+        // A public key of another signer does not verify a valid partial signature.
+        let public_key: IndividualPublicKey = (&secret_keys[1]).into();
+        assert!(partials[0].verify(&signers, &public_key, &quorum, &message_hash, &group_key).is_err());
+    }
+
+    #[test]
+    fn lagrange_coefficients_batch() {
+        let quorums: [&[u32]; 5] = [
+            &[1, 2],
+            &[1, 2, 3],
+            &[2, 5, 7],
+            &[3, 1, 4, 10],
+            &[6, 9, 12, 15, 42],
+        ];
+
+        for quorum in quorums.iter() {
+            let coefficients = lagrange_coefficients_for(quorum).unwrap();
+            assert_eq!(coefficients.len(), quorum.len());
+
+            for (index, (coefficient_index, coefficient)) in quorum.iter().zip(coefficients.iter()) {
+                assert_eq!(index, coefficient_index);
+                assert_eq!(*coefficient, calculate_lagrange_coefficients(index, quorum).unwrap());
+            }
+
+            // The coefficients interpolate a constant polynomial to itself.
+            let sum: Scalar = coefficients.iter().map(|(_, coefficient)| coefficient).sum();
+            assert_eq!(sum, Scalar::one());
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn lagrange_coefficient_of_absent_index() {
+        let _ = calculate_lagrange_coefficients(&4, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn partial_signature_with_nonce_proof() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p2_sk = secret_keys[1].clone();
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+        let (p2_public_comshares, mut p2_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 2, 2);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+        let quorum = SigningQuorum::new(&params, &[1, 2]).unwrap();
+
+        let p1_partial = p1_sk.sign_partial_with_nonce_proof(
+            &mut p1_secret_comshares, 0, &signers, &quorum, &message_hash, &group_key, &mut OsRng).unwrap();
+        let p2_partial = p2_sk.sign_partial_with_nonce_proof(
+            &mut p2_secret_comshares, 0, &signers, &quorum, &message_hash, &group_key, &mut OsRng).unwrap();
+
+        let p1_proof = p1_partial.nonce_proof().unwrap();
+        assert!(p1_proof.verify(&1, &p1_public_comshares.commitments[0], &message_hash).is_ok());
+        assert!(p1_proof.verify(&2, &p1_public_comshares.commitments[0], &message_hash).is_err());
+        assert_eq!(NizkOfNonces::from_bytes(&p1_proof.to_bytes()).unwrap(), *p1_proof);
+
+        // The proof is part of the encoding of the partial signature.
+        let p1_bytes = p1_partial.to_bytes();
+        assert_eq!(p1_bytes.len(), 133);
+        let p1_partial = PartialThresholdSignature::from_bytes(&p1_bytes).unwrap();
+        assert_eq!(p1_partial.nonce_proof(), Some(p1_proof));
+
+        // A truncated or unflagged proof is rejected.
+        assert!(PartialThresholdSignature::from_bytes(&p1_bytes[..132]).is_err());
+        let mut unflagged = p1_bytes.clone();
+        unflagged[36] = 2;
+        assert!(PartialThresholdSignature::from_bytes(&unflagged).is_err());
+
+        // A proof for nonces other than the ones behind the published commitment
+        // share is rejected, even though the partial signature itself is valid.
+        let unused_share = &p2_secret_comshares.commitments[0];
+        let wrong_proof = NizkOfNonces::prove(
+            &2,
+            &unused_share.hiding.nonce,
+            &unused_share.binding.nonce,
+            &p2_public_comshares.commitments[0],
+            &message_hash,
+            &mut OsRng,
+        );
+        let p2_tampered = PartialThresholdSignature { index: 2, z: p2_partial.z, nonce_proof: Some(wrong_proof) };
+        assert_eq!(aggregator.add_partial_signature(p2_tampered), Err(SignatureError::InvalidPartialSignature(2)));
+
+        aggregator.add_partial_signature(p1_partial).unwrap();
+        aggregator.add_partial_signature(p2_partial).unwrap();
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+
+        assert!(threshold_signature.verify(&group_key, &message_hash).is_ok());
+    }
+
+    #[test]
+    fn single_participant_degenerate_path() {
+        let params = Parameters { n: 1, t: 1 };
+        let mut rng = OsRng;
+
+        // The Lagrange coefficient of a singleton set is exactly one, whatever the index.
+        for index in [1u32, 2, 7, u32::MAX] {
+            assert_eq!(calculate_lagrange_coefficients(&index, &[index]), Ok(Scalar::one()));
+            assert_eq!(calculate_lagrange_coefficients_constant_time(&index, &[index]), Ok(Scalar::one()));
+            assert_eq!(lagrange_coefficients_for(&[index]), Ok(vec![(index, Scalar::one())]));
+        }
+
+        let (p1, p1coeffs, p1_dh_sk) = Participant::new_dealer(&params, 1, "Φ", &mut rng);
+        let participants: Vec<Participant> = vec![p1.clone()];
+        let (p1_state, _participant_lists) = DistributedKeyGeneration::<RoundOne>::new_initial(
+            &params, &p1_dh_sk, &p1.index, &p1coeffs, &participants, "Φ", &mut rng).unwrap();
+        let p1_my_encrypted_secret_shares = p1_state.their_encrypted_secret_shares().unwrap().clone();
+        let (group_key, p1_sk) = p1_state.to_round_two(p1_my_encrypted_secret_shares, &mut rng).unwrap().finish().unwrap();
+
+        // The single share is the group secret key itself.
+        assert_eq!(&RISTRETTO_BASEPOINT_TABLE * &p1_sk.key, group_key.0);
+
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+
+        let quorum = SigningQuorum::new(&params, &[1]).unwrap();
+        assert_eq!(quorum.signers(), &[1]);
+        assert_eq!(quorum.lagrange_coefficient(1), Some(Scalar::one()));
+
+        // The partial signature goes through the verified path of the aggregator.
+        let p1_partial = p1_sk.sign_partial(
+            &mut p1_secret_comshares, 0, &signers, &quorum, &message_hash, &group_key).unwrap();
+        aggregator.add_partial_signature(p1_partial).unwrap();
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+
+        assert!(threshold_signature.verify(&group_key, &message_hash).is_ok());
+        assert_eq!(ThresholdSignature::from_bytes(&threshold_signature.to_bytes()).unwrap(), threshold_signature);
+    }
+
+    #[test]
+    fn prehashed_signing() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+        let prehash = compute_message_hash(&[], &message[..]);
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p2_sk = secret_keys[1].clone();
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+        let (p2_public_comshares, mut p2_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 2, 1);
+
+        let mut aggregator = SignatureAggregator::new_prehashed(params, group_key, &context[..], &prehash[..]);
+
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_prehashed_message_hash(&context[..], &prehash[..]);
+        assert_ne!(message_hash, compute_message_hash(&context[..], &prehash[..]));
+
+        let p1_partial = p1_sk.sign(&message_hash, &group_key, &mut p1_secret_comshares, 0, &signers).unwrap();
+        let p2_partial = p2_sk.sign(&message_hash, &group_key, &mut p2_secret_comshares, 0, &signers).unwrap();
+
+        // The aggregator checks the partial signatures against the prehashed message hash.
+        aggregator.add_partial_signature(p1_partial).unwrap();
+        aggregator.add_partial_signature(p2_partial).unwrap();
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+
+        assert!(threshold_signature.verify_prehashed(&group_key, &context[..], &prehash[..]).is_ok());
+        assert!(threshold_signature.verify_prehashed(&group_key, &b"other context"[..], &prehash[..]).is_err());
+
+        // It is not a signature on the prehash, nor on the message, as plain messages.
+        assert_eq!(
+            threshold_signature.verify(&group_key, &compute_message_hash(&context[..], &prehash[..])),
+            Err(SignatureError::InvalidSignature)
+        );
+        assert_eq!(
+            threshold_signature.verify(&group_key, &compute_message_hash(&context[..], &message[..])),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn group_commitment_from_public_lists() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p3_sk = secret_keys[2].clone();
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 2);
+        let (p3_public_comshares, mut p3_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 3, 2);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        aggregator.include_signer(3, p3_public_comshares.commitments[1], (&p3_sk).into()).unwrap();
+        aggregator.include_signer(1, p1_public_comshares.commitments[1], (&p1_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+
+        let p1_partial = p1_sk.sign(&message_hash, &group_key, &mut p1_secret_comshares, 1, &signers).unwrap();
+        let p3_partial = p3_sk.sign(&message_hash, &group_key, &mut p3_secret_comshares, 1, &signers).unwrap();
+
+        aggregator.add_partial_signature(p1_partial).unwrap();
+        aggregator.add_partial_signature(p3_partial).unwrap();
+
+        let aggregator = aggregator.finalize().unwrap();
+        let threshold_signature = aggregator.aggregate().unwrap();
+        assert!(threshold_signature.verify(&group_key, &message_hash).is_ok());
+
+        let mut lists = vec![p3_public_comshares, p1_public_comshares];
+        let R = compute_group_commitment_from_lists(&message_hash, &lists, 1).unwrap();
+        assert_eq!(R.compress(), threshold_signature.R.compress());
+
+        // The commitments at another position yield another group commitment.
+        let other_R = compute_group_commitment_from_lists(&message_hash, &lists, 0).unwrap();
+        assert_ne!(other_R.compress(), R.compress());
+
+        assert_eq!(
+            compute_group_commitment_from_lists(&message_hash, &lists, 2),
+            Err(SignatureError::MissingCommitmentShares)
+        );
+
+        lists[0].participant_index = 1;
+        assert_eq!(
+            compute_group_commitment_from_lists(&message_hash, &lists, 1),
+            Err(SignatureError::DuplicateSigner(1))
+        );
+    }
+
+    #[test]
+    fn signature_aggregator_resumes_from_bytes() {
+        let params = Parameters { n: 3, t: 2 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+
+        let (group_key, secret_keys) = trusted_dealer_keys(&params, &mut OsRng);
+        let p1_sk = secret_keys[0].clone();
+        let p2_sk = secret_keys[1].clone();
+
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+        let (p2_public_comshares, mut p2_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 2, 1);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+        aggregator.include_signer(2, p2_public_comshares.commitments[0], (&p2_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+
+        let p1_partial = p1_sk.sign(&message_hash, &group_key, &mut p1_secret_comshares, 0, &signers).unwrap();
+        let p2_partial = p2_sk.sign(&message_hash, &group_key, &mut p2_secret_comshares, 0, &signers).unwrap();
+        let p1_partial_bytes = p1_partial.to_bytes();
+        let p2_partial_bytes = p2_partial.to_bytes();
+
+        aggregator.add_partial_signature(p1_partial).unwrap();
+
+        // Persist the aggregator mid-collection, and resume from its serialisation.
+        let bytes = aggregator.to_bytes();
+        let mut resumed = SignatureAggregator::from_bytes(&bytes).unwrap();
+        assert_eq!(resumed.to_bytes(), bytes);
+        assert_eq!(resumed.get_remaining_signers().len(), 1);
+
+        aggregator.add_partial_signature(p2_partial).unwrap();
+        resumed.add_partial_signature(PartialThresholdSignature::from_bytes(&p2_partial_bytes).unwrap()).unwrap();
+
+        // The partial signature collected before the restart is still there.
+        assert_eq!(
+            resumed.add_partial_signature(PartialThresholdSignature::from_bytes(&p1_partial_bytes).unwrap()),
+            Err(SignatureError::DuplicateSigner(1))
+        );
+
+        let threshold_signature = aggregator.finalize().unwrap().aggregate().unwrap();
+        let resumed_threshold_signature = resumed.finalize().unwrap().aggregate().unwrap();
+
+        assert_eq!(threshold_signature, resumed_threshold_signature);
+        assert!(resumed_threshold_signature.verify(&group_key, &message_hash).is_ok());
+
+        assert!(SignatureAggregator::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(SignatureAggregator::from_bytes(&extended).is_err());
+    }
+
+    #[test]
+    fn finalize_requires_partial_signatures_from_signers_only() {
+        let params = Parameters { n: 3, t: 1 };
+        let context = b"CONTEXT STRING STOLEN FROM DALEK TEST SUITE";
+        let message = b"This is a test of the tsunami alert system. This is only a test.";
+
+        let p1_sk = SecretKey{ index: 1, key: Scalar::random(&mut OsRng) };
+        let group_key = GroupKey::new(&RISTRETTO_BASEPOINT_TABLE * &p1_sk.key);
+        let (p1_public_comshares, mut p1_secret_comshares) = generate_commitment_share_lists(&mut OsRng, 1, 1);
+
+        let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
+        aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+
+        let signers = aggregator.get_signers().clone();
+        let message_hash = compute_message_hash(&context[..], &message[..]);
+        let p1_partial = p1_sk.sign(&message_hash, &group_key, &mut p1_secret_comshares, 0, &signers).unwrap();
+
+        aggregator.add_partial_signature(p1_partial).unwrap();
+        aggregator.include_partial_signature(PartialThresholdSignature { index: 2, z: Scalar::one(), nonce_proof: None });
+
+        let misbehaving_participants = aggregator.finalize().unwrap_err();
+        assert_eq!(misbehaving_participants.get(&2), Some(&"Partial signature from a non-signer"));
+        assert!(!misbehaving_participants.contains_key(&1));
+    }
 }
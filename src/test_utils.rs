@@ -0,0 +1,285 @@
+// -*- mode: rust; -*-
+//
+// This file is part of ice-frost.
+// Copyright (c) 2021-2022 Toposware Inc.
+// See LICENSE for licensing information.
+//
+// Authors:
+// - Toposware developers <dev@toposware.com>
+
+//! Fixtures shared by the unit tests of the different modules.
+
+use std::vec::Vec;
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_TABLE;
+use curve25519_dalek::scalar::Scalar;
+
+use rand::CryptoRng;
+use rand::RngCore;
+
+use crate::keygen::collect_shares_for;
+use crate::keygen::Coefficients;
+use crate::keygen::DHPrivateKey;
+use crate::keygen::DistributedKeyGeneration;
+use crate::keygen::EncryptedSecretShare;
+use crate::keygen::GroupKey;
+use crate::keygen::Participant;
+use crate::keygen::RoundOne;
+use crate::keygen::RoundTwo;
+use crate::keygen::SecretKey;
+use crate::nizk::DkgHash;
+use crate::parameters::Parameters;
+
+/// Deal the secret keys of the `parameters.n` participants of a group with a
+/// trusted dealer, as the evaluations at their indices of a random secret
+/// polynomial of degree \\(t - 1\\).
+///
+/// # Returns
+///
+/// The group key, and the secret keys of the participants with indices 1
+/// to \\(n\\), in this order.
+pub(crate) fn trusted_dealer_keys(
+    parameters: &Parameters,
+    mut rng: impl RngCore + CryptoRng,
+) -> (GroupKey, Vec<SecretKey>) {
+    let coefficients: Vec<Scalar> = (0..parameters.t).map(|_| Scalar::random(&mut rng)).collect();
+    let group_key = GroupKey::new(&RISTRETTO_BASEPOINT_TABLE * &coefficients[0]);
+
+    let secret_keys = (1..=parameters.n)
+        .map(|index| {
+            // Evaluate the polynomial using Horner's method.
+            let x = Scalar::from(index);
+            let key = coefficients.iter().rev().fold(Scalar::zero(), |sum, coefficient| sum * x + coefficient);
+
+            SecretKey { index, key }
+        })
+        .collect();
+
+    (group_key, secret_keys)
+}
+
+/// Construct the `parameters.n` dealers of a key generation with the given
+/// `context_string`.
+///
+/// # Returns
+///
+/// The broadcast, the secret coefficients and the DH private key of each
+/// dealer, in the order of their indices.
+pub(crate) fn new_dealers(
+    parameters: &Parameters,
+    context_string: &str,
+    mut rng: impl RngCore + CryptoRng,
+) -> Vec<(Participant, Coefficients, DHPrivateKey)> {
+    (1..=parameters.n)
+        .map(|i| Participant::new_dealer(parameters, i, context_string, &mut rng))
+        .collect()
+}
+
+/// Run the first round of a key generation for each of the `dealers`, with
+/// the broadcasts of all of them as participants.
+///
+/// # Returns
+///
+/// The states of the dealers in the first round, in the same order.
+pub(crate) fn new_initial_states(
+    parameters: &Parameters,
+    dealers: &[(Participant, Coefficients, DHPrivateKey)],
+    context_string: &str,
+    mut rng: impl RngCore + CryptoRng,
+) -> Vec<DistributedKeyGeneration<RoundOne>> {
+    let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+
+    dealers
+        .iter()
+        .map(|(p, coefficients, dh_sk)| {
+            DistributedKeyGeneration::<RoundOne>::new_initial(
+                parameters, dh_sk, &p.index, coefficients, &participants, context_string, &mut rng).unwrap().0
+        })
+        .collect()
+}
+
+/// Gather the encrypted shares computed by each of the round one `states`,
+/// to be dispatched to their receivers with [`collect_shares_for`].
+pub(crate) fn all_encrypted_shares(states: &[DistributedKeyGeneration<RoundOne>]) -> Vec<Vec<EncryptedSecretShare>> {
+    states
+        .iter()
+        .map(|state| state.their_encrypted_secret_shares().unwrap().clone())
+        .collect()
+}
+
+/// Construct `parameters.n` dealers for an honest key generation with the
+/// given `context_string`, and run the first round for each of them.
+///
+/// # Returns
+///
+/// The broadcasts of the participants, their DH private keys, and their
+/// states in the first round, all in the order of their indices.
+pub(crate) fn run_round_one(
+    parameters: &Parameters,
+    context_string: &str,
+    mut rng: impl RngCore + CryptoRng,
+) -> (Vec<Participant>, Vec<DHPrivateKey>, Vec<DistributedKeyGeneration<RoundOne>>) {
+    let dealers = new_dealers(parameters, context_string, &mut rng);
+    let states = new_initial_states(parameters, &dealers, context_string, &mut rng);
+    let (participants, dh_private_keys) = dealers.into_iter().map(|(p, _, dh_sk)| (p, dh_sk)).unzip();
+
+    (participants, dh_private_keys, states)
+}
+
+/// Run the first round of an honest key generation between `parameters.n`
+/// dealers with the given `context_string`, and move every participant to
+/// the second round with the shares it was sent.
+///
+/// # Returns
+///
+/// The broadcasts of the participants, and their states in the second
+/// round, both in the order of their indices.
+pub(crate) fn run_dkg(
+    parameters: &Parameters,
+    context_string: &str,
+    mut rng: impl RngCore + CryptoRng,
+) -> (Vec<Participant>, Vec<DistributedKeyGeneration<RoundTwo>>) {
+    let (participants, _dh_private_keys, states) = run_round_one(parameters, context_string, &mut rng);
+
+    let all_dealers_shares = all_encrypted_shares(&states);
+
+    let states = states
+        .into_iter()
+        .zip(participants.iter())
+        .map(|(state, p)| state.to_round_two(collect_shares_for(p.index, &all_dealers_shares), &mut rng).unwrap())
+        .collect();
+
+    (participants, states)
+}
+
+/// Copy a round one `state` into a new one, which does not share with it the
+/// flag recording that it was advanced, so that several scenarios can be
+/// replayed from the same state.
+pub(crate) fn replayable<H: DkgHash>(state: &DistributedKeyGeneration<RoundOne, H>) -> DistributedKeyGeneration<RoundOne, H> {
+    DistributedKeyGeneration::<RoundOne, H>::from_bytes(&state.to_bytes()).unwrap()
+}
+
+/// A deterministic RNG, to replay the randomness consumed by the protocol.
+/// It is obviously not cryptographically secure, and only meant for tests.
+pub(crate) struct ControlledRng(pub(crate) u64);
+
+impl RngCore for ControlledRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ControlledRng {}
+
+/// An RNG which never provides any randomness, to drive the error paths of
+/// the functions drawing it fallibly, or to check that a code path does not
+/// consume any randomness.
+///
+/// Its infallible methods panic, since they have no way to report the failure.
+pub(crate) struct FailingRng;
+
+impl RngCore for FailingRng {
+    fn next_u32(&mut self) -> u32 {
+        panic!("FailingRng was used")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        panic!("FailingRng was used")
+    }
+
+    fn fill_bytes(&mut self, _dest: &mut [u8]) {
+        panic!("FailingRng was used")
+    }
+
+    fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), rand::Error> {
+        // This unwrap() cannot fail, since CUSTOM_START is not zero.
+        Err(core::num::NonZeroU32::new(rand::Error::CUSTOM_START).unwrap().into())
+    }
+}
+
+impl CryptoRng for FailingRng {}
+
+/// An RNG recording every byte drawn from the inner one.
+pub(crate) struct RecordingRng<R: RngCore> {
+    pub(crate) inner: R,
+    pub(crate) drawn: Vec<u8>,
+}
+
+impl<R: RngCore> RngCore for RecordingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.inner.fill_bytes(dest);
+        self.drawn.extend_from_slice(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.inner.try_fill_bytes(dest)?;
+        self.drawn.extend_from_slice(dest);
+        Ok(())
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for RecordingRng<R> {}
+
+/// An RNG serving a precomputed stream of bytes, and panicking once exhausted.
+pub(crate) struct StreamRng<'a>(pub(crate) &'a [u8]);
+
+impl<'a> RngCore for StreamRng<'a> {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        assert!(dest.len() <= self.0.len(), "StreamRng was exhausted");
+        let (head, tail) = self.0.split_at(dest.len());
+        dest.copy_from_slice(head);
+        self.0 = tail;
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<'a> CryptoRng for StreamRng<'a> {}
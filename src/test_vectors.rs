@@ -0,0 +1,277 @@
+// -*- mode: rust; -*-
+//
+// This file is part of ice-frost.
+// Copyright (c) 2021-2022 Toposware Inc.
+// See LICENSE for licensing information.
+//
+// Authors:
+// - Toposware developers <dev@toposware.com>
+
+//! Deterministic test vectors, locking down the wire format and the
+//! cryptographic outputs of the key generation.
+//!
+//! A [`DkgTranscript`] is generated from a fixed seed, and serialised to a
+//! stable blob which is checked into the repository. Any change to the
+//! serialisations, the hashing or the consumption of randomness alters the
+//! transcript, and makes the tests below fail.
+//!
+//! After an intentional change, the vector files under `tests/vectors` can be
+//! overwritten with regenerated ones with
+//!
+//! ```text
+//! cargo test --lib test_vectors::test::generate -- --ignored
+//! ```
+
+use std::string::String;
+use std::vec::Vec;
+
+use core::convert::TryInto;
+
+use crate::keygen::collect_shares_for;
+use crate::keygen::DistributedKeyGeneration;
+use crate::keygen::EncryptedSecretShare;
+use crate::keygen::Error;
+use crate::keygen::GroupKey;
+use crate::keygen::Participant;
+use crate::keygen::RoundOne;
+use crate::keygen::SecretKey;
+use crate::parameters::Parameters;
+use crate::test_utils::ControlledRng;
+
+/// The serialised outputs of a key generation between dealers which are also
+/// the signers.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct DkgTranscript {
+    pub(crate) seed: u64,
+    pub(crate) parameters: Parameters,
+    /// The serialised participants, sorted by index.
+    pub(crate) participants: Vec<Vec<u8>>,
+    /// The serialised encrypted shares, dealer by dealer.
+    pub(crate) encrypted_shares: Vec<[u8; 56]>,
+    pub(crate) group_key: [u8; 32],
+    /// The serialised secret keys, sorted by index.
+    pub(crate) secret_keys: Vec<[u8; 36]>,
+}
+
+impl DkgTranscript {
+    /// Run a key generation with the given `parameters`, drawing all the
+    /// randomness from a [`ControlledRng`] seeded with `seed`.
+    pub(crate) fn generate(parameters: &Parameters, seed: u64) -> Result<DkgTranscript, Error> {
+        let mut rng = ControlledRng(seed);
+
+        let dealers: Vec<_> = (1..=parameters.n)
+            .map(|i| Participant::new_dealer(parameters, i, "Φ", &mut rng))
+            .collect();
+        let participants: Vec<Participant> = dealers.iter().map(|(p, _, _)| p.clone()).collect();
+
+        let mut states = Vec::with_capacity(dealers.len());
+        for (p, coefficients, dh_sk) in dealers.iter() {
+            let (state, _) = DistributedKeyGeneration::<RoundOne>::new_initial(
+                parameters, dh_sk, &p.index, coefficients, &participants, "Φ", &mut rng)?;
+            states.push(state);
+        }
+
+        let mut all_dealers_shares: Vec<Vec<EncryptedSecretShare>> = Vec::with_capacity(states.len());
+        for state in states.iter() {
+            all_dealers_shares.push(state.their_encrypted_secret_shares()?.clone());
+        }
+
+        let mut group_key: Option<GroupKey> = None;
+        let mut secret_keys: Vec<SecretKey> = Vec::with_capacity(states.len());
+        for (state, p) in states.into_iter().zip(participants.iter()) {
+            let shares = collect_shares_for(p.index, &all_dealers_shares);
+            let (key, secret_key) = state.to_round_two(shares, &mut rng)?.finish()?;
+
+            if matches!(group_key, Some(previous) if previous != key) {
+                return Err(Error::InvalidGroupKey);
+            }
+            group_key = Some(key);
+            secret_keys.push(secret_key);
+        }
+
+        Ok(DkgTranscript {
+            seed,
+            parameters: *parameters,
+            participants: participants.iter().map(|p| p.to_bytes()).collect(),
+            encrypted_shares: all_dealers_shares.iter().flatten().map(|share| share.to_bytes()).collect(),
+            group_key: group_key.ok_or(Error::InvalidGroupKey)?.to_bytes(),
+            secret_keys: secret_keys.iter().map(|secret_key| secret_key.to_bytes()).collect(),
+        })
+    }
+
+    /// Serialise this transcript to a Vec of bytes, each list being prefixed
+    /// by its length and each participant by its size.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::new();
+        res.extend_from_slice(&self.seed.to_le_bytes());
+        res.extend_from_slice(&self.parameters.to_bytes());
+
+        res.extend_from_slice(&(self.participants.len() as u32).to_le_bytes());
+        for participant in self.participants.iter() {
+            res.extend_from_slice(&(participant.len() as u32).to_le_bytes());
+            res.extend_from_slice(participant);
+        }
+
+        res.extend_from_slice(&(self.encrypted_shares.len() as u32).to_le_bytes());
+        for share in self.encrypted_shares.iter() {
+            res.extend_from_slice(share);
+        }
+
+        res.extend_from_slice(&self.group_key);
+
+        res.extend_from_slice(&(self.secret_keys.len() as u32).to_le_bytes());
+        for secret_key in self.secret_keys.iter() {
+            res.extend_from_slice(secret_key);
+        }
+
+        res
+    }
+
+    /// Deserialise this slice of bytes to a `DkgTranscript`, checking that
+    /// every item it holds deserialises to the same bytes.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<DkgTranscript, Error> {
+        let mut reader = Reader { bytes, position: 0 };
+
+        let seed = u64::from_le_bytes(reader.read(8)?.try_into().map_err(|_| Error::SerialisationError)?);
+        let parameters = Parameters::from_bytes(&reader.read(8)?.try_into().map_err(|_| Error::SerialisationError)?)?;
+
+        let mut participants = Vec::new();
+        for _ in 0..reader.read_u32()? {
+            let len = reader.read_u32()? as usize;
+            let participant = reader.read(len)?;
+            if Participant::from_bytes(participant)?.to_bytes() != participant {
+                return Err(Error::SerialisationError);
+            }
+            participants.push(participant.to_vec());
+        }
+
+        let mut encrypted_shares = Vec::new();
+        for _ in 0..reader.read_u32()? {
+            let share: [u8; 56] = reader.read(56)?.try_into().map_err(|_| Error::SerialisationError)?;
+            if EncryptedSecretShare::from_bytes(&share)?.to_bytes() != share {
+                return Err(Error::SerialisationError);
+            }
+            encrypted_shares.push(share);
+        }
+
+        let group_key: [u8; 32] = reader.read(32)?.try_into().map_err(|_| Error::SerialisationError)?;
+        if GroupKey::from_bytes(&group_key)?.to_bytes() != group_key {
+            return Err(Error::SerialisationError);
+        }
+
+        let mut secret_keys = Vec::new();
+        for _ in 0..reader.read_u32()? {
+            let secret_key: [u8; 36] = reader.read(36)?.try_into().map_err(|_| Error::SerialisationError)?;
+            if SecretKey::from_bytes(&secret_key)?.to_bytes() != secret_key {
+                return Err(Error::SerialisationError);
+            }
+            secret_keys.push(secret_key);
+        }
+
+        if reader.position != bytes.len() {
+            return Err(Error::SerialisationError);
+        }
+
+        Ok(DkgTranscript { seed, parameters, participants, encrypted_shares, group_key, secret_keys })
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let slice = self.bytes.get(self.position..self.position + len).ok_or(Error::SerialisationError)?;
+        self.position += len;
+        Ok(slice)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.read(4)?.try_into().map_err(|_| Error::SerialisationError)?))
+    }
+}
+
+/// Check a serialised transcript against the one generated again from its
+/// own seed and parameters.
+pub(crate) fn check(bytes: &[u8]) -> Result<(), Error> {
+    let expected = DkgTranscript::from_bytes(bytes)?;
+    let generated = DkgTranscript::generate(&expected.parameters, expected.seed)?;
+
+    match generated == expected {
+        true => Ok(()),
+        false => Err(Error::Custom(String::from("The generated transcript differs from the test vector"))),
+    }
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| std::format!("{:02x}", byte)).collect()
+}
+
+pub(crate) fn from_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    let hex: Vec<u8> = hex.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+    let pairs = hex.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(Error::SerialisationError);
+    }
+
+    pairs
+        .map(|pair| {
+            let pair = core::str::from_utf8(pair).map_err(|_| Error::SerialisationError)?;
+            u8::from_str_radix(pair, 16).map_err(|_| Error::SerialisationError)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DKG_2_OF_3_SEED: u64 = 0x1ce_f705;
+    const DKG_2_OF_3: &str = include_str!("../tests/vectors/dkg_2_of_3.hex");
+
+    #[test]
+    #[ignore]
+    fn generate() {
+        let transcript = DkgTranscript::generate(&Parameters { n: 3, t: 2 }, DKG_2_OF_3_SEED).unwrap();
+        let hex = to_hex(&transcript.to_bytes());
+
+        let mut contents = String::with_capacity(hex.len() + hex.len() / 64 + 1);
+        for line in hex.as_bytes().chunks(64) {
+            contents.push_str(core::str::from_utf8(line).unwrap());
+            contents.push('\n');
+        }
+
+        std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/vectors/dkg_2_of_3.hex"), contents).unwrap();
+    }
+
+    #[test]
+    fn dkg_2_of_3_vector() {
+        let bytes = from_hex(DKG_2_OF_3).unwrap();
+        let transcript = DkgTranscript::from_bytes(&bytes).unwrap();
+
+        assert_eq!(transcript.seed, DKG_2_OF_3_SEED);
+        assert_eq!(transcript.parameters, Parameters { n: 3, t: 2 });
+        assert_eq!(transcript.participants.len(), 3);
+        assert_eq!(transcript.encrypted_shares.len(), 9);
+        assert_eq!(transcript.secret_keys.len(), 3);
+        assert_eq!(transcript.to_bytes(), bytes);
+
+        assert!(check(&bytes).is_ok());
+    }
+
+    #[test]
+    fn tampered_vector_is_rejected() {
+        let mut bytes = from_hex(DKG_2_OF_3).unwrap();
+
+        // Flip a bit of the ciphertext of the last encrypted share, which
+        // still deserialises, but no longer matches the generated one.
+        let position = bytes.len() - 32 - 4 - 3 * 36 - 1;
+        bytes[position] ^= 1;
+        assert!(DkgTranscript::from_bytes(&bytes).is_ok());
+        assert!(check(&bytes).is_err());
+
+        assert!(check(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
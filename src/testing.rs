@@ -0,0 +1,151 @@
+// -*- mode: rust; -*-
+//
+// This file is part of ice-frost.
+// Copyright (c) 2021-2022 Toposware Inc.
+// See LICENSE for licensing information.
+//
+// Authors:
+// - Toposware developers <dev@toposware.com>
+
+//! Diagnostics checking the outputs of a whole key generation at once, for
+//! integration tests.
+//!
+//! This module is only available with the `testing` feature, and is not part
+//! of the stable API of this crate.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_TABLE;
+use curve25519_dalek::scalar::Scalar;
+
+use crate::keygen::calculate_group_key_from;
+use crate::keygen::Error;
+use crate::keygen::GroupKey;
+use crate::keygen::SecretKey;
+use crate::keygen::VerifiableSecretSharingCommitment;
+use crate::parameters::Parameters;
+use crate::signature::calculate_lagrange_coefficients;
+
+/// Check the internal consistency of the outputs of a key generation.
+///
+/// # Inputs
+///
+/// * The `group_key` output by the participants,
+/// * The `secret_keys` of the participants, at least \\(t\\) of them,
+/// * The `commitments` of the dealers, and
+/// * The `parameters` of the dealers.
+///
+/// The checks are, in order, that the group key is the interpolation of the
+/// commitments, that the public key of each secret key verifies against the
+/// commitments, and that every subset of \\(t\\) secret keys reconstructs the
+/// group secret key. The number of subsets grows quickly with the number of
+/// secret keys, so that this is only meant for small groups.
+///
+/// # Returns
+///
+/// An empty `Ok` if all the checks passed, otherwise an
+/// [`Error::InvalidGroupKey`] if the group key does not match the commitments
+/// or is not reconstructed by some subset, an [`Error::BadShare`] with the
+/// index of the first participant whose secret key does not match the
+/// commitments, or the error of the commitments' validation.
+pub fn verify_dkg_outputs(
+    group_key: &GroupKey,
+    secret_keys: &[SecretKey],
+    commitments: &[VerifiableSecretSharingCommitment],
+    parameters: &Parameters,
+) -> Result<(), Error> {
+    if calculate_group_key_from(commitments, parameters)? != *group_key {
+        return Err(Error::InvalidGroupKey);
+    }
+
+    if secret_keys.len() < parameters.t as usize {
+        return Err(Error::InvalidNumberOfParticipants(secret_keys.len(), parameters.t));
+    }
+
+    for secret_key in secret_keys.iter() {
+        if secret_key.to_public().verify(commitments).is_err() {
+            return Err(Error::BadShare(secret_key.index));
+        }
+    }
+
+    // Enumerate the subsets of t secret keys in lexicographic order of their positions.
+    let t = parameters.t as usize;
+    let mut positions: Vec<usize> = (0..t).collect();
+
+    loop {
+        let indices: Vec<u32> = positions.iter().map(|position| secret_keys[*position].index).collect();
+        let mut secret = Scalar::zero();
+
+        for (position, index) in positions.iter().zip(indices.iter()) {
+            let coefficient = calculate_lagrange_coefficients(index, &indices)
+                .map_err(|_| Error::InvalidGroupKey)?;
+            secret += coefficient * secret_keys[*position].key;
+        }
+
+        if GroupKey::new(&RISTRETTO_BASEPOINT_TABLE * &secret) != *group_key {
+            return Err(Error::InvalidGroupKey);
+        }
+
+        // Find the last position which can still be moved forward.
+        match (0..t).rev().find(|i| positions[*i] < secret_keys.len() - t + i) {
+            Some(i) => {
+                positions[i] += 1;
+                for j in i + 1..t {
+                    positions[j] = positions[j - 1] + 1;
+                }
+            },
+            None => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_utils::run_dkg;
+
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn verify_dkg_outputs_3_out_of_5() {
+        let params = Parameters { n: 5, t: 3 };
+
+        let (participants, states) = run_dkg(&params, "Φ", &mut OsRng);
+
+        let mut group_key = None;
+        let mut secret_keys = Vec::new();
+        for state in states.into_iter() {
+            let (key, secret_key) = state.finish().unwrap();
+            group_key = Some(key);
+            secret_keys.push(secret_key);
+        }
+        let group_key = group_key.unwrap();
+        let commitments: Vec<VerifiableSecretSharingCommitment> =
+            participants.iter().map(|p| p.commitments.clone().unwrap()).collect();
+
+        assert!(verify_dkg_outputs(&group_key, &secret_keys, &commitments, &params).is_ok());
+        assert!(verify_dkg_outputs(&group_key, &secret_keys[1..4], &commitments, &params).is_ok());
+        assert_eq!(
+            verify_dkg_outputs(&group_key, &secret_keys[..2], &commitments, &params),
+            Err(Error::InvalidNumberOfParticipants(2, 3))
+        );
+
+        // A group key other than the interpolation of the commitments is flagged.
+        assert_eq!(
+            verify_dkg_outputs(&group_key.negate(), &secret_keys, &commitments, &params),
+            Err(Error::InvalidGroupKey)
+        );
+
+        // So is a corrupted share.
+        let mut corrupted = secret_keys.clone();
+        corrupted[2].key += Scalar::one();
+        assert_eq!(
+            verify_dkg_outputs(&group_key, &corrupted, &commitments, &params),
+            Err(Error::BadShare(3))
+        );
+    }
+}
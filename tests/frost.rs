@@ -19,6 +19,8 @@ use ice_frost::compute_message_hash;
 use ice_frost::generate_commitment_share_lists;
 
 use ice_frost::DistributedKeyGeneration;
+use ice_frost::Error;
+use ice_frost::keygen::DHPrivateKey;
 use ice_frost::Parameters;
 use ice_frost::Participant;
 
@@ -131,9 +133,9 @@ fn signing_and_verification_3_out_of_5() {
 
     let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-    aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
-    aggregator.include_signer(3, p3_public_comshares.commitments[0], (&p3_sk).into());
-    aggregator.include_signer(4, p4_public_comshares.commitments[0], (&p4_sk).into());
+    aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+    aggregator.include_signer(3, p3_public_comshares.commitments[0], (&p3_sk).into()).unwrap();
+    aggregator.include_signer(4, p4_public_comshares.commitments[0], (&p4_sk).into()).unwrap();
 
     let signers = aggregator.get_signers();
     let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -153,7 +155,8 @@ fn signing_and_verification_3_out_of_5() {
     assert!(verification_result.is_ok());
 }
 
-/// We are currently incompatible with ed25519 verification.
+/// We are currently incompatible with ed25519 verification, since signatures
+/// are made over the Ristretto group with their own challenge computation.
 #[test]
 fn signing_and_verification_with_ed25519_dalek_2_out_of_3() {
     let params = Parameters { n: 3, t: 2 };
@@ -218,8 +221,8 @@ fn signing_and_verification_with_ed25519_dalek_2_out_of_3() {
 
     let mut aggregator = SignatureAggregator::new(params, group_key, &context[..], &message[..]);
 
-    aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into());
-    aggregator.include_signer(3, p3_public_comshares.commitments[0], (&p3_sk).into());
+    aggregator.include_signer(1, p1_public_comshares.commitments[0], (&p1_sk).into()).unwrap();
+    aggregator.include_signer(3, p3_public_comshares.commitments[0], (&p3_sk).into()).unwrap();
 
     let signers = aggregator.get_signers();
     let message_hash = compute_message_hash(&context[..], &message[..]);
@@ -257,3 +260,17 @@ fn signing_and_verification_with_ed25519_dalek_2_out_of_3() {
         }
     }
 }
+
+#[test]
+fn error_matching_with_catch_all() {
+    let error = DHPrivateKey::from_bytes(&[0xffu8; 32]).unwrap_err();
+
+    // `Error` is non-exhaustive, so downstream matches need a wildcard arm.
+    let description = match error {
+        Error::SerialisationError => "serialisation",
+        Error::InvalidProofOfKnowledge => "proof of knowledge",
+        _ => "other",
+    };
+
+    assert_eq!(description, "serialisation");
+}